@@ -0,0 +1,19 @@
+#![stable]
+
+use libc;
+
+pub mod pan;
+
+/// Abstract class for event-related logic.
+///
+/// Actions are ActorMeta instances, added to an actor with
+/// `Actor#add_action()`, that implement a gesture or an interaction on top
+/// of the events received by the actor, such as dragging or panning.
+///
+/// _Since 1.4_
+pub trait Action {
+  /// Returns a pointer to the the underlying C object.
+  ///
+  /// Generally only used internally.
+  fn as_action(&self) -> *mut libc::c_void;
+}
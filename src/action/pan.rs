@@ -0,0 +1,236 @@
+#![stable]
+
+use libc;
+use std;
+use super::Action;
+use super::super::actor::{ActorHandle, ActorMeta};
+use super::super::geometry::Point;
+use super::super::signal;
+use super::super::value::{Value, TYPE_BOOLEAN};
+
+/// The axes along which a PanAction pans.
+///
+/// `CLUTTER_PAN_AXIS_AUTO` is not bound, as it is only available since
+/// Clutter 1.24.
+///
+/// _Since 1.12_
+#[repr(i32)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Axis {
+  /// No constraint
+  None = 0,
+
+  /// Set a constraint on the X axis
+  X = 1,
+
+  /// Set a constraint on the Y axis
+  Y = 2
+}
+
+/// Opaque struct which holds a reference to the underlying Clutter object.
+#[repr(C)]
+pub struct PanActionRef {
+  opaque: *mut libc::c_void
+}
+
+impl PanActionRef {
+  /// Creates a new PanAction instance.
+  ///
+  /// _Since 1.12_
+  pub fn new() -> PanActionRef {
+    unsafe {
+      let foreign_result = clutter_pan_action_new();
+      return foreign_result;
+    }
+  }
+}
+
+/// Action for pan gestures.
+///
+/// PanAction tracks the pointer or touch point dragged over its actor, and
+/// emits the `pan` signal for each motion. When interpolation is enabled, it
+/// keeps emitting `pan` after the gesture ends, with a decelerating motion
+/// based on the speed of the gesture, which is what kinetic scrolling needs.
+///
+/// _Since 1.12_
+pub trait PanAction {
+  /// Returns a pointer to the the underlying C object.
+  ///
+  /// Generally only used internally.
+  fn as_pan_action(&self) -> *mut libc::c_void;
+
+  /// Restricts the panning action to a specific axis.
+  ///
+  /// _Since 1.12_
+  fn set_pan_axis(&mut self, axis: Axis) {
+    unsafe {
+      clutter_pan_action_set_pan_axis(self.as_pan_action(), axis);
+    }
+  }
+
+  /// Retrieves the axis constraint set by `.set_pan_axis()`.
+  ///
+  /// _Since 1.12_
+  fn get_pan_axis(&mut self) -> Axis {
+    unsafe {
+      let foreign_result = clutter_pan_action_get_pan_axis(self.as_pan_action());
+      return foreign_result;
+    }
+  }
+
+  /// Sets whether the action should emit interpolated `pan` events after the
+  /// gesture ends, to emulate kinetic inertia.
+  ///
+  /// _Since 1.12_
+  fn set_interpolate(&mut self, should_interpolate: bool) {
+    unsafe {
+      clutter_pan_action_set_interpolate(self.as_pan_action(), (should_interpolate as i32));
+    }
+  }
+
+  /// Checks if the action should emit `pan` events after the gesture ends.
+  ///
+  /// _Since 1.12_
+  fn get_interpolate(&mut self) -> bool {
+    unsafe {
+      let foreign_result = clutter_pan_action_get_interpolate(self.as_pan_action());
+      return foreign_result != 0;
+    }
+  }
+
+  /// Sets the deceleration rate of the interpolated `pan` events, between 0
+  /// and 1; higher values make the inertia last longer.
+  ///
+  /// _Since 1.12_
+  fn set_deceleration(&mut self, rate: f64) {
+    unsafe {
+      clutter_pan_action_set_deceleration(self.as_pan_action(), rate);
+    }
+  }
+
+  /// Retrieves the deceleration rate of the interpolated `pan` events.
+  ///
+  /// _Since 1.12_
+  fn get_deceleration(&mut self) -> f64 {
+    unsafe {
+      let foreign_result = clutter_pan_action_get_deceleration(self.as_pan_action());
+      return foreign_result;
+    }
+  }
+
+  /// Sets the factor by which the momentum of the gesture is multiplied when
+  /// starting the interpolated phase.
+  ///
+  /// _Since 1.12_
+  fn set_acceleration_factor(&mut self, factor: f64) {
+    unsafe {
+      clutter_pan_action_set_acceleration_factor(self.as_pan_action(), factor);
+    }
+  }
+
+  /// Retrieves the initial acceleration factor of the interpolated phase.
+  ///
+  /// _Since 1.12_
+  fn get_acceleration_factor(&mut self) -> f64 {
+    unsafe {
+      let foreign_result = clutter_pan_action_get_acceleration_factor(self.as_pan_action());
+      return foreign_result;
+    }
+  }
+
+  /// Retrieves the delta, in stage coordinates, since the latest motion
+  /// event of the touch point `point`, during the dragging phase.
+  ///
+  /// This is the motion delta of the underlying GestureAction.
+  ///
+  /// _Since 1.12_
+  fn get_motion_delta(&mut self, point: u32) -> Point {
+    unsafe {
+      let mut delta = Point::new(0.0, 0.0);
+      clutter_gesture_action_get_motion_delta(self.as_pan_action(), point, &mut delta.x, &mut delta.y);
+      return delta;
+    }
+  }
+
+  /// Retrieves the delta, in stage coordinates, since the previous
+  /// interpolated `pan` event, during the interpolated phase.
+  ///
+  /// _Since 1.12_
+  fn get_interpolated_delta(&mut self) -> Point {
+    unsafe {
+      let mut delta = Point::new(0.0, 0.0);
+      clutter_pan_action_get_interpolated_delta(self.as_pan_action(), &mut delta.x, &mut delta.y);
+      return delta;
+    }
+  }
+
+  /// Connects a handler to the `pan` signal, which is emitted for each
+  /// motion of the gesture and, during the interpolated phase, for each
+  /// frame of the inertia.
+  ///
+  /// The handler receives the actor and whether the event is interpolated;
+  /// it returns false to stop the gesture.
+  ///
+  /// Returns the id of the signal handler.
+  ///
+  /// _Since 1.12_
+  fn on_pan(&mut self, handler: std::boxed::Box<Fn(&mut ActorHandle, bool) -> bool + 'static>) -> u64 {
+    unsafe {
+      return signal::connect(self.as_pan_action(), "pan", std::boxed::Box::new(move |params: &[Value]| {
+        let mut actor: ActorHandle = std::mem::transmute(params[1].get_object());
+        let mut result = Value::new(TYPE_BOOLEAN);
+        result.set_boolean(handler(&mut actor, params[2].get_boolean()));
+        return Some(result);
+      }), false);
+    }
+  }
+
+  /// Connects a handler to the `pan-stopped` signal, which is emitted when
+  /// the gesture ends, or when the interpolated phase ends if interpolation
+  /// is enabled.
+  ///
+  /// Returns the id of the signal handler.
+  ///
+  /// _Since 1.12_
+  fn on_pan_stopped(&mut self, handler: std::boxed::Box<Fn(&mut ActorHandle) + 'static>) -> u64 {
+    unsafe {
+      return signal::connect(self.as_pan_action(), "pan-stopped", std::boxed::Box::new(move |params: &[Value]| {
+        let mut actor: ActorHandle = std::mem::transmute(params[1].get_object());
+        handler(&mut actor);
+        return None;
+      }), false);
+    }
+  }
+}
+
+impl PanAction for PanActionRef {
+  fn as_pan_action(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+impl Action for PanActionRef {
+  fn as_action(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+impl ActorMeta for PanActionRef {
+  fn as_actor_meta(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+extern {
+  fn clutter_pan_action_new() -> PanActionRef;
+  fn clutter_pan_action_set_pan_axis(self_value: *mut libc::c_void, axis: Axis);
+  fn clutter_pan_action_get_pan_axis(self_value: *mut libc::c_void) -> Axis;
+  fn clutter_pan_action_set_interpolate(self_value: *mut libc::c_void, should_interpolate: i32);
+  fn clutter_pan_action_get_interpolate(self_value: *mut libc::c_void) -> i32;
+  fn clutter_pan_action_set_deceleration(self_value: *mut libc::c_void, rate: f64);
+  fn clutter_pan_action_get_deceleration(self_value: *mut libc::c_void) -> f64;
+  fn clutter_pan_action_set_acceleration_factor(self_value: *mut libc::c_void, factor: f64);
+  fn clutter_pan_action_get_acceleration_factor(self_value: *mut libc::c_void) -> f64;
+  fn clutter_pan_action_get_interpolated_delta(self_value: *mut libc::c_void, delta_x: *mut f32, delta_y: *mut f32) -> f32;
+  fn clutter_gesture_action_get_motion_delta(self_value: *mut libc::c_void, point: u32, delta_x: *mut f32, delta_y: *mut f32) -> f32;
+}
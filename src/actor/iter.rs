@@ -0,0 +1,367 @@
+#![stable]
+
+use libc;
+use std;
+use std::collections::VecDeque;
+use super::{Actor, ActorHandle};
+
+/// The order in which `Actor#descendants()` visits the actor tree.
+pub enum TraversalOrder {
+  /// Visit each actor before its children, and all the descendants of a
+  /// child before its next sibling
+  DepthFirst,
+
+  /// Visit all the actors at a given depth before the actors below them
+  BreadthFirst
+}
+
+/// Mirror of ClutterActorIter.
+#[repr(C)]
+struct RawIter {
+  dummy1: *mut libc::c_void,
+  dummy2: *mut libc::c_void,
+  dummy3: *mut libc::c_void,
+  dummy4: i32,
+  dummy5: *mut libc::c_void
+}
+
+/// An iterator over the children of an actor, returned by
+/// `Actor#children()`.
+///
+/// The iterator borrows the actor it was created for, which keeps the actor
+/// alive while iterating. The list of children can be changed while
+/// iterating, but only through `.remove()` and `.destroy()`, which act on
+/// the last child returned by the iterator.
+///
+/// _Since 1.10_
+pub struct ActorIter<'a> {
+  raw: RawIter,
+  current: bool,
+  marker: std::marker::PhantomData<&'a mut ()>
+}
+
+impl<'a> ActorIter<'a> {
+  /// Creates an iterator over the children of `root`.
+  pub fn new<T: Actor + ?Sized>(root: &'a mut T) -> ActorIter<'a> {
+    unsafe {
+      let mut raw: RawIter = std::intrinsics::init();
+      clutter_actor_iter_init(&mut raw, root.as_actor());
+      return ActorIter { raw: raw, current: false, marker: std::marker::PhantomData };
+    }
+  }
+
+  /// Moves the iterator back to the previous child and returns it.
+  ///
+  /// _Since 1.10_
+  pub fn prev(&mut self) -> Option<ActorHandle> {
+    unsafe {
+      let mut child: *mut libc::c_void = std::ptr::null_mut();
+      self.current = clutter_actor_iter_prev(&mut self.raw, &mut child) != 0;
+      if !self.current {
+        return None;
+      }
+
+      return Some(ActorHandle { opaque: child });
+    }
+  }
+
+  /// Checks whether the iterator is still valid, that is whether the list of
+  /// children has not been changed behind its back.
+  ///
+  /// _Since 1.12_
+  pub fn is_valid(&self) -> bool {
+    unsafe {
+      let foreign_result = clutter_actor_iter_is_valid(&self.raw);
+      return foreign_result != 0;
+    }
+  }
+
+  /// Removes the last child returned by the iterator from its parent.
+  ///
+  /// Does nothing if the iterator has not returned a child yet, or if the
+  /// child has already been removed or destroyed.
+  ///
+  /// _Since 1.10_
+  pub fn remove(&mut self) {
+    unsafe {
+      if self.current {
+        clutter_actor_iter_remove(&mut self.raw);
+        self.current = false;
+      }
+    }
+  }
+
+  /// Destroys the last child returned by the iterator.
+  ///
+  /// Does nothing if the iterator has not returned a child yet, or if the
+  /// child has already been removed or destroyed.
+  ///
+  /// _Since 1.10_
+  pub fn destroy(&mut self) {
+    unsafe {
+      if self.current {
+        clutter_actor_iter_destroy(&mut self.raw);
+        self.current = false;
+      }
+    }
+  }
+}
+
+impl<'a> Iterator for ActorIter<'a> {
+  type Item = ActorHandle;
+
+  fn next(&mut self) -> Option<ActorHandle> {
+    unsafe {
+      let mut child: *mut libc::c_void = std::ptr::null_mut();
+      self.current = clutter_actor_iter_next(&mut self.raw, &mut child) != 0;
+      if !self.current {
+        return None;
+      }
+
+      return Some(ActorHandle { opaque: child });
+    }
+  }
+}
+
+/// An iterator over all the descendants of an actor, returned by
+/// `Actor#descendants()`.
+///
+/// The actor the iterator was created for is not part of the results. The
+/// children of an actor are read when the actor is returned.
+///
+/// The iterator holds a reference on the actors it has queued and on the
+/// last actor it returned, so they stay valid even if the tree is modified
+/// while iterating; an actor removed from the tree after being queued is
+/// still returned, with its own descendants.
+pub struct Descendants {
+  order: TraversalOrder,
+  pending: VecDeque<*mut libc::c_void>,
+  current: *mut libc::c_void
+}
+
+impl Descendants {
+  /// Creates an iterator over the descendants of `root`.
+  pub fn new<T: Actor + ?Sized>(root: &mut T, order: TraversalOrder) -> Descendants {
+    let mut descendants = Descendants { order: order, pending: VecDeque::new(), current: std::ptr::null_mut() };
+    descendants.push_children(root.as_actor());
+    return descendants;
+  }
+
+  /// Drops the reference on the last actor returned, if any.
+  fn release_current(&mut self) {
+    unsafe {
+      if !self.current.is_null() {
+        g_object_unref(self.current);
+        self.current = std::ptr::null_mut();
+      }
+    }
+  }
+
+  fn push_children(&mut self, actor: *mut libc::c_void) {
+    unsafe {
+      match self.order {
+        TraversalOrder::DepthFirst => {
+          // The front of the queue is used as a stack; pushing the children
+          // from last to first keeps them in order.
+          let mut child = rsi_get_last_child(actor);
+          while !child.is_null() {
+            self.pending.push_front(g_object_ref(child));
+            child = rsi_get_previous_sibling(child);
+          }
+        },
+        TraversalOrder::BreadthFirst => {
+          let mut child = rsi_get_first_child(actor);
+          while !child.is_null() {
+            self.pending.push_back(g_object_ref(child));
+            child = rsi_get_next_sibling(child);
+          }
+        }
+      }
+    }
+  }
+}
+
+impl Iterator for Descendants {
+  type Item = ActorHandle;
+
+  fn next(&mut self) -> Option<ActorHandle> {
+    self.release_current();
+    return match self.pending.pop_front() {
+      Some(actor) => {
+        // The reference taken when queuing the actor is kept until the
+        // next call.
+        self.current = actor;
+        self.push_children(actor);
+        Some(ActorHandle { opaque: actor })
+      },
+      None => None
+    };
+  }
+}
+
+impl Drop for Descendants {
+  fn drop(&mut self) {
+    self.release_current();
+    for actor in self.pending.iter() {
+      unsafe {
+        g_object_unref(*actor);
+      }
+    }
+  }
+}
+
+/// An iterator over the parent, grandparent and further ancestors of an
+/// actor, returned by `Actor#ancestors()`.
+pub struct Ancestors {
+  current: *mut libc::c_void
+}
+
+impl Ancestors {
+  /// Creates an iterator over the ancestors of `actor`.
+  pub fn new<T: Actor + ?Sized>(actor: &mut T) -> Ancestors {
+    return Ancestors { current: actor.as_actor() };
+  }
+}
+
+impl Iterator for Ancestors {
+  type Item = ActorHandle;
+
+  fn next(&mut self) -> Option<ActorHandle> {
+    unsafe {
+      self.current = rsi_get_parent(self.current);
+      if self.current.is_null() {
+        return None;
+      }
+
+      return Some(ActorHandle { opaque: self.current });
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{Descendants, TraversalOrder};
+  use super::super::{Actor, ActorHandle, ActorRef};
+
+  /// A root with two children, `a` and `b`; `a` has two children and `b`
+  /// has one.
+  struct Tree {
+    root: ActorRef,
+    a: ActorHandle,
+    a_1: ActorHandle,
+    a_2: ActorHandle,
+    b: ActorHandle,
+    b_1: ActorHandle
+  }
+
+  fn new_tree() -> Tree {
+    let mut root = ActorRef::new();
+    let mut a = ActorRef::new().into_handle();
+    let mut a_1 = ActorRef::new().into_handle();
+    let mut a_2 = ActorRef::new().into_handle();
+    let mut b = ActorRef::new().into_handle();
+    let mut b_1 = ActorRef::new().into_handle();
+    root.add_child(&mut a);
+    root.add_child(&mut b);
+    a.add_child(&mut a_1);
+    a.add_child(&mut a_2);
+    b.add_child(&mut b_1);
+    return Tree { root: root, a: a, a_1: a_1, a_2: a_2, b: b, b_1: b_1 };
+  }
+
+  fn children_in_order() {
+    let mut tree = new_tree();
+    let children: Vec<ActorHandle> = tree.root.children().collect();
+    assert_eq!(children, vec![tree.a, tree.b]);
+
+    let mut iter = tree.a.children();
+    assert_eq!(iter.next(), Some(tree.a_1));
+    assert_eq!(iter.next(), Some(tree.a_2));
+    assert_eq!(iter.prev(), Some(tree.a_1));
+    assert!(iter.is_valid());
+  }
+
+  fn children_can_be_removed() {
+    let mut tree = new_tree();
+    {
+      let mut iter = tree.root.children();
+      assert_eq!(iter.next(), Some(tree.a));
+      iter.destroy();
+      assert_eq!(iter.next(), Some(tree.b));
+      assert_eq!(iter.next(), None);
+    }
+    assert_eq!(tree.root.get_n_children(), 1);
+  }
+
+  fn descendants_in_order() {
+    let mut tree = new_tree();
+    let depth_first: Vec<ActorHandle> = tree.root.descendants(TraversalOrder::DepthFirst).collect();
+    assert_eq!(depth_first, vec![tree.a, tree.a_1, tree.a_2, tree.b, tree.b_1]);
+
+    let breadth_first: Vec<ActorHandle> = tree.root.descendants(TraversalOrder::BreadthFirst).collect();
+    assert_eq!(breadth_first, vec![tree.a, tree.b, tree.a_1, tree.a_2, tree.b_1]);
+  }
+
+  fn descendants_survive_removals() {
+    let mut tree = new_tree();
+    let mut descendants = Descendants::new(&mut tree.root, TraversalOrder::BreadthFirst);
+    assert_eq!(descendants.next(), Some(tree.a));
+
+    // Removing `b` drops the reference held by the root, but `b` is queued,
+    // so it is still alive and returned, together with its child.
+    tree.root.remove_child(&mut tree.b);
+    assert_eq!(descendants.next(), Some(tree.b));
+    assert_eq!(descendants.next(), Some(tree.a_1));
+    assert_eq!(descendants.next(), Some(tree.a_2));
+    assert_eq!(descendants.next(), Some(tree.b_1));
+    assert_eq!(descendants.next(), None);
+
+    // The iterator held the last reference on `b`.
+    drop(descendants);
+  }
+
+  fn ancestors_up_to_the_root() {
+    let mut tree = new_tree();
+    let ancestors: Vec<ActorHandle> = tree.b_1.ancestors().collect();
+    assert_eq!(ancestors.len(), 2);
+    assert_eq!(ancestors[0], tree.b);
+    assert_eq!(ancestors[1].as_actor(), tree.root.as_actor());
+    assert_eq!(tree.root.ancestors().next(), None);
+  }
+
+  /// Clutter is not thread-safe, so the scenarios run from a single test.
+  ///
+  /// The test initializes Clutter, which needs a display, so it only runs
+  /// with `cargo test -- --ignored`.
+  #[test]
+  #[ignore]
+  fn iterates_actor_trees() {
+    super::super::super::init();
+    children_in_order();
+    children_can_be_removed();
+    descendants_in_order();
+    descendants_survive_removals();
+    ancestors_up_to_the_root();
+  }
+}
+
+extern {
+  fn clutter_actor_iter_init(iter: *mut RawIter, root: *mut libc::c_void);
+  fn clutter_actor_iter_next(iter: *mut RawIter, child: *mut *mut libc::c_void) -> i32;
+  fn clutter_actor_iter_prev(iter: *mut RawIter, child: *mut *mut libc::c_void) -> i32;
+  fn clutter_actor_iter_remove(iter: *mut RawIter);
+  fn clutter_actor_iter_destroy(iter: *mut RawIter);
+  fn clutter_actor_iter_is_valid(iter: *const RawIter) -> i32;
+  #[link_name = "clutter_actor_get_first_child"]
+  fn rsi_get_first_child(self_value: *mut libc::c_void) -> *mut libc::c_void;
+  #[link_name = "clutter_actor_get_last_child"]
+  fn rsi_get_last_child(self_value: *mut libc::c_void) -> *mut libc::c_void;
+  #[link_name = "clutter_actor_get_next_sibling"]
+  fn rsi_get_next_sibling(self_value: *mut libc::c_void) -> *mut libc::c_void;
+  #[link_name = "clutter_actor_get_previous_sibling"]
+  fn rsi_get_previous_sibling(self_value: *mut libc::c_void) -> *mut libc::c_void;
+  #[link_name = "clutter_actor_get_parent"]
+  fn rsi_get_parent(self_value: *mut libc::c_void) -> *mut libc::c_void;
+  fn g_object_ref(object: *mut libc::c_void) -> *mut libc::c_void;
+  fn g_object_unref(object: *mut libc::c_void);
+}
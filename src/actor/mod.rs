@@ -4,9 +4,18 @@ use libc;
 use std;
 use cairo;
 use super::content::Content;
+use super::action::Action;
+use super::animation::AnimationMode;
 use super::constraint::Constraint;
+use super::geometry::{Margin, Point, Rect, Size, Vertex};
+use super::layout::{LayoutManager, Orientation};
+use super::matrix::Matrix;
+use super::ffi::{c_str_to_string, take_list, type_name_of, List};
 
 pub mod allocation;
+pub mod iter;
+pub mod selector;
+pub mod snapshot;
 
 /// Flags used to signal the state of an actor.
 #[repr(i32)]
@@ -30,24 +39,323 @@ pub enum Flags {
   NoLayout = 32
 }
 
+/// Axis of a rotation.
+///
+/// _Since 0.4_
+#[repr(i32)]
+pub enum RotateAxis {
+  /// Rotate around the X axis
+  X = 0,
+
+  /// Rotate around the Y axis
+  Y = 1,
+
+  /// Rotate around the Z axis
+  Z = 2
+}
+
+/// Controls how an actor should be aligned inside the allocation assigned by
+/// its parent.
+///
+/// _Since 1.10_
+#[repr(i32)]
+pub enum ActorAlign {
+  /// Stretch to cover the whole allocated space
+  Fill = 0,
+
+  /// Snap to left or top side, leaving space to the right or bottom. For
+  /// horizontal layouts, in right-to-left locales this should be reversed.
+  Start = 1,
+
+  /// Center the actor inside the allocation
+  Center = 2,
+
+  /// Snap to right or bottom side, leaving space to the left or top. For
+  /// horizontal layouts, in right-to-left locales this should be reversed.
+  End = 3
+}
+
+/// Specifies the type of requests for an Actor.
+///
+/// _Since 0.8_
+#[repr(i32)]
+pub enum RequestMode {
+  /// Height for width requests
+  HeightForWidth = 0,
+
+  /// Width for height requests
+  WidthForHeight = 1
+}
+
+/// Controls the alignment of the Content inside the allocation of an Actor.
+///
+/// _Since 1.10_
+#[repr(i32)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ContentGravity {
+  /// Align the content to the top left corner
+  TopLeft = 0,
+
+  /// Align the content to the top edge
+  Top = 1,
+
+  /// Align the content to the top right corner
+  TopRight = 2,
+
+  /// Align the content to the left edge
+  Left = 3,
+
+  /// Align the content to the center
+  Center = 4,
+
+  /// Align the content to the right edge
+  Right = 5,
+
+  /// Align the content to the bottom left corner
+  BottomLeft = 6,
+
+  /// Align the content to the bottom edge
+  Bottom = 7,
+
+  /// Align the content to the bottom right corner
+  BottomRight = 8,
+
+  /// Resize the content to fill the allocation
+  ResizeFill = 9,
+
+  /// Resize the content to remain within the allocation, while maintaining
+  /// the aspect ratio, leaving empty bands on the sides if needed
+  ResizeAspect = 10
+}
+
+/// Controls the repeat policy of the Content of an Actor, when it is smaller
+/// than the content box.
+///
+/// _Since 1.12_
+#[repr(i32)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ContentRepeat {
+  /// No repeat
+  None = 0,
+
+  /// Repeat the content on the X axis
+  XAxis = 1,
+
+  /// Repeat the content on the Y axis
+  YAxis = 2,
+
+  /// Repeat the content on both axis
+  Both = 3
+}
+
+/// Controls when an Actor is painted into an offscreen buffer, and then
+/// composited as a single layer.
+///
+/// Painting offscreen is needed to apply the opacity of an actor to its
+/// children as a whole: otherwise the opacity is applied to each child, and
+/// overlapping children show through each other.
+///
+/// _Since 1.8_
+#[repr(i32)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum OffscreenRedirect {
+  /// Never redirect; the opacity is applied to each child separately
+  None = 0,
+
+  /// Redirect only when the actor is semi-transparent and `.has_overlaps()`
+  /// returns true
+  AutomaticForOpacity = 1,
+
+  /// Always redirect, which also caches the rendering of the actor while it
+  /// does not change
+  Always = 2
+}
+
 /// Bounding box of an actor.
 ///
 /// The coordinates of the top left and right bottom corners of an actor. The
 /// coordinates of the two points are expressed in pixels with sub-pixel
 /// precision.
 #[repr(C)]
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub struct Box {
   /// X coordinate of the top left corner
-  x1: f32,
+  pub x1: f32,
 
   /// Y coordinate of the top left corner
-  y1: f32,
+  pub y1: f32,
 
   /// X coordinate of the bottom right corner
-  x2: f32,
+  pub x2: f32,
 
   /// Y coordinate of the bottom right corner
-  y2: f32
+  pub y2: f32
+}
+
+impl Box {
+  /// Creates a new Box with the given coordinates of the top left and
+  /// bottom right corners.
+  ///
+  /// _Since 1.0_
+  pub fn new(x_1: f32, y_1: f32, x_2: f32, y_2: f32) -> Box {
+    return Box { x1: x_1, y1: y_1, x2: x_2, y2: y_2 };
+  }
+
+  /// Creates a new Box with the given origin and size.
+  ///
+  /// _Since 1.10_
+  pub fn from_rect(x: f32, y: f32, width: f32, height: f32) -> Box {
+    return Box { x1: x, y1: y, x2: x + width, y2: y + height };
+  }
+
+  /// Calculates the bounding box represented by the four vertices; for
+  /// details of the vertex array see `Actor#get_abs_allocation_vertices()`.
+  ///
+  /// _Since 1.0_
+  pub fn from_vertices(verts: &[Vertex; 4]) -> Box {
+    unsafe {
+      let mut result:Box = std::intrinsics::init();
+      clutter_actor_box_from_vertices(&mut result, verts.as_ptr());
+      return result;
+    }
+  }
+
+  /// Retrieves the X coordinate of the origin of the box.
+  ///
+  /// _Since 1.0_
+  pub fn get_x(&self) -> f32 {
+    return self.x1;
+  }
+
+  /// Retrieves the Y coordinate of the origin of the box.
+  ///
+  /// _Since 1.0_
+  pub fn get_y(&self) -> f32 {
+    return self.y1;
+  }
+
+  /// Retrieves the width of the box.
+  ///
+  /// _Since 1.0_
+  pub fn get_width(&self) -> f32 {
+    return self.x2 - self.x1;
+  }
+
+  /// Retrieves the height of the box.
+  ///
+  /// _Since 1.0_
+  pub fn get_height(&self) -> f32 {
+    return self.y2 - self.y1;
+  }
+
+  /// Retrieves the origin of the box.
+  ///
+  /// _Since 1.0_
+  pub fn get_origin(&self) -> Point {
+    return Point::new(self.x1, self.y1);
+  }
+
+  /// Changes the origin of the box, maintaining the size.
+  ///
+  /// _Since 1.6_
+  pub fn set_origin(&mut self, origin: &Point) {
+    let width = self.get_width();
+    let height = self.get_height();
+    self.x1 = origin.x;
+    self.y1 = origin.y;
+    self.x2 = origin.x + width;
+    self.y2 = origin.y + height;
+  }
+
+  /// Retrieves the size of the box.
+  ///
+  /// _Since 1.0_
+  pub fn get_size(&self) -> Size {
+    return Size::new(self.get_width(), self.get_height());
+  }
+
+  /// Sets the size of the box, maintaining the origin.
+  ///
+  /// _Since 1.6_
+  pub fn set_size(&mut self, size: &Size) {
+    self.x2 = self.x1 + size.width;
+    self.y2 = self.y1 + size.height;
+  }
+
+  /// Retrieves the area of the box.
+  ///
+  /// _Since 1.0_
+  pub fn get_area(&self) -> f32 {
+    unsafe {
+      let foreign_result = clutter_actor_box_get_area(self);
+      return foreign_result;
+    }
+  }
+
+  /// Checks whether a point is contained by the box.
+  ///
+  /// _Since 1.0_
+  pub fn contains(&self, point: &Point) -> bool {
+    unsafe {
+      let foreign_result = clutter_actor_box_contains(self, point.x, point.y);
+      return foreign_result != 0;
+    }
+  }
+
+  /// Unions the two boxes and returns the smallest box containing both.
+  ///
+  /// _Since 1.4_
+  pub fn union(&self, other: &Box) -> Box {
+    unsafe {
+      let mut result:Box = std::intrinsics::init();
+      clutter_actor_box_union(self, other, &mut result);
+      return result;
+    }
+  }
+
+  /// Computes the intersection of the two boxes.
+  ///
+  /// Returns __None__ if the boxes do not overlap.
+  pub fn intersection(&self, other: &Box) -> Option<Box> {
+    let x_1 = self.x1.max(other.x1);
+    let y_1 = self.y1.max(other.y1);
+    let x_2 = self.x2.min(other.x2);
+    let y_2 = self.y2.min(other.y2);
+    if x_1 >= x_2 || y_1 >= y_2 {
+      return None;
+    }
+
+    return Some(Box::new(x_1, y_1, x_2, y_2));
+  }
+
+  /// Clamps the components of the box to the nearest integer.
+  ///
+  /// _Since 1.2_
+  pub fn clamp_to_pixel(&mut self) {
+    unsafe {
+      clutter_actor_box_clamp_to_pixel(self);
+    }
+  }
+
+  /// Interpolates between this box and the `final_box` using `progress`.
+  ///
+  /// _Since 1.2_
+  pub fn interpolate(&self, final_box: &Box, progress: f64) -> Box {
+    unsafe {
+      let mut result:Box = std::intrinsics::init();
+      clutter_actor_box_interpolate(self, final_box, progress, &mut result);
+      return result;
+    }
+  }
+}
+
+extern {
+  fn clutter_actor_box_from_vertices(self_value: *mut Box, verts: *const Vertex);
+  fn clutter_actor_box_get_area(self_value: *const Box) -> f32;
+  fn clutter_actor_box_contains(self_value: *const Box, x: f32, y: f32) -> i32;
+  fn clutter_actor_box_union(a: *const Box, b: *const Box, result: *mut Box);
+  fn clutter_actor_box_clamp_to_pixel(self_value: *mut Box);
+  fn clutter_actor_box_interpolate(initial: *const Box, final_box: *const Box, progress: f64, result: *mut Box);
 }
 
 /// Opaque struct which holds a reference to the underlying Clutter object.
@@ -69,6 +377,16 @@ impl ActorRef {
       return foreign_result;
     }
   }
+
+  /// Turns the reference into an ActorHandle, which does not destroy the
+  /// actor when it is dropped.
+  ///
+  /// The actor should be added to a parent, which then owns it.
+  pub fn into_handle(self) -> ActorHandle {
+    let handle = ActorHandle { opaque: self.opaque };
+    std::mem::forget(self);
+    return handle;
+  }
 }
 
 /// The basic element of the scene graph.
@@ -564,6 +882,51 @@ pub trait Actor {
     }
   }
 
+  /// Defines the circumstances where the actor should be redirected into an
+  /// offscreen image.
+  ///
+  /// The offscreen image is used as a source for painting the actor, which
+  /// can improve performance, since the image is cached until the actor or
+  /// its children change. It is also used to implement the opacity of actors
+  /// with overlapping children: with `OffscreenRedirect::None` the opacity
+  /// is applied to each child, and their overlapping areas show through each
+  /// other.
+  ///
+  /// The default is `OffscreenRedirect::AutomaticForOpacity`, which only
+  /// redirects actors that are semi-transparent and may contain overlapping
+  /// primitives, as reported by `.has_overlaps()`. Use
+  /// `OffscreenRedirect::Always` to fade a group whose children do overlap
+  /// but whose implementation does not report it.
+  ///
+  /// _Since 1.8_
+  fn set_offscreen_redirect(&mut self, redirect: OffscreenRedirect) {
+    unsafe {
+      clutter_actor_set_offscreen_redirect(self.as_actor(), redirect);
+    }
+  }
+
+  /// Retrieves whether to redirect the actor to an offscreen buffer, as set
+  /// by `.set_offscreen_redirect()`.
+  ///
+  /// Clutter stores the value as flags, so C code can combine
+  /// `AutomaticForOpacity` and `Always`; such a combination is reported as
+  /// `Always`, which already redirects in every case the automatic mode
+  /// does.
+  ///
+  /// _Since 1.8_
+  fn get_offscreen_redirect(&mut self) -> OffscreenRedirect {
+    unsafe {
+      let foreign_result = clutter_actor_get_offscreen_redirect(self.as_actor());
+      return if (foreign_result & (OffscreenRedirect::Always as i32)) != 0 {
+        OffscreenRedirect::Always
+      } else if (foreign_result & (OffscreenRedirect::AutomaticForOpacity as i32)) != 0 {
+        OffscreenRedirect::AutomaticForOpacity
+      } else {
+        OffscreenRedirect::None
+      };
+    }
+  }
+
   /// Returns whether an Actor has any mapped clones.
   ///
   /// _Since 1.16_
@@ -613,6 +976,177 @@ pub trait Actor {
     }
   }
 
+  /// Sets the geometry request mode of the actor.
+  ///
+  /// The request mode determines the type of geometry management used by the
+  /// actor, either height for width (the default) or width for height. The
+  /// content size mode needs Clutter 1.22 and is deliberately not exposed.
+  ///
+  /// For actors implementing height for width, the parent container should
+  /// get the preferred width first, and then the preferred height for that
+  /// width; for width for height, the preferred height first, and then the
+  /// preferred width for that height.
+  ///
+  /// _Since 1.2_
+  fn set_request_mode(&mut self, mode: RequestMode) {
+    unsafe {
+      clutter_actor_set_request_mode(self.as_actor(), mode);
+    }
+  }
+
+  /// Retrieves the geometry request mode of the actor.
+  ///
+  /// _Since 1.2_
+  fn get_request_mode(&mut self) -> RequestMode {
+    unsafe {
+      let foreign_result = clutter_actor_get_request_mode(self.as_actor());
+      return foreign_result;
+    }
+  }
+
+  /// Assigns the size of the actor from the given `allocation` box.
+  ///
+  /// This method should only be called on the children of an actor when
+  /// overriding the `allocate` virtual function, or inside the `allocate`
+  /// method of a LayoutManager.
+  ///
+  /// This method will adjust the stored allocation to take into account the
+  /// alignment flags set in the `x-align` and `y-align` properties, as well as
+  /// the margin values set in the `margin-top`, `margin-right`,
+  /// `margin-bottom`, and `margin-left` properties.
+  ///
+  /// This method will respect the easing state of the actor and interpolate
+  /// between the current allocation and the new one if the easing state
+  /// duration is a positive value.
+  ///
+  /// _Since 0.8_
+  fn allocate(&mut self, allocation: &Box, flags: allocation::Flags) {
+    unsafe {
+      clutter_actor_allocate(self.as_actor(), allocation, flags);
+    }
+  }
+
+  /// Allocates the natural size of the actor.
+  ///
+  /// This method is a utility call for Actor implementations that allocates
+  /// the actor's preferred natural size. It can be used by fixed layout
+  /// managers (like Group or so called 'composite actors') inside the
+  /// `allocate` virtual function to allocate each child.
+  ///
+  /// The actor will be allocated at its fixed position, as set with
+  /// `.set_position()`.
+  ///
+  /// _Since 0.8_
+  fn allocate_preferred_size(&mut self, flags: allocation::Flags) {
+    unsafe {
+      clutter_actor_allocate_preferred_size(self.as_actor(), flags);
+    }
+  }
+
+  /// Allocates the actor by taking into consideration the available
+  /// allocation area; an alignment factor on either axis; and whether the
+  /// actor should fill the allocation on either axis.
+  ///
+  /// The `allocation` box should contain the available allocation width and
+  /// height; if the x1 and y1 members are set to a value different than 0,
+  /// they will be used as the origin of the allocation.
+  ///
+  /// The alignment factors go from 0.0, left or top, to 1.0, right or bottom.
+  ///
+  /// This method takes into consideration the `request-mode` of the actor.
+  ///
+  /// _Since 1.4_
+  fn allocate_align_fill(&mut self, allocation: &Box, x_align: f64, y_align: f64, x_fill: bool, y_fill: bool, flags: allocation::Flags) {
+    unsafe {
+      clutter_actor_allocate_align_fill(self.as_actor(), allocation, x_align, y_align, (x_fill as i32), (y_fill as i32), flags);
+    }
+  }
+
+  /// Allocates the actor taking into account the available size and the
+  /// actor's preferred size.
+  ///
+  /// The actor is allocated at `x`, `y`, with the size of its preferred
+  /// natural size, but clamped to `available_width` and `available_height`;
+  /// the preferred size is computed according to the `request-mode`.
+  ///
+  /// This method is useful for layout managers that want to position their
+  /// children at a given place, but don't want them to overflow the
+  /// available space.
+  ///
+  /// _Since 1.0_
+  fn allocate_available_size(&mut self, x: f32, y: f32, available_width: f32, available_height: f32, flags: allocation::Flags) {
+    unsafe {
+      clutter_actor_allocate_available_size(self.as_actor(), x, y, available_width, available_height, flags);
+    }
+  }
+
+  /// Gets the layout box the actor has been assigned.
+  ///
+  /// The allocation can only be assumed valid inside a `paint` method;
+  /// anywhere else, it may be out-of-date.
+  ///
+  /// An allocation does not incorporate the actor's scale or anchor point;
+  /// those transformations do not affect layout, only rendering.
+  ///
+  /// Do not call any of the `allocate` methods inside the `allocate`
+  /// implementation of a custom layout manager with this box: use the box
+  /// passed to the implementation.
+  ///
+  /// _Since 0.8_
+  fn get_allocation_box(&mut self) -> Box {
+    unsafe {
+      let mut allocation:Box = std::intrinsics::init();
+      clutter_actor_get_allocation_box(self.as_actor(), &mut allocation);
+      return allocation;
+    }
+  }
+
+  /// Calculates the transformed coordinates of the four corners of the actor
+  /// in the plane of the stage.
+  ///
+  /// The vertices are returned in the order: top left, top right, bottom
+  /// left, bottom right.
+  ///
+  /// _Since 0.6_
+  fn get_allocation_vertices(&mut self) -> [Vertex; 4] {
+    unsafe {
+      let mut verts:[Vertex; 4] = std::intrinsics::init();
+      let null_void: *mut libc::c_void = std::ptr::null_mut();
+      clutter_actor_get_allocation_vertices(self.as_actor(), null_void, verts.as_mut_ptr());
+      return verts;
+    }
+  }
+
+  /// Calculates the transformed coordinates of the four corners of the actor
+  /// in the plane of `ancestor`.
+  ///
+  /// The vertices are returned in the same order as
+  /// `.get_allocation_vertices()`.
+  ///
+  /// _Since 0.6_
+  fn get_allocation_vertices_relative_to<T: Actor>(&mut self, ancestor: &mut T) -> [Vertex; 4] {
+    unsafe {
+      let mut verts:[Vertex; 4] = std::intrinsics::init();
+      clutter_actor_get_allocation_vertices(self.as_actor(), ancestor.as_actor(), verts.as_mut_ptr());
+      return verts;
+    }
+  }
+
+  /// Calculates the transformed screen coordinates of the four corners of
+  /// the actor.
+  ///
+  /// The vertices are returned in the same order as
+  /// `.get_allocation_vertices()`.
+  ///
+  /// _Since 0.4_
+  fn get_abs_allocation_vertices(&mut self) -> [Vertex; 4] {
+    unsafe {
+      let mut verts:[Vertex; 4] = std::intrinsics::init();
+      clutter_actor_get_abs_allocation_vertices(self.as_actor(), verts.as_mut_ptr());
+      return verts;
+    }
+  }
+
   /// Sets whether an actor has a fixed position set (and will thus be
   /// unaffected by any layout manager).
   ///
@@ -734,7 +1268,7 @@ pub trait Actor {
   }
 
   /// Checks whether an actor, or any of its children, is set to expand
-  /// horizontally or vertically.
+  /// in the given `orientation`.
   ///
   /// This method should only be called by layout managers that can assign
   /// extra space to their children.
@@ -743,51 +1277,214 @@ pub trait Actor {
   /// `.get_x_expand()` or `.get_y_expand()`.
   ///
   /// _Since 1.12_
-  fn needs_expand(&mut self) -> bool {
+  fn needs_expand(&mut self, orientation: Orientation) -> bool {
     unsafe {
-      let foreign_result = clutter_actor_needs_expand(self.as_actor());
+      let foreign_result = clutter_actor_needs_expand(self.as_actor(), orientation);
       return foreign_result != 0;
     }
   }
 
-  /// Sets the actor's size request in pixels.
-  ///
-  /// This overrides any "normal" size request the actor would have. For example
-  /// a text actor might normally request the size of the text; this method
-  /// would force a specific size instead.
+  /// Sets the horizontal alignment policy of the actor, in case the actor
+  /// received extra horizontal space.
   ///
-  /// If width and/or height are -1 the actor will use its "normal" size request
-  /// instead of overriding it, i.e. you can "unset" the size with -1.
+  /// See also the `x-align` property.
   ///
-  /// This method sets or unsets both the minimum and natural size.
-  fn set_size(&mut self, for_width: f32, for_height: f32) {
+  /// _Since 1.10_
+  fn set_x_align(&mut self, x_align: ActorAlign) {
     unsafe {
-      clutter_actor_set_size(self.as_actor(), for_width, for_height);
+      clutter_actor_set_x_align(self.as_actor(), x_align);
     }
   }
 
-  /// Tries to "do what you mean" and return the size an actor will have.
-  ///
-  /// If the actor has a valid allocation, the allocation will be returned;
-  /// otherwise, the actors natural size request will be returned.
-  ///
-  /// If you care whether you get the request vs. the allocation, you should
-  /// probably call a different function like `.get_allocation_box()` or
-  /// ``get_preferred_width()`.
+  /// Retrieves the horizontal alignment policy set using `.set_x_align()`.
   ///
-  /// _Since 0.2_
-  fn get_size(&mut self) -> (f32, f32) {
+  /// _Since 1.10_
+  fn get_x_align(&mut self) -> ActorAlign {
     unsafe {
-      let mut width:f32 = std::intrinsics::init();
-      let mut height:f32 = std::intrinsics::init();
-      clutter_actor_get_size(self.as_actor(), &mut width, &mut height);
-      return (width, height);
+      let foreign_result = clutter_actor_get_x_align(self.as_actor());
+      return foreign_result;
     }
   }
 
-  /// Sets the actor's fixed position in pixels relative to any parent actor.
+  /// Sets the vertical alignment policy of the actor, in case the actor
+  /// received extra vertical space.
   ///
-  /// If a layout manager is in use, this position will override the layout
+  /// See also the `y-align` property.
+  ///
+  /// _Since 1.10_
+  fn set_y_align(&mut self, y_align: ActorAlign) {
+    unsafe {
+      clutter_actor_set_y_align(self.as_actor(), y_align);
+    }
+  }
+
+  /// Retrieves the vertical alignment policy set using `.set_y_align()`.
+  ///
+  /// _Since 1.10_
+  fn get_y_align(&mut self) -> ActorAlign {
+    unsafe {
+      let foreign_result = clutter_actor_get_y_align(self.as_actor());
+      return foreign_result;
+    }
+  }
+
+  /// Sets all the components of the margin of the actor.
+  ///
+  /// The margin is extra space added around the actor when its parent
+  /// allocates it, and is honoured by the layout managers.
+  ///
+  /// _Since 1.10_
+  fn set_margin(&mut self, margin: &Margin) {
+    unsafe {
+      clutter_actor_set_margin(self.as_actor(), margin);
+    }
+  }
+
+  /// Retrieves all the components of the margin of the actor.
+  ///
+  /// _Since 1.10_
+  fn get_margin(&mut self) -> Margin {
+    unsafe {
+      let mut margin:Margin = std::intrinsics::init();
+      clutter_actor_get_margin(self.as_actor(), &mut margin);
+      return margin;
+    }
+  }
+
+  /// Sets the margin from the top of the actor.
+  ///
+  /// The `margin-top` property is animatable.
+  ///
+  /// _Since 1.10_
+  fn set_margin_top(&mut self, margin: f32) {
+    unsafe {
+      clutter_actor_set_margin_top(self.as_actor(), margin);
+    }
+  }
+
+  /// Retrieves the top margin of the actor.
+  ///
+  /// _Since 1.10_
+  fn get_margin_top(&mut self) -> f32 {
+    unsafe {
+      let foreign_result = clutter_actor_get_margin_top(self.as_actor());
+      return foreign_result;
+    }
+  }
+
+  /// Sets the margin from the bottom of the actor.
+  ///
+  /// The `margin-bottom` property is animatable.
+  ///
+  /// _Since 1.10_
+  fn set_margin_bottom(&mut self, margin: f32) {
+    unsafe {
+      clutter_actor_set_margin_bottom(self.as_actor(), margin);
+    }
+  }
+
+  /// Retrieves the bottom margin of the actor.
+  ///
+  /// _Since 1.10_
+  fn get_margin_bottom(&mut self) -> f32 {
+    unsafe {
+      let foreign_result = clutter_actor_get_margin_bottom(self.as_actor());
+      return foreign_result;
+    }
+  }
+
+  /// Sets the margin from the left of the actor.
+  ///
+  /// The `margin-left` property is animatable.
+  ///
+  /// _Since 1.10_
+  fn set_margin_left(&mut self, margin: f32) {
+    unsafe {
+      clutter_actor_set_margin_left(self.as_actor(), margin);
+    }
+  }
+
+  /// Retrieves the left margin of the actor.
+  ///
+  /// _Since 1.10_
+  fn get_margin_left(&mut self) -> f32 {
+    unsafe {
+      let foreign_result = clutter_actor_get_margin_left(self.as_actor());
+      return foreign_result;
+    }
+  }
+
+  /// Sets the margin from the right of the actor.
+  ///
+  /// The `margin-right` property is animatable.
+  ///
+  /// _Since 1.10_
+  fn set_margin_right(&mut self, margin: f32) {
+    unsafe {
+      clutter_actor_set_margin_right(self.as_actor(), margin);
+    }
+  }
+
+  /// Retrieves the right margin of the actor.
+  ///
+  /// _Since 1.10_
+  fn get_margin_right(&mut self) -> f32 {
+    unsafe {
+      let foreign_result = clutter_actor_get_margin_right(self.as_actor());
+      return foreign_result;
+    }
+  }
+
+  /// Sets the actor's size request in pixels.
+  ///
+  /// This overrides any "normal" size request the actor would have. For example
+  /// a text actor might normally request the size of the text; this method
+  /// would force a specific size instead.
+  ///
+  /// If width and/or height are -1 the actor will use its "normal" size request
+  /// instead of overriding it, i.e. you can "unset" the size with -1.
+  ///
+  /// This method sets or unsets both the minimum and natural size.
+  fn set_size(&mut self, for_width: f32, for_height: f32) {
+    unsafe {
+      clutter_actor_set_size(self.as_actor(), for_width, for_height);
+    }
+  }
+
+  /// Sets the actor's size request in pixels from a `Size`.
+  ///
+  /// This is the same as `.set_size()`, taking the width and height from
+  /// `size`.
+  ///
+  /// _Since 0.2_
+  fn set_size_from(&mut self, size: &Size) {
+    unsafe {
+      clutter_actor_set_size(self.as_actor(), size.width, size.height);
+    }
+  }
+
+  /// Tries to "do what you mean" and return the size an actor will have.
+  ///
+  /// If the actor has a valid allocation, the allocation will be returned;
+  /// otherwise, the actors natural size request will be returned.
+  ///
+  /// If you care whether you get the request vs. the allocation, you should
+  /// probably call a different function like `.get_allocation_box()` or
+  /// ``get_preferred_width()`.
+  ///
+  /// _Since 0.2_
+  fn get_size(&mut self) -> Size {
+    unsafe {
+      let mut width:f32 = std::intrinsics::init();
+      let mut height:f32 = std::intrinsics::init();
+      clutter_actor_get_size(self.as_actor(), &mut width, &mut height);
+      return Size::new(width, height);
+    }
+  }
+
+  /// Sets the actor's fixed position in pixels relative to any parent actor.
+  ///
+  /// If a layout manager is in use, this position will override the layout
   /// manager and force a fixed position.
   fn set_position(&mut self, x: f32, y: f32) {
     unsafe {
@@ -795,6 +1492,18 @@ pub trait Actor {
     }
   }
 
+  /// Sets the actor's fixed position in pixels from a `Point`.
+  ///
+  /// This is the same as `.set_position()`, taking the coordinates from
+  /// `position`.
+  ///
+  /// _Since 0.6_
+  fn set_position_from(&mut self, position: &Point) {
+    unsafe {
+      clutter_actor_set_position(self.as_actor(), position.x, position.y);
+    }
+  }
+
   /// This function tries to "do what you mean" and tell you where the actor
   /// is, prior to any transformations.
   ///
@@ -805,12 +1514,12 @@ pub trait Actor {
   /// The returned position is in pixels.
   ///
   /// _Since 0.6_
-  fn get_position(&mut self) -> (f32, f32) {
+  fn get_position(&mut self) -> Point {
     unsafe {
       let mut x:f32 = std::intrinsics::init();
       let mut y:f32 = std::intrinsics::init();
       clutter_actor_get_position(self.as_actor(), &mut x, &mut y);
-      return (x, y);
+      return Point::new(x, y);
     }
   }
 
@@ -1005,15 +1714,28 @@ pub trait Actor {
     }
   }
 
+  /// Sets the position of the `pivot-point` from a `Point` in normalized
+  /// space.
+  ///
+  /// This is the same as `.set_pivot_point()`, taking the coordinates from
+  /// `pivot_point`.
+  ///
+  /// _Since 1.12_
+  fn set_pivot_point_from(&mut self, pivot_point: &Point) {
+    unsafe {
+      clutter_actor_set_pivot_point(self.as_actor(), pivot_point.x, pivot_point.y);
+    }
+  }
+
   /// Retrieves the coordinates of the `pivot-point`.
   ///
   /// _Since 1.12_
-  fn get_pivot_point(&mut self) -> (f32, f32) {
+  fn get_pivot_point(&mut self) -> Point {
     unsafe {
       let mut pivot_x:f32 = std::intrinsics::init();
       let mut pivot_y:f32 = std::intrinsics::init();
       clutter_actor_get_pivot_point(self.as_actor(), &mut pivot_x, &mut pivot_y);
-      return (pivot_x, pivot_y);
+      return Point::new(pivot_x, pivot_y);
     }
   }
 
@@ -1098,6 +1820,35 @@ pub trait Actor {
     }
   }
 
+  /// Sets the `angle` of rotation of the actor on the given `axis`.
+  ///
+  /// This method is a convenience for setting the rotation properties
+  /// `rotation-angle-x`, `rotation-angle-y`, and `rotation-angle-z`.
+  ///
+  /// The center of rotation is established by the `pivot-point` property, see
+  /// `.set_pivot_point()` and `.set_pivot_point_z()`.
+  ///
+  /// The rotation properties are animatable, so a rotation changed after
+  /// `.save_easing_state()` will be tweened using the current easing state.
+  ///
+  /// _Since 1.12_
+  fn set_rotation_angle(&mut self, axis: RotateAxis, angle: f64) {
+    unsafe {
+      clutter_actor_set_rotation_angle(self.as_actor(), axis, angle);
+    }
+  }
+
+  /// Retrieves the angle of rotation set by `.set_rotation_angle()`, in
+  /// degrees.
+  ///
+  /// _Since 1.12_
+  fn get_rotation_angle(&mut self, axis: RotateAxis) -> f64 {
+    unsafe {
+      let foreign_result = clutter_actor_get_rotation_angle(self.as_actor(), axis);
+      return foreign_result;
+    }
+  }
+
   /// Checks whether any rotation is applied to the actor.
   ///
   /// _Since 0.6_
@@ -1118,30 +1869,127 @@ pub trait Actor {
     }
   }
 
+  /// Sets an additional translation transformation on the actor from a
+  /// `Vertex`.
+  ///
+  /// This is the same as `.set_translation()`, taking the components from
+  /// `translation`.
+  ///
+  /// _Since 1.12_
+  fn set_translation_from(&mut self, translation: &Vertex) {
+    unsafe {
+      clutter_actor_set_translation(self.as_actor(), translation.x, translation.y, translation.z);
+    }
+  }
+
   /// Retrieves the translation set using `.set_translation()`.
   ///
   /// _Since 1.12_
-  fn get_translation(&mut self) -> (f32, f32, f32) {
+  fn get_translation(&mut self) -> Vertex {
     unsafe {
       let mut translate_x:f32 = std::intrinsics::init();
       let mut translate_y:f32 = std::intrinsics::init();
       let mut translate_z:f32 = std::intrinsics::init();
       clutter_actor_get_translation(self.as_actor(), &mut translate_x, &mut translate_y, &mut translate_z);
-      return (translate_x, translate_y, translate_z);
+      return Vertex::new(translate_x, translate_y, translate_z);
+    }
+  }
+
+  /// Overrides the transformations of the actor with a custom matrix, which
+  /// will be applied relative to the origin of the actor's allocation and to
+  /// the actor's pivot point.
+  ///
+  /// To reset the transformation, pass `Matrix::identity()`.
+  ///
+  /// The `transform` property is animatable.
+  ///
+  /// _Since 1.12_
+  fn set_transform(&mut self, transform: &Matrix) {
+    unsafe {
+      clutter_actor_set_transform(self.as_actor(), transform);
+    }
+  }
+
+  /// Retrieves the current transformation matrix of the actor, relative to
+  /// its parent.
+  ///
+  /// Unlike the matrix set using `.set_transform()`, it includes the
+  /// position of the actor, its scale, rotation and translation, and the
+  /// child transformation of its parent.
+  ///
+  /// _Since 1.12_
+  fn get_transform(&mut self) -> Matrix {
+    unsafe {
+      let mut transform:Matrix = std::intrinsics::init();
+      clutter_actor_get_transform(self.as_actor(), &mut transform);
+      return transform;
+    }
+  }
+
+  /// Sets the transformation matrix to be applied to all the children of the
+  /// actor prior to their own transformations.
+  ///
+  /// The default child transformation is the identity matrix; passing
+  /// `Matrix::identity()` resets it.
+  ///
+  /// The `child-transform` property is animatable.
+  ///
+  /// _Since 1.12_
+  fn set_child_transform(&mut self, transform: &Matrix) {
+    unsafe {
+      clutter_actor_set_child_transform(self.as_actor(), transform);
+    }
+  }
+
+  /// Retrieves the child transformation matrix set using
+  /// `.set_child_transform()`.
+  ///
+  /// _Since 1.12_
+  fn get_child_transform(&mut self) -> Matrix {
+    unsafe {
+      let mut transform:Matrix = std::intrinsics::init();
+      clutter_actor_get_child_transform(self.as_actor(), &mut transform);
+      return transform;
     }
   }
 
+  /// Retrieves the full transformation of the actor relative to the stage,
+  /// by composing its `.get_transform()` with the transformations of each of
+  /// its ancestors.
+  ///
+  /// The transformation of an actor, as returned by `.get_transform()`,
+  /// already includes its allocation origin and the child transformation of
+  /// its parent, so the matrices are composed as they are; this is the same
+  /// composition Clutter uses when painting the actor.
+  ///
+  /// The stage's own projection and viewport are not included.
+  ///
+  /// _Since 1.12_
+  fn get_transformed_matrix(&mut self) -> Matrix {
+    let mut matrix = self.get_transform();
+    let mut parent = self.get_parent();
+    while !parent.opaque.is_null() {
+      matrix = parent.get_transform().multiply(&matrix);
+      let next = parent.get_parent();
+      // The parent is still owned by the scene graph.
+      std::mem::forget(parent);
+      parent = next;
+    }
+
+    std::mem::forget(parent);
+    return matrix;
+  }
+
   /// Transforms point in coordinates relative to the actor into
   /// screen-relative coordinates with the current actor transformation
   /// (i.e. scale, rotation, etc).
   ///
   /// _Since 0.4_
-  fn apply_transform_to_point(&mut self, x: f32, y: f32) -> (bool, f32, f32) {
+  fn apply_transform_to_point(&mut self, point: &Vertex) -> Vertex {
     unsafe {
-      let mut x_out:f32 = std::intrinsics::init();
-      let mut y_out:f32 = std::intrinsics::init();
-      let foreign_result = clutter_actor_apply_transform_to_point(self.as_actor(), x, y, &mut x_out, &mut y_out);
-      return (foreign_result != 0, x_out, y_out);
+      let mut vertex:Vertex = std::intrinsics::init();
+      clutter_actor_apply_transform_to_point(self.as_actor(), point, &mut vertex);
+      return vertex;
     }
   }
 
@@ -1163,24 +2011,28 @@ pub trait Actor {
   /// of the `.paint()` implementation.
   ///
   /// _Since 0.6_
-  fn transform_stage_point(&mut self, x: f32, y: f32) -> (bool, f32, f32) {
+  fn transform_stage_point(&mut self, point: &Point) -> Option<Point> {
     unsafe {
       let mut x_out:f32 = std::intrinsics::init();
       let mut y_out:f32 = std::intrinsics::init();
-      let foreign_result = clutter_actor_transform_stage_point(self.as_actor(), x, y, &mut x_out, &mut y_out);
-      return (foreign_result != 0, x_out, y_out);
+      let foreign_result = clutter_actor_transform_stage_point(self.as_actor(), point.x, point.y, &mut x_out, &mut y_out);
+      if foreign_result == 0 {
+        return None;
+      }
+
+      return Some(Point::new(x_out, y_out));
     }
   }
 
   /// Gets the absolute position of an actor, in pixels relative to the stage.
   ///
   /// _Since 0.8_
-  fn get_transformed_position(&mut self) -> (f32, f32) {
+  fn get_transformed_position(&mut self) -> Point {
     unsafe {
       let mut x:f32 = std::intrinsics::init();
       let mut y:f32 = std::intrinsics::init();
       clutter_actor_get_transformed_position(self.as_actor(), &mut x, &mut y);
-      return (x, y);
+      return Point::new(x, y);
     }
   }
 
@@ -1205,12 +2057,12 @@ pub trait Actor {
   /// quadrangle.
   ///
   /// _Since 0.8_
-  fn get_transformed_size(&mut self) -> (f32, f32) {
+  fn get_transformed_size(&mut self) -> Size {
     unsafe {
       let mut width:f32 = std::intrinsics::init();
       let mut height:f32 = std::intrinsics::init();
       clutter_actor_get_transformed_size(self.as_actor(), &mut width, &mut height);
-      return (width, height);
+      return Size::new(width, height);
     }
   }
 
@@ -1288,15 +2140,100 @@ pub trait Actor {
     }
   }
 
+  /// Sets the gravity of the Content used by the actor.
+  ///
+  /// The gravity defines where the content will be painted inside the
+  /// allocation of the actor, or whether it is resized to fill it.
+  ///
+  /// _Since 1.10_
+  fn set_content_gravity(&mut self, gravity: ContentGravity) {
+    unsafe {
+      clutter_actor_set_content_gravity(self.as_actor(), gravity);
+    }
+  }
+
+  /// Retrieves the content gravity as set using `.set_content_gravity()`.
+  ///
+  /// _Since 1.10_
+  fn get_content_gravity(&mut self) -> ContentGravity {
+    unsafe {
+      let foreign_result = clutter_actor_get_content_gravity(self.as_actor());
+      return foreign_result;
+    }
+  }
+
+  /// Sets the policy for repeating the Content of the actor, when it is
+  /// smaller than the content box.
+  ///
+  /// The content is only repeated with the gravities that do not resize it.
+  ///
+  /// _Since 1.12_
+  fn set_content_repeat(&mut self, repeat: ContentRepeat) {
+    unsafe {
+      clutter_actor_set_content_repeat(self.as_actor(), repeat);
+    }
+  }
+
+  /// Retrieves the repeat policy set using `.set_content_repeat()`.
+  ///
+  /// _Since 1.12_
+  fn get_content_repeat(&mut self) -> ContentRepeat {
+    unsafe {
+      let foreign_result = clutter_actor_get_content_repeat(self.as_actor());
+      return foreign_result;
+    }
+  }
+
+  /// Retrieves the bounding box for the Content of the actor.
+  ///
+  /// The bounding box is relative to the allocation of the actor, and
+  /// depends on the content gravity: with `ContentGravity::ResizeAspect`,
+  /// for instance, it is the letterboxed area actually painted. If the actor
+  /// has no content, or the content has no preferred size, the box covers
+  /// the whole allocation.
+  ///
+  /// _Since 1.10_
+  fn get_content_box(&mut self) -> Box {
+    unsafe {
+      let mut content_box = Box::new(0.0, 0.0, 0.0, 0.0);
+      clutter_actor_get_content_box(self.as_actor(), &mut content_box);
+      return content_box;
+    }
+  }
+
+  /// Sets the background color of the actor.
+  ///
+  /// The background color will be used to cover the whole allocation of the
+  /// actor. The default background color of an actor is transparent.
+  ///
+  /// _Since 1.10_
+  fn set_background_color(&mut self, color: &super::color::Color) {
+    unsafe {
+      clutter_actor_set_background_color(self.as_actor(), color.opaque);
+    }
+  }
+
+  /// Retrieves the background color of the actor.
+  ///
+  /// _Since 1.10_
+  fn get_background_color(&mut self) -> super::color::Color {
+    unsafe {
+      let color = super::color::Color::new(0, 0, 0, 0);
+      clutter_actor_get_background_color(self.as_actor(), color.opaque);
+      return color;
+    }
+  }
+
   /// Sets clip area for the actor.
   ///
   /// The clip area is always computed from the upper left corner of the actor,
   /// even if the anchor point is set otherwise.
   ///
   /// _Since 0.6_
-  fn set_clip(&mut self, xoff: f32, yoff: f32, width: f32, height: f32) {
+  fn set_clip(&mut self, clip: &Rect) {
     unsafe {
-      clutter_actor_set_clip(self.as_actor(), xoff, yoff, width, height);
+      let clip = clip.normalize();
+      clutter_actor_set_clip(self.as_actor(), clip.origin.x, clip.origin.y, clip.size.width, clip.size.height);
     }
   }
 
@@ -1320,14 +2257,14 @@ pub trait Actor {
   /// Gets the clip area for the actor, if any is set.
   ///
   /// _Since 0.6_
-  fn get_clip(&mut self) -> (f32, f32, f32, f32) {
+  fn get_clip(&mut self) -> Rect {
     unsafe {
       let mut xoff:f32 = std::intrinsics::init();
       let mut yoff:f32 = std::intrinsics::init();
       let mut width:f32 = std::intrinsics::init();
       let mut height:f32 = std::intrinsics::init();
       clutter_actor_get_clip(self.as_actor(), &mut xoff, &mut yoff, &mut width, &mut height);
-      return (xoff, yoff, width, height);
+      return Rect::new(xoff, yoff, width, height);
     }
   }
 
@@ -1387,6 +2324,30 @@ pub trait Actor {
     }
   }
 
+  /// Sets the LayoutManager delegate object that will be used to lay out the
+  /// children of the actor.
+  ///
+  /// The Actor will take a reference on the passed `manager` which will be
+  /// released either when the layout manager is removed, or when the actor is
+  /// destroyed.
+  ///
+  /// _Since 1.10_
+  fn set_layout_manager<T: LayoutManager>(&mut self, manager: &mut T) {
+    unsafe {
+      clutter_actor_set_layout_manager(self.as_actor(), manager.as_layout_manager());
+    }
+  }
+
+  /// Retrieves the LayoutManager used by the actor.
+  ///
+  /// _Since 1.10_
+  fn get_layout_manager(&mut self) -> super::layout::LayoutManagerRef {
+    unsafe {
+      let foreign_result = clutter_actor_get_layout_manager(self.as_actor());
+      return foreign_result;
+    }
+  }
+
   /// Adds child to the children of the actor.
   ///
   /// This method will acquire a reference on `child` that will only be
@@ -1583,6 +2544,89 @@ pub trait Actor {
     }
   }
 
+  /// Returns an iterator over the children of the actor.
+  ///
+  /// Children can be removed or destroyed while iterating by using
+  /// `ActorIter#remove()` and `ActorIter#destroy()`.
+  ///
+  /// _Since 1.10_
+  fn children(&mut self) -> iter::ActorIter {
+    return iter::ActorIter::new(self);
+  }
+
+  /// Returns an iterator over all the descendants of the actor, visited in
+  /// the given `order`.
+  fn descendants(&mut self, order: iter::TraversalOrder) -> iter::Descendants {
+    let mut root = ActorHandle { opaque: self.as_actor() };
+    return iter::Descendants::new(&mut root, order);
+  }
+
+  /// Returns an iterator over the parent of the actor, the parent of the
+  /// parent and so on, up to the top-level actor.
+  fn ancestors(&mut self) -> iter::Ancestors {
+    let mut actor = ActorHandle { opaque: self.as_actor() };
+    return iter::Ancestors::new(&mut actor);
+  }
+
+  /// Finds the first descendant of the actor with the given `name`, as set
+  /// with `.set_name()`, in depth-first order.
+  fn find_by_name(&mut self, name: &str) -> Option<ActorHandle> {
+    unsafe {
+      for child in self.descendants(iter::TraversalOrder::DepthFirst) {
+        let child_name = clutter_actor_get_name(child.opaque);
+        if !child_name.is_null() && c_str_to_string(child_name as *const i8) == name {
+          return Some(child);
+        }
+      }
+
+      return None;
+    }
+  }
+
+  /// Returns all the descendants of the actor matching `selector`, in
+  /// depth-first order.
+  ///
+  /// See `selector::Selector` for the syntax of selectors.
+  fn select(&mut self, selector: &str) -> Result<Vec<ActorHandle>, selector::SelectorError> {
+    let parsed = try!(selector::Selector::parse(selector));
+    let mut root = ActorHandle { opaque: self.as_actor() };
+    return Ok(parsed.select_all(&mut root));
+  }
+
+  /// Returns the first descendant of the actor matching `selector`, in
+  /// depth-first order.
+  ///
+  /// See `selector::Selector` for the syntax of selectors.
+  fn select_first(&mut self, selector: &str) -> Result<Option<ActorHandle>, selector::SelectorError> {
+    let parsed = try!(selector::Selector::parse(selector));
+    let mut root = ActorHandle { opaque: self.as_actor() };
+    return Ok(parsed.select_first(&mut root));
+  }
+
+  /// Captures the state of the actor and of all its descendants.
+  ///
+  /// See `snapshot::Node#to_json()` to serialize the result.
+  fn snapshot(&mut self) -> snapshot::Node {
+    let mut root = ActorHandle { opaque: self.as_actor() };
+    return snapshot::Node::new(&mut root);
+  }
+
+  /// Describes the layout of the actor and of all its descendants.
+  ///
+  /// The result has one line per actor, indented by depth, listing the name
+  /// (or the type name, for unnamed actors), the request mode, the minimum
+  /// and natural sizes, the expand flags as set with `.set_x_expand()` and
+  /// `.set_y_expand()`, the result of `.needs_expand()` on both axes, and the
+  /// allocation box. It is meant to be printed when debugging layouts; the
+  /// format may change.
+  fn dump_layout(&mut self) -> String {
+    let mut root = ActorRef { opaque: self.as_actor() };
+    let mut out = String::new();
+    dump_layout_node(&mut root, 0, &mut out);
+    std::mem::forget(root);
+    return out;
+  }
+
   /// Retrieves the parent of the actor.
   ///
   /// _Since 1.10_
@@ -1701,6 +2745,27 @@ pub trait Actor {
     }
   }
 
+  /// Sets the easing mode for the tweening of animatable properties of the
+  /// actor.
+  ///
+  /// _Since 1.10_
+  fn set_easing_mode(&mut self, mode: AnimationMode) {
+    unsafe {
+      clutter_actor_set_easing_mode(self.as_actor(), mode);
+    }
+  }
+
+  /// Retrieves the easing mode for the tweening of animatable properties of
+  /// the actor for the current easing state.
+  ///
+  /// _Since 1.10_
+  fn get_easing_mode(&mut self) -> AnimationMode {
+    unsafe {
+      let foreign_result = clutter_actor_get_easing_mode(self.as_actor());
+      return foreign_result;
+    }
+  }
+
   /// Sets the delay that should be applied before tweening animatable
   /// properties.
   ///
@@ -1770,6 +2835,29 @@ pub trait Actor {
     }
   }
 
+  /// Adds `action` to the list of actions applied to the actor.
+  ///
+  /// An Action can only belong to one actor at a time. The Actor will hold a
+  /// reference on `action` until it is removed.
+  ///
+  /// _Since 1.4_
+  fn add_action<T: Action>(&mut self, action: &mut T) {
+    unsafe {
+      clutter_actor_add_action(self.as_actor(), action.as_action());
+    }
+  }
+
+  /// A convenience method for setting the name of an Action while adding it
+  /// to the actor.
+  ///
+  /// _Since 1.4_
+  fn add_action_with_name<T: Action>(&mut self, name: &str, action: &mut T) {
+    unsafe {
+      use std::ffi::CString;
+      clutter_actor_add_action_with_name(self.as_actor(), name.to_c_str().unwrap() as *mut i8, action.as_action());
+    }
+  }
+
   /// Returns whether the actor has any actions applied.
   ///
   /// _Since 1.10_
@@ -1792,11 +2880,102 @@ pub trait Actor {
     }
   }
 
-  //FIXME doc
-  fn on_allocation_changed(&mut self, handler: &mut ActorRef, &Box, allocation::Flags) -> u64 {
+  /// A convenience method for setting the name of a Constraint while adding
+  /// it to the actor.
+  ///
+  /// _Since 1.4_
+  fn add_constraint_with_name<T: Constraint>(&mut self, name: &str, constraint: &mut T) {
     unsafe {
-      let null_void: *mut libc::c_void = std::ptr::null_mut();
-      return rsi_connect_on_allocation_changed(self.as_actor(), "allocation_changed".to_c_str().unwrap() as *mut i8, handler_for_on_allocation_changed, std::mem::transmute::<&&mut ActorRef, &Box, allocation::Flags, *mut libc::c_void>(handler), null_void, 0);
+      use std::ffi::CString;
+      clutter_actor_add_constraint_with_name(self.as_actor(), name.to_c_str().unwrap() as *mut i8, constraint.as_constraint());
+    }
+  }
+
+  /// Removes `constraint` from the list of constraints applied to the actor.
+  ///
+  /// The reference held by the actor on the Constraint will be released.
+  ///
+  /// _Since 1.4_
+  fn remove_constraint<T: Constraint>(&mut self, constraint: &mut T) {
+    unsafe {
+      clutter_actor_remove_constraint(self.as_actor(), constraint.as_constraint());
+    }
+  }
+
+  /// Removes the Constraint with the given name from the list of constraints
+  /// applied to the actor.
+  ///
+  /// _Since 1.4_
+  fn remove_constraint_by_name(&mut self, name: &str) {
+    unsafe {
+      use std::ffi::CString;
+      clutter_actor_remove_constraint_by_name(self.as_actor(), name.to_c_str().unwrap() as *mut i8);
+    }
+  }
+
+  /// Retrieves the Constraint with the given name in the list of constraints
+  /// applied to the actor.
+  ///
+  /// Returns __None__ if no constraint with that name exists.
+  ///
+  /// _Since 1.4_
+  fn get_constraint(&mut self, name: &str) -> Option<super::constraint::ConstraintRef> {
+    unsafe {
+      use std::ffi::CString;
+      let foreign_result = clutter_actor_get_constraint(self.as_actor(), name.to_c_str().unwrap() as *mut i8);
+      if foreign_result.is_null() {
+        return None;
+      }
+
+      return Some(std::mem::transmute::<*mut libc::c_void, super::constraint::ConstraintRef>(foreign_result));
+    }
+  }
+
+  /// Retrieves the list of constraints applied to the actor.
+  ///
+  /// The constraints are still owned by the actor.
+  ///
+  /// _Since 1.4_
+  fn get_constraints(&mut self) -> Vec<super::constraint::ConstraintRef> {
+    unsafe {
+      let constraints = take_list(clutter_actor_get_constraints(self.as_actor()));
+      return constraints.into_iter().map(|constraint| std::mem::transmute::<*mut libc::c_void, super::constraint::ConstraintRef>(constraint)).collect();
+    }
+  }
+
+  /// Returns whether the actor has any constraints applied.
+  ///
+  /// _Since 1.10_
+  fn has_constraints(&mut self) -> bool {
+    unsafe {
+      let foreign_result = clutter_actor_has_constraints(self.as_actor());
+      return foreign_result != 0;
+    }
+  }
+
+  /// Clears the list of constraints applied to the actor.
+  ///
+  /// _Since 1.4_
+  fn clear_constraints(&mut self) {
+    unsafe {
+      clutter_actor_clear_constraints(self.as_actor());
+    }
+  }
+
+  /// Connects a handler to the `allocation-changed` signal, which is emitted
+  /// when the allocation of the actor changes.
+  ///
+  /// The handler receives the actor, its new allocation box, and the flags
+  /// passed to `.allocate()`. It is dropped when the actor is destroyed.
+  ///
+  /// Returns the id of the signal handler.
+  ///
+  /// _Since 1.0_
+  fn on_allocation_changed(&mut self, handler: std::boxed::Box<FnMut(&mut ActorRef, &Box, allocation::Flags) + 'static>) -> u64 {
+    unsafe {
+      use std::ffi::CString;
+      let data: *mut libc::c_void = std::mem::transmute(std::boxed::Box::new(handler));
+      return rsi_connect_on_allocation_changed(self.as_actor(), "allocation-changed".to_c_str().unwrap() as *mut i8, handler_for_on_allocation_changed, data, destroy_on_allocation_changed, 0);
     }
   }
 
@@ -1815,16 +2994,119 @@ impl Actor for ActorRef {
   }
 }
 
+/// A reference to an actor owned by the scene graph.
+///
+/// Unlike ActorRef, dropping an ActorHandle does not destroy the actor; it
+/// is returned by the methods walking the actor tree, such as
+/// `Actor#children()`. The handle is only valid as long as the actor is
+/// alive.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ActorHandle {
+  opaque: *mut libc::c_void
+}
+
+impl ActorHandle {
+  /// Turns the handle into an ActorRef, which destroys the actor when it is
+  /// dropped.
+  pub fn into_actor_ref(self) -> ActorRef {
+    return ActorRef { opaque: self.opaque };
+  }
+}
+
+impl Actor for ActorHandle {
+  fn as_actor(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+impl FromActor for ActorHandle {
+  unsafe fn from_actor(actor: *mut libc::c_void) -> ActorHandle {
+    return ActorHandle { opaque: actor };
+  }
+}
+
+/// A type that can wrap a pointer to an actor without owning it, such as
+/// ActorHandle.
+pub trait FromActor {
+  /// Wraps `actor`, which must be an instance of the type; dropping the
+  /// result must not destroy or release the actor.
+  unsafe fn from_actor(actor: *mut libc::c_void) -> Self;
+}
+
+/// Checks whether the Visible flag of `actor` is set.
+///
+/// This is what the CLUTTER_ACTOR_IS_VISIBLE macro does; the
+/// clutter_actor_is_visible() function needs Clutter 1.24.
+fn is_visible(actor: *mut libc::c_void) -> bool {
+  unsafe {
+    return rsi_get_flags(actor) & (Flags::Visible as u32) != 0;
+  }
+}
+
+/// Appends the description of `actor` and its descendants to `out`, for
+/// `Actor#dump_layout()`.
+fn dump_layout_node(actor: &mut ActorRef, depth: usize, out: &mut String) {
+  unsafe {
+    let name = clutter_actor_get_name(actor.as_actor());
+    let label = if name.is_null() {
+      type_name_of(actor.as_actor())
+    } else {
+      format!("\"{}\"", c_str_to_string(name as *const i8))
+    };
+
+    let mode = match actor.get_request_mode() {
+      RequestMode::HeightForWidth => "height-for-width",
+      RequestMode::WidthForHeight => "width-for-height"
+    };
+
+    let mut min_width: f32 = 0.0;
+    let mut min_height: f32 = 0.0;
+    let mut natural_width: f32 = 0.0;
+    let mut natural_height: f32 = 0.0;
+    clutter_actor_get_preferred_size(actor.as_actor(), &mut min_width, &mut min_height, &mut natural_width, &mut natural_height);
+
+    let allocation = actor.get_allocation_box();
+    for _ in 0..depth {
+      out.push_str("  ");
+    }
+    out.push_str(format!("{} mode={} min={}x{} natural={}x{} expand=({}, {}) needs-expand=({}, {}) allocation=({}, {})-({}, {})\n",
+                         label, mode,
+                         min_width, min_height, natural_width, natural_height,
+                         actor.get_x_expand(), actor.get_y_expand(),
+                         actor.needs_expand(Orientation::Horizontal), actor.needs_expand(Orientation::Vertical),
+                         allocation.x1, allocation.y1, allocation.x2, allocation.y2).as_slice());
+
+    let mut child = actor.get_first_child();
+    while !child.opaque.is_null() {
+      dump_layout_node(&mut child, depth + 1, out);
+      let next = child.get_next_sibling();
+      // The children are still owned by the scene graph.
+      std::mem::forget(child);
+      child = next;
+    }
+    std::mem::forget(child);
+  }
+}
+
 //FIXME doc
-extern "C" fn handler_for_on_allocation_changed(actor: *mut libc::c_void, allocation_box: *mut Box, flags: allocation::Flags, handler: *mut libc::c_void) {
+extern "C" fn handler_for_on_allocation_changed(actor: *mut libc::c_void, allocation_box: *const Box, flags: allocation::Flags, handler: *mut libc::c_void) {
   unsafe {
     let mut actor_r = ActorRef { opaque: actor };
-    let handler = std::mem::transmute::<*mut libc::c_void, &mut FnMut(&mut ActorRef, &Box,  allocation::Flags)>(handler);
-    (*handler)(&mut actor_r, std::mem::transmute(allocation_box), flags);
+    let handler = std::mem::transmute::<*mut libc::c_void, &mut std::boxed::Box<FnMut(&mut ActorRef, &Box, allocation::Flags)>>(handler);
+    (*handler)(&mut actor_r, &*allocation_box, flags);
     std::mem::forget(actor_r);
   }
 }
 
+/// Frees the handler connected by `.on_allocation_changed()`.
+extern "C" fn destroy_on_allocation_changed(handler: *mut libc::c_void, _closure: *mut libc::c_void) {
+  unsafe {
+    let handler = std::mem::transmute::<*mut libc::c_void, std::boxed::Box<std::boxed::Box<FnMut(&mut ActorRef, &Box, allocation::Flags)>>>(handler);
+    drop(handler);
+  }
+}
+
 //FIXME doc
 extern "C" fn handler_for_on_destroy(actor: *mut libc::c_void, handler: *mut libc::c_void) {
   unsafe {
@@ -1838,12 +3120,14 @@ extern "C" fn handler_for_on_destroy(actor: *mut libc::c_void, handler: *mut lib
 extern {
   fn clutter_actor_new() -> ActorRef;
   #[link_name = "g_signal_connect_data"]
-  fn rsi_connect_on_allocation_changed(instance: *mut libc::c_void, detailed_signal: *mut libc::c_char, c_handler: extern "C" fn(*mut libc::c_void, *mut Box, allocation::Flags, *mut libc::c_void), data: *mut libc::c_void, destroy_data: *mut libc::c_void, connect_flags: i32) -> u64;
+  fn rsi_connect_on_allocation_changed(instance: *mut libc::c_void, detailed_signal: *mut libc::c_char, c_handler: extern "C" fn(*mut libc::c_void, *const Box, allocation::Flags, *mut libc::c_void), data: *mut libc::c_void, destroy_data: extern "C" fn(*mut libc::c_void, *mut libc::c_void), connect_flags: i32) -> u64;
   #[link_name = "g_signal_connect_data"]
   fn rsi_connect_on_destroy(instance: *mut libc::c_void, detailed_signal: *mut libc::c_char, c_handler: extern "C" fn(*mut libc::c_void, *mut libc::c_void), data: *mut libc::c_void, destroy_data: *mut libc::c_void, connect_flags: i32) -> u64;
   fn clutter_actor_set_flags(self_value: *mut libc::c_void, flags: Flags);
   fn clutter_actor_unset_flags(self_value: *mut libc::c_void, flags: Flags);
   fn clutter_actor_get_flags(self_value: *mut libc::c_void) -> Flags;
+  #[link_name = "clutter_actor_get_flags"]
+  fn rsi_get_flags(self_value: *mut libc::c_void) -> u32;
   fn clutter_actor_set_name(self_value: *mut libc::c_void, name: *mut libc::c_char);
   fn clutter_actor_get_name(self_value: *mut libc::c_void) -> *mut i8;
   fn clutter_actor_get_gid(self_value: *mut libc::c_void) -> i32;
@@ -1862,10 +3146,19 @@ extern {
   fn clutter_actor_map(self_value: *mut libc::c_void);
   fn clutter_actor_unmap(self_value: *mut libc::c_void);
   fn clutter_actor_has_overlaps(self_value: *mut libc::c_void) -> i32;
+  fn clutter_actor_set_offscreen_redirect(self_value: *mut libc::c_void, redirect: OffscreenRedirect);
+  fn clutter_actor_get_offscreen_redirect(self_value: *mut libc::c_void) -> i32;
   fn clutter_actor_has_mapped_clones(self_value: *mut libc::c_void) -> i32;
   fn clutter_actor_get_preferred_size(self_value: *mut libc::c_void, min_width_p: *mut f32, min_height_p: *mut f32, natural_width_p: *mut f32, natural_height_p: *mut f32);
   fn clutter_actor_get_preferred_width(self_value: *mut libc::c_void, for_height: f32, min_width_p: *mut f32, natural_width_p: *mut f32);
   fn clutter_actor_get_preferred_height(self_value: *mut libc::c_void, for_width: f32, min_height_p: *mut f32, natural_height_p: *mut f32);
+  fn clutter_actor_allocate(self_value: *mut libc::c_void, allocation: *const Box, flags: allocation::Flags);
+  fn clutter_actor_allocate_preferred_size(self_value: *mut libc::c_void, flags: allocation::Flags);
+  fn clutter_actor_allocate_align_fill(self_value: *mut libc::c_void, allocation: *const Box, x_align: f64, y_align: f64, x_fill: i32, y_fill: i32, flags: allocation::Flags);
+  fn clutter_actor_allocate_available_size(self_value: *mut libc::c_void, x: f32, y: f32, available_width: f32, available_height: f32, flags: allocation::Flags);
+  fn clutter_actor_get_allocation_box(self_value: *mut libc::c_void, allocation: *mut Box);
+  fn clutter_actor_get_allocation_vertices(self_value: *mut libc::c_void, ancestor: *mut libc::c_void, verts: *mut Vertex);
+  fn clutter_actor_get_abs_allocation_vertices(self_value: *mut libc::c_void, verts: *mut Vertex);
   fn clutter_actor_set_fixed_position_set(self_value: *mut libc::c_void, fixed_position_set: i32);
   fn clutter_actor_get_fixed_position_set(self_value: *mut libc::c_void) -> i32;
   fn clutter_actor_has_allocation(self_value: *mut libc::c_void) -> i32;
@@ -1873,7 +3166,23 @@ extern {
   fn clutter_actor_get_x_expand(self_value: *mut libc::c_void) -> i32;
   fn clutter_actor_set_y_expand(self_value: *mut libc::c_void, expand: i32);
   fn clutter_actor_get_y_expand(self_value: *mut libc::c_void) -> i32;
-  fn clutter_actor_needs_expand(self_value: *mut libc::c_void) -> i32;
+  fn clutter_actor_needs_expand(self_value: *mut libc::c_void, orientation: Orientation) -> i32;
+  fn clutter_actor_set_request_mode(self_value: *mut libc::c_void, mode: RequestMode);
+  fn clutter_actor_get_request_mode(self_value: *mut libc::c_void) -> RequestMode;
+  fn clutter_actor_set_x_align(self_value: *mut libc::c_void, x_align: ActorAlign);
+  fn clutter_actor_get_x_align(self_value: *mut libc::c_void) -> ActorAlign;
+  fn clutter_actor_set_y_align(self_value: *mut libc::c_void, y_align: ActorAlign);
+  fn clutter_actor_get_y_align(self_value: *mut libc::c_void) -> ActorAlign;
+  fn clutter_actor_set_margin(self_value: *mut libc::c_void, margin: *const Margin);
+  fn clutter_actor_get_margin(self_value: *mut libc::c_void, margin: *mut Margin);
+  fn clutter_actor_set_margin_top(self_value: *mut libc::c_void, margin: f32);
+  fn clutter_actor_get_margin_top(self_value: *mut libc::c_void) -> f32;
+  fn clutter_actor_set_margin_bottom(self_value: *mut libc::c_void, margin: f32);
+  fn clutter_actor_get_margin_bottom(self_value: *mut libc::c_void) -> f32;
+  fn clutter_actor_set_margin_left(self_value: *mut libc::c_void, margin: f32);
+  fn clutter_actor_get_margin_left(self_value: *mut libc::c_void) -> f32;
+  fn clutter_actor_set_margin_right(self_value: *mut libc::c_void, margin: f32);
+  fn clutter_actor_get_margin_right(self_value: *mut libc::c_void) -> f32;
   fn clutter_actor_set_size(self_value: *mut libc::c_void, for_width: f32, for_height: f32);
   fn clutter_actor_get_size(self_value: *mut libc::c_void, width: *mut f32, height: *mut f32);
   fn clutter_actor_set_position(self_value: *mut libc::c_void, x: f32, y: f32);
@@ -1898,10 +3207,16 @@ extern {
   fn clutter_actor_set_scale_z(self_value: *mut libc::c_void, pivot_z: f32);
   fn clutter_actor_get_scale_z(self_value: *mut libc::c_void) -> f32;
   fn clutter_actor_is_scaled(self_value: *mut libc::c_void) -> i32;
+  fn clutter_actor_set_rotation_angle(self_value: *mut libc::c_void, axis: RotateAxis, angle: f64);
+  fn clutter_actor_get_rotation_angle(self_value: *mut libc::c_void, axis: RotateAxis) -> f64;
   fn clutter_actor_is_rotated(self_value: *mut libc::c_void) -> i32;
   fn clutter_actor_set_translation(self_value: *mut libc::c_void, translate_x: f32, translate_y: f32, translate_z: f32);
   fn clutter_actor_get_translation(self_value: *mut libc::c_void, translate_x: *mut f32, translate_y: *mut f32, translate_z: *mut f32);
-  fn clutter_actor_apply_transform_to_point(self_value: *mut libc::c_void, x: f32, y: f32, x_out: *mut f32, y_out: *mut f32) -> i32;
+  fn clutter_actor_set_transform(self_value: *mut libc::c_void, transform: *const Matrix);
+  fn clutter_actor_get_transform(self_value: *mut libc::c_void, transform: *mut Matrix);
+  fn clutter_actor_set_child_transform(self_value: *mut libc::c_void, transform: *const Matrix);
+  fn clutter_actor_get_child_transform(self_value: *mut libc::c_void, transform: *mut Matrix);
+  fn clutter_actor_apply_transform_to_point(self_value: *mut libc::c_void, point: *const Vertex, vertex: *mut Vertex);
   fn clutter_actor_transform_stage_point(self_value: *mut libc::c_void, x: f32, y: f32, x_out: *mut f32, y_out: *mut f32) -> i32;
   fn clutter_actor_get_transformed_position(self_value: *mut libc::c_void, x: *mut f32, y: *mut f32);
   fn clutter_actor_get_transformed_size(self_value: *mut libc::c_void, width: *mut f32, height: *mut f32);
@@ -1911,6 +3226,13 @@ extern {
   fn clutter_actor_get_content(self_value: *mut libc::c_void) -> super::content::ContentRef;
   fn clutter_actor_set_content_scaling_filters(self_value: *mut libc::c_void, min: super::scaling::Filter, mag: super::scaling::Filter);
   fn clutter_actor_get_content_scaling_filters(self_value: *mut libc::c_void, min: *mut super::scaling::Filter, mag: *mut super::scaling::Filter);
+  fn clutter_actor_set_content_gravity(self_value: *mut libc::c_void, gravity: ContentGravity);
+  fn clutter_actor_get_content_gravity(self_value: *mut libc::c_void) -> ContentGravity;
+  fn clutter_actor_set_content_repeat(self_value: *mut libc::c_void, repeat: ContentRepeat);
+  fn clutter_actor_get_content_repeat(self_value: *mut libc::c_void) -> ContentRepeat;
+  fn clutter_actor_get_content_box(self_value: *mut libc::c_void, content_box: *mut Box);
+  fn clutter_actor_set_background_color(self_value: *mut libc::c_void, color: *mut libc::c_void);
+  fn clutter_actor_get_background_color(self_value: *mut libc::c_void, color: *mut libc::c_void);
   fn clutter_actor_set_clip(self_value: *mut libc::c_void, xoff: f32, yoff: f32, width: f32, height: f32);
   fn clutter_actor_remove_clip(self_value: *mut libc::c_void);
   fn clutter_actor_has_clip(self_value: *mut libc::c_void) -> i32;
@@ -1920,6 +3242,8 @@ extern {
   fn clutter_actor_set_opacity(self_value: *mut libc::c_void, opacity: i8);
   fn clutter_actor_get_opacity(self_value: *mut libc::c_void) -> i8;
   fn clutter_actor_is_in_clone_paint(self_value: *mut libc::c_void) -> i32;
+  fn clutter_actor_set_layout_manager(self_value: *mut libc::c_void, manager: *mut libc::c_void);
+  fn clutter_actor_get_layout_manager(self_value: *mut libc::c_void) -> super::layout::LayoutManagerRef;
   fn clutter_actor_add_child(self_value: *mut libc::c_void, child: *mut libc::c_void);
   fn clutter_actor_insert_child_above(self_value: *mut libc::c_void, child: *mut libc::c_void, sibling: *mut libc::c_void);
   fn clutter_actor_insert_child_at_index(self_value: *mut libc::c_void, child: *mut libc::c_void, index: i32);
@@ -1944,6 +3268,8 @@ extern {
   fn clutter_actor_restore_easing_state(self_value: *mut libc::c_void);
   fn clutter_actor_set_easing_duration(self_value: *mut libc::c_void, msecs: i32);
   fn clutter_actor_get_easing_duration(self_value: *mut libc::c_void) -> i32;
+  fn clutter_actor_set_easing_mode(self_value: *mut libc::c_void, mode: AnimationMode);
+  fn clutter_actor_get_easing_mode(self_value: *mut libc::c_void) -> AnimationMode;
   fn clutter_actor_set_easing_delay(self_value: *mut libc::c_void, msecs: i32);
   fn clutter_actor_get_easing_delay(self_value: *mut libc::c_void) -> i32;
   fn clutter_actor_set_reactive(self_value: *mut libc::c_void, reactive: i32);
@@ -1952,7 +3278,16 @@ extern {
   fn clutter_actor_grab_key_focus(self_value: *mut libc::c_void);
   fn clutter_actor_has_pointer(self_value: *mut libc::c_void) -> i32;
   fn clutter_actor_has_actions(self_value: *mut libc::c_void) -> i32;
+  fn clutter_actor_add_action(self_value: *mut libc::c_void, action: *mut libc::c_void);
+  fn clutter_actor_add_action_with_name(self_value: *mut libc::c_void, name: *mut libc::c_char, action: *mut libc::c_void);
   fn clutter_actor_add_constraint(self_value: *mut libc::c_void, constraint: *mut libc::c_void);
+  fn clutter_actor_add_constraint_with_name(self_value: *mut libc::c_void, name: *mut libc::c_char, constraint: *mut libc::c_void);
+  fn clutter_actor_remove_constraint(self_value: *mut libc::c_void, constraint: *mut libc::c_void);
+  fn clutter_actor_remove_constraint_by_name(self_value: *mut libc::c_void, name: *mut libc::c_char);
+  fn clutter_actor_get_constraint(self_value: *mut libc::c_void, name: *mut libc::c_char) -> *mut libc::c_void;
+  fn clutter_actor_get_constraints(self_value: *mut libc::c_void) -> *mut List;
+  fn clutter_actor_has_constraints(self_value: *mut libc::c_void) -> i32;
+  fn clutter_actor_clear_constraints(self_value: *mut libc::c_void);
 }
 
 /// Opaque struct which holds a reference to the underlying Clutter object.
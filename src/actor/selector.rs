@@ -0,0 +1,369 @@
+#![stable]
+
+use libc;
+use super::{is_visible, Actor, ActorHandle};
+use super::iter::{Descendants, TraversalOrder};
+use super::super::ffi::{c_str_to_string, type_name_of};
+
+/// An error found while parsing a selector.
+#[derive(Clone, PartialEq, Debug)]
+pub struct SelectorError {
+  /// The byte offset of the error in the selector
+  pub position: usize,
+
+  /// A description of the error
+  pub message: String
+}
+
+/// How a compound selector relates to the one before it.
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Combinator {
+  /// The actor is any descendant of an actor matching the previous part
+  Descendant,
+
+  /// The actor is a child of an actor matching the previous part
+  Child
+}
+
+/// A set of conditions on a single actor, such as `ClutterText#title:visible`.
+#[derive(Clone, PartialEq, Debug)]
+struct Compound {
+  type_name: Option<String>,
+  name: Option<String>,
+  visible: bool,
+  reactive: bool
+}
+
+/// A parsed selector, matching actors by name, type and state.
+///
+/// The syntax is a small subset of CSS selectors:
+///
+/// - `Text` or `ClutterText` matches actors of exactly that type; the
+///   `Clutter` prefix can be omitted, and `*` matches any type
+/// - `#title` matches actors named "title", as set with `Actor#set_name()`
+/// - `:visible` and `:reactive` match actors that are visible or reactive
+/// - `A B` matches actors matching `B` that are descendants of an actor
+///   matching `A`
+/// - `A > B` matches actors matching `B` that are children of an actor
+///   matching `A`
+///
+/// Type selectors compare the name of the actor's own GType only, so
+/// subclasses do not match: `Actor` matches plain `ClutterActor` instances,
+/// not `ClutterText` or `ClutterStage`, and `*` is the only way to match
+/// actors of any type.
+///
+/// Conditions on the same actor can be combined, as in `Text#title:visible`.
+/// Only the subtree the selector is applied to is considered when matching
+/// ancestors.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Selector {
+  parts: Vec<(Combinator, Compound)>
+}
+
+impl Selector {
+  /// Parses `selector`.
+  pub fn parse(selector: &str) -> Result<Selector, SelectorError> {
+    let bytes = selector.as_bytes();
+    let mut parts = Vec::new();
+    let mut position = 0;
+    let mut combinator = Combinator::Descendant;
+
+    loop {
+      let start = position;
+      position = skip_spaces(bytes, position);
+      if position >= bytes.len() {
+        if parts.is_empty() {
+          return Err(error(position, "empty selector"));
+        }
+        if combinator == Combinator::Child {
+          return Err(error(position, "expected a selector after '>'"));
+        }
+        break;
+      }
+
+      if bytes[position] == b'>' {
+        if parts.is_empty() || combinator == Combinator::Child {
+          return Err(error(position, "unexpected '>'"));
+        }
+        combinator = Combinator::Child;
+        position += 1;
+        continue;
+      }
+
+      if !parts.is_empty() && start == position && combinator == Combinator::Descendant {
+        return Err(error(position, "expected a combinator"));
+      }
+
+      let (compound, end) = try!(parse_compound(selector, position));
+      parts.push((combinator, compound));
+      combinator = Combinator::Descendant;
+      position = end;
+    }
+
+    return Ok(Selector { parts: parts });
+  }
+
+  /// Checks whether `actor` matches the selector, considering its ancestors
+  /// up to and including `root`.
+  pub fn matches<T: Actor, U: Actor>(&self, root: &mut T, actor: &mut U) -> bool {
+    return self.matches_part(self.parts.len() - 1, root.as_actor(), actor.as_actor());
+  }
+
+  /// Returns all the descendants of `root` matching the selector, in
+  /// depth-first order.
+  pub fn select_all<T: Actor>(&self, root: &mut T) -> Vec<ActorHandle> {
+    let root_actor = root.as_actor();
+    let mut result = Vec::new();
+    for actor in Descendants::new(root, TraversalOrder::DepthFirst) {
+      if self.matches_part(self.parts.len() - 1, root_actor, actor.as_actor()) {
+        result.push(actor);
+      }
+    }
+
+    return result;
+  }
+
+  /// Returns the first descendant of `root` matching the selector, in
+  /// depth-first order.
+  pub fn select_first<T: Actor>(&self, root: &mut T) -> Option<ActorHandle> {
+    let root_actor = root.as_actor();
+    for actor in Descendants::new(root, TraversalOrder::DepthFirst) {
+      if self.matches_part(self.parts.len() - 1, root_actor, actor.as_actor()) {
+        return Some(actor);
+      }
+    }
+
+    return None;
+  }
+
+  /// Checks whether `actor` matches the parts of the selector up to `index`.
+  fn matches_part(&self, index: usize, root: *mut libc::c_void, actor: *mut libc::c_void) -> bool {
+    let (combinator, ref compound) = self.parts[index];
+    if !compound_matches(compound, actor) {
+      return false;
+    }
+    if index == 0 {
+      return true;
+    }
+
+    if actor == root {
+      return false;
+    }
+
+    unsafe {
+      let mut ancestor = clutter_actor_get_parent(actor);
+      while !ancestor.is_null() {
+        if self.matches_part(index - 1, root, ancestor) {
+          return true;
+        }
+        if combinator == Combinator::Child || ancestor == root {
+          return false;
+        }
+        ancestor = clutter_actor_get_parent(ancestor);
+      }
+    }
+
+    return false;
+  }
+}
+
+fn compound_matches(compound: &Compound, actor: *mut libc::c_void) -> bool {
+  unsafe {
+    if let Some(ref name) = compound.name {
+      let actor_name = clutter_actor_get_name(actor);
+      if actor_name.is_null() || c_str_to_string(actor_name) != *name {
+        return false;
+      }
+    }
+
+    if let Some(ref type_name) = compound.type_name {
+      if !type_name_matches(type_name.as_slice(), type_name_of(actor).as_slice()) {
+        return false;
+      }
+    }
+
+    if compound.visible && !is_visible(actor) {
+      return false;
+    }
+    if compound.reactive && clutter_actor_get_reactive(actor) == 0 {
+      return false;
+    }
+  }
+
+  return true;
+}
+
+/// Checks whether the selector type `type_name` names `actor_type` exactly,
+/// with or without its `Clutter` prefix.
+fn type_name_matches(type_name: &str, actor_type: &str) -> bool {
+  return actor_type == type_name ||
+         (actor_type.starts_with("Clutter") && &actor_type[7..] == type_name);
+}
+
+/// Parses the compound selector starting at `position`, returning it and the
+/// position after it.
+fn parse_compound(selector: &str, position: usize) -> Result<(Compound, usize), SelectorError> {
+  let bytes = selector.as_bytes();
+  let mut compound = Compound { type_name: None, name: None, visible: false, reactive: false };
+  let mut position = position;
+
+  let mut universal = false;
+  if bytes[position] == b'*' {
+    universal = true;
+    position += 1;
+  } else if is_identifier_byte(bytes[position]) {
+    let end = identifier_end(bytes, position);
+    compound.type_name = Some(selector[position..end].to_string());
+    position = end;
+  }
+
+  let start = position;
+  while position < bytes.len() && bytes[position] != b' ' && bytes[position] != b'>' {
+    match bytes[position] {
+      b'#' => {
+        let end = identifier_end(bytes, position + 1);
+        if end == position + 1 {
+          return Err(error(position, "expected a name after '#'"));
+        }
+        if compound.name.is_some() {
+          return Err(error(position, "more than one name"));
+        }
+        compound.name = Some(selector[(position + 1)..end].to_string());
+        position = end;
+      },
+      b':' => {
+        let end = identifier_end(bytes, position + 1);
+        match &selector[(position + 1)..end] {
+          "visible" => compound.visible = true,
+          "reactive" => compound.reactive = true,
+          _ => return Err(error(position, "unknown pseudo-class"))
+        }
+        position = end;
+      },
+      _ => return Err(error(position, "unexpected character"))
+    }
+  }
+
+  if position == start && compound.type_name.is_none() && !universal {
+    return Err(error(position, "expected a selector"));
+  }
+
+  return Ok((compound, position));
+}
+
+fn is_identifier_byte(byte: u8) -> bool {
+  return (byte >= b'a' && byte <= b'z') || (byte >= b'A' && byte <= b'Z') ||
+         (byte >= b'0' && byte <= b'9') || byte == b'_' || byte == b'-';
+}
+
+fn identifier_end(bytes: &[u8], position: usize) -> usize {
+  let mut end = position;
+  while end < bytes.len() && is_identifier_byte(bytes[end]) {
+    end += 1;
+  }
+
+  return end;
+}
+
+fn skip_spaces(bytes: &[u8], position: usize) -> usize {
+  let mut end = position;
+  while end < bytes.len() && bytes[end] == b' ' {
+    end += 1;
+  }
+
+  return end;
+}
+
+fn error(position: usize, message: &str) -> SelectorError {
+  return SelectorError { position: position, message: message.to_string() };
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{Combinator, Compound, Selector, SelectorError, type_name_matches};
+
+  fn compound(type_name: Option<&str>, name: Option<&str>, visible: bool, reactive: bool) -> Compound {
+    return Compound {
+      type_name: type_name.map(|type_name| type_name.to_string()),
+      name: name.map(|name| name.to_string()),
+      visible: visible,
+      reactive: reactive
+    };
+  }
+
+  fn parse_error(selector: &str) -> (usize, String) {
+    match Selector::parse(selector) {
+      Ok(parsed) => panic!("{:?} parsed as {:?}", selector, parsed),
+      Err(SelectorError { position, message }) => return (position, message)
+    }
+  }
+
+  #[test]
+  fn compound_selectors() {
+    let selector = Selector::parse("Text#title:visible:reactive").unwrap();
+    assert_eq!(selector.parts, vec![
+      (Combinator::Descendant, compound(Some("Text"), Some("title"), true, true))
+    ]);
+
+    let selector = Selector::parse("*").unwrap();
+    assert_eq!(selector.parts, vec![(Combinator::Descendant, compound(None, None, false, false))]);
+
+    let selector = Selector::parse("#close-button").unwrap();
+    assert_eq!(selector.parts, vec![
+      (Combinator::Descendant, compound(None, Some("close-button"), false, false))
+    ]);
+  }
+
+  #[test]
+  fn pseudo_classes() {
+    let selector = Selector::parse(":visible").unwrap();
+    assert_eq!(selector.parts, vec![(Combinator::Descendant, compound(None, None, true, false))]);
+
+    let selector = Selector::parse("*:reactive").unwrap();
+    assert_eq!(selector.parts, vec![(Combinator::Descendant, compound(None, None, false, true))]);
+
+    assert_eq!(parse_error("Text:hover"), (4, "unknown pseudo-class".to_string()));
+    assert_eq!(parse_error("Text:"), (4, "unknown pseudo-class".to_string()));
+  }
+
+  #[test]
+  fn combinators() {
+    let expected = vec![
+      (Combinator::Descendant, compound(Some("Stage"), None, false, false)),
+      (Combinator::Child, compound(Some("Actor"), None, false, false)),
+      (Combinator::Descendant, compound(Some("Text"), Some("title"), false, false))
+    ];
+    assert_eq!(Selector::parse("Stage > Actor Text#title").unwrap().parts, expected);
+    assert_eq!(Selector::parse("Stage>Actor Text#title").unwrap().parts, expected);
+    assert_eq!(Selector::parse("  Stage  >  Actor   Text#title  ").unwrap().parts, expected);
+  }
+
+  #[test]
+  fn errors() {
+    assert_eq!(parse_error(""), (0, "empty selector".to_string()));
+    assert_eq!(parse_error("   "), (3, "empty selector".to_string()));
+    assert_eq!(parse_error("> Text"), (0, "unexpected '>'".to_string()));
+    assert_eq!(parse_error("Stage > > Text"), (8, "unexpected '>'".to_string()));
+    assert_eq!(parse_error("Stage >"), (7, "expected a selector after '>'".to_string()));
+    assert_eq!(parse_error("Text#"), (4, "expected a name after '#'".to_string()));
+    assert_eq!(parse_error("#one#two"), (4, "more than one name".to_string()));
+    assert_eq!(parse_error("Text.title"), (4, "unexpected character".to_string()));
+    assert_eq!(parse_error("."), (0, "unexpected character".to_string()));
+  }
+
+  #[test]
+  fn clutter_prefix() {
+    assert!(type_name_matches("Text", "ClutterText"));
+    assert!(type_name_matches("ClutterText", "ClutterText"));
+    assert!(type_name_matches("MxButton", "MxButton"));
+    assert!(!type_name_matches("Text", "MxText"));
+    assert!(!type_name_matches("Text", "ClutterTextBuffer"));
+    assert!(!type_name_matches("Actor", "ClutterText"));
+  }
+}
+
+extern {
+  fn clutter_actor_get_name(self_value: *mut libc::c_void) -> *const i8;
+  fn clutter_actor_get_parent(self_value: *mut libc::c_void) -> *mut libc::c_void;
+  fn clutter_actor_get_reactive(self_value: *mut libc::c_void) -> i32;
+}
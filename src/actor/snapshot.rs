@@ -0,0 +1,266 @@
+#![stable]
+
+use libc;
+use super::{is_visible, Actor};
+use super::super::ffi::{c_str_to_string, type_name_of};
+use super::super::geometry::{Point, Rect, Size};
+
+/// The state of an actor and its descendants at a given time.
+///
+/// A Node is created with `Actor#snapshot()`. It is a plain Rust value, so
+/// it can be compared with an expected tree in tests, or turned into JSON
+/// with `.to_json()` to be stored in a snapshot file or attached to a bug
+/// report.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Node {
+  /// The name of the type of the actor, such as `ClutterText`
+  pub type_name: String,
+
+  /// The name of the actor, if any
+  pub name: Option<String>,
+
+  /// The position of the actor, as returned by `Actor#get_position()`
+  pub position: Point,
+
+  /// The size of the actor, as returned by `Actor#get_size()`
+  pub size: Size,
+
+  /// The size of the actor on the stage, as returned by
+  /// `Actor#get_transformed_size()`
+  pub transformed_size: Size,
+
+  /// The opacity of the actor, between 0 and 255
+  pub opacity: u8,
+
+  /// Whether the actor is visible
+  pub visible: bool,
+
+  /// The clip area of the actor, if any
+  pub clip: Option<Rect>,
+
+  /// The scale factors of the actor on the X and Y axis
+  pub scale: (f64, f64),
+
+  /// The pivot point of the actor, in normalized coordinates
+  pub pivot: Point,
+
+  /// The name of the type of the content of the actor, if any
+  pub content_type: Option<String>,
+
+  /// The children of the actor, in paint order
+  pub children: Vec<Node>
+}
+
+impl Node {
+  /// Captures the state of `actor` and its descendants.
+  pub fn new<T: Actor>(actor: &mut T) -> Node {
+    unsafe {
+      let raw = actor.as_actor();
+      let name = clutter_actor_get_name(raw);
+      let content = clutter_actor_get_content(raw);
+
+      let clip = if clutter_actor_has_clip(raw) != 0 {
+        Some(actor.get_clip())
+      } else {
+        None
+      };
+
+      let mut scale_x: f64 = 0.0;
+      let mut scale_y: f64 = 0.0;
+      clutter_actor_get_scale(raw, &mut scale_x, &mut scale_y);
+
+      let mut children = Vec::new();
+      for mut child in actor.children() {
+        children.push(Node::new(&mut child));
+      }
+
+      return Node {
+        type_name: type_name_of(raw),
+        name: if name.is_null() { None } else { Some(c_str_to_string(name)) },
+        position: actor.get_position(),
+        size: actor.get_size(),
+        transformed_size: actor.get_transformed_size(),
+        opacity: clutter_actor_get_opacity(raw),
+        visible: is_visible(raw),
+        clip: clip,
+        scale: (scale_x, scale_y),
+        pivot: actor.get_pivot_point(),
+        content_type: if content.is_null() { None } else { Some(type_name_of(content)) },
+        children: children
+      };
+    }
+  }
+
+  /// Finds the first node in the tree, including this one, whose actor has
+  /// the given `name`.
+  pub fn find(&self, name: &str) -> Option<&Node> {
+    if self.name.as_ref().map_or(false, |own| own.as_slice() == name) {
+      return Some(self);
+    }
+
+    for child in self.children.iter() {
+      if let Some(found) = child.find(name) {
+        return Some(found);
+      }
+    }
+
+    return None;
+  }
+
+  /// Serializes the tree as an indented JSON document.
+  ///
+  /// Keys are always written in the same order and numbers with the same
+  /// precision, so that two snapshots of the same tree are identical and
+  /// can be compared textually.
+  pub fn to_json(&self) -> String {
+    let mut out = String::new();
+    self.write_json(0, &mut out);
+    out.push('\n');
+    return out;
+  }
+
+  fn write_json(&self, depth: usize, out: &mut String) {
+    out.push_str("{\n");
+
+    let mut fields = Vec::new();
+    fields.push(format!("\"type\": {}", json_string(self.type_name.as_slice())));
+    fields.push(format!("\"name\": {}", match self.name {
+      Some(ref name) => json_string(name.as_slice()),
+      None => String::from_str("null")
+    }));
+    fields.push(format!("\"position\": {{\"x\": {}, \"y\": {}}}", json_number(self.position.x as f64), json_number(self.position.y as f64)));
+    fields.push(format!("\"size\": {}", json_size(&self.size)));
+    fields.push(format!("\"transformed_size\": {}", json_size(&self.transformed_size)));
+    fields.push(format!("\"opacity\": {}", self.opacity));
+    fields.push(format!("\"visible\": {}", self.visible));
+    fields.push(format!("\"clip\": {}", match self.clip {
+      Some(ref clip) => format!("{{\"x\": {}, \"y\": {}, \"width\": {}, \"height\": {}}}",
+                                json_number(clip.origin.x as f64), json_number(clip.origin.y as f64),
+                                json_number(clip.size.width as f64), json_number(clip.size.height as f64)),
+      None => String::from_str("null")
+    }));
+    fields.push(format!("\"scale\": {{\"x\": {}, \"y\": {}}}", json_number(self.scale.0), json_number(self.scale.1)));
+    fields.push(format!("\"pivot\": {{\"x\": {}, \"y\": {}}}", json_number(self.pivot.x as f64), json_number(self.pivot.y as f64)));
+    fields.push(format!("\"content_type\": {}", match self.content_type {
+      Some(ref content_type) => json_string(content_type.as_slice()),
+      None => String::from_str("null")
+    }));
+
+    for field in fields.iter() {
+      push_indent(out, depth + 1);
+      out.push_str(field.as_slice());
+      out.push_str(",\n");
+    }
+
+    push_indent(out, depth + 1);
+    if self.children.is_empty() {
+      out.push_str("\"children\": []\n");
+    } else {
+      out.push_str("\"children\": [\n");
+      for (index, child) in self.children.iter().enumerate() {
+        push_indent(out, depth + 2);
+        child.write_json(depth + 2, out);
+        if index + 1 < self.children.len() {
+          out.push(',');
+        }
+        out.push('\n');
+      }
+      push_indent(out, depth + 1);
+      out.push_str("]\n");
+    }
+
+    push_indent(out, depth);
+    out.push('}');
+  }
+}
+
+fn push_indent(out: &mut String, depth: usize) {
+  for _ in 0..depth {
+    out.push_str("  ");
+  }
+}
+
+fn json_size(size: &Size) -> String {
+  return format!("{{\"width\": {}, \"height\": {}}}", json_number(size.width as f64), json_number(size.height as f64));
+}
+
+/// Formats a number with at most three decimals, dropping trailing zeros.
+fn json_number(value: f64) -> String {
+  if !value.is_finite() {
+    return String::from_str("null");
+  }
+
+  let mut formatted = format!("{:.3}", value);
+  while formatted.as_slice().ends_with("0") {
+    formatted.pop();
+  }
+  if formatted.as_slice().ends_with(".") {
+    formatted.pop();
+  }
+  if formatted.as_slice() == "-0" {
+    return String::from_str("0");
+  }
+
+  return formatted;
+}
+
+fn json_string(value: &str) -> String {
+  let mut out = String::from_str("\"");
+  for c in value.chars() {
+    match c {
+      '"' => out.push_str("\\\""),
+      '\\' => out.push_str("\\\\"),
+      '\n' => out.push_str("\\n"),
+      '\r' => out.push_str("\\r"),
+      '\t' => out.push_str("\\t"),
+      c if (c as u32) < 0x20 => out.push_str(format!("\\u{:04x}", c as u32).as_slice()),
+      c => out.push(c)
+    }
+  }
+  out.push('"');
+  return out;
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{json_number, json_string};
+
+  #[test]
+  fn numbers() {
+    assert_eq!(json_number(0.0), "0");
+    assert_eq!(json_number(-0.0), "0");
+    assert_eq!(json_number(-0.0001), "0");
+    assert_eq!(json_number(100.0), "100");
+    assert_eq!(json_number(12.5), "12.5");
+    assert_eq!(json_number(-3.25), "-3.25");
+    assert_eq!(json_number(1.0 / 3.0), "0.333");
+    assert_eq!(json_number(2.0 / 3.0), "0.667");
+    assert_eq!(json_number(0.0004), "0");
+  }
+
+  #[test]
+  fn non_finite_numbers() {
+    assert_eq!(json_number(1.0 / 0.0), "null");
+    assert_eq!(json_number(-1.0 / 0.0), "null");
+    assert_eq!(json_number(0.0 / 0.0), "null");
+  }
+
+  #[test]
+  fn strings() {
+    assert_eq!(json_string(""), "\"\"");
+    assert_eq!(json_string("ClutterText"), "\"ClutterText\"");
+    assert_eq!(json_string("say \"hi\""), "\"say \\\"hi\\\"\"");
+    assert_eq!(json_string("C:\\path"), "\"C:\\\\path\"");
+    assert_eq!(json_string("one\ntwo\r\tthree"), "\"one\\ntwo\\r\\tthree\"");
+    assert_eq!(json_string("\u{1}\u{1f}"), "\"\\u0001\\u001f\"");
+    assert_eq!(json_string("caf\u{e9} \u{1f600}"), "\"caf\u{e9} \u{1f600}\"");
+  }
+}
+
+extern {
+  fn clutter_actor_get_name(self_value: *mut libc::c_void) -> *const i8;
+  fn clutter_actor_get_content(self_value: *mut libc::c_void) -> *mut libc::c_void;
+  fn clutter_actor_has_clip(self_value: *mut libc::c_void) -> i32;
+  fn clutter_actor_get_scale(self_value: *mut libc::c_void, scale_x: *mut f64, scale_y: *mut f64);
+  fn clutter_actor_get_opacity(self_value: *mut libc::c_void) -> u8;
+}
@@ -0,0 +1,429 @@
+#![stable]
+
+use libc;
+use std;
+use std::cell::RefCell;
+use super::actor::{Actor, ActorHandle, ActorRef};
+use super::ffi::{self, ObjectClass};
+use super::interval::{Interval, IntervalValue};
+use super::value::{Value, Type, TypeNameTaken};
+
+/// Opaque struct which holds a reference to the underlying Clutter object.
+#[repr(C)]
+pub struct AnimatableRef {
+  opaque: *mut libc::c_void
+}
+
+/// Interface for animatable classes.
+///
+/// Animatable is an interface that allows a type to control how an actor
+/// will animate a property.
+///
+/// Every Actor is animatable; the methods of this trait call the Clutter
+/// implementation of the interface. To expose custom animatable properties
+/// from Rust, implement AnimatableImpl and wrap it in an AnimatableActorRef.
+///
+/// Values are interpolated with the `interpolate_value()` function of this
+/// module, which is generic over the type of the values.
+///
+/// _Since 1.0_
+pub trait Animatable {
+  /// Returns a pointer to the the underlying C object.
+  ///
+  /// Generally only used internally.
+  fn as_animatable(&self) -> *mut libc::c_void;
+
+  /// Checks whether the animatable has a property named `property_name`.
+  ///
+  /// _Since 1.4_
+  fn has_property(&mut self, property_name: &str) -> bool {
+    unsafe {
+      use std::ffi::CString;
+      let foreign_result = clutter_animatable_find_property(self.as_animatable(), property_name.to_c_str().unwrap() as *mut i8);
+      return !foreign_result.is_null();
+    }
+  }
+
+  /// Retrieves the current state of `property_name` and sets `value` with it.
+  ///
+  /// `value` must be initialised to the type of the property.
+  ///
+  /// _Since 1.4_
+  fn get_initial_state(&mut self, property_name: &str, value: &mut Value) {
+    unsafe {
+      use std::ffi::CString;
+      clutter_animatable_get_initial_state(self.as_animatable(), property_name.to_c_str().unwrap() as *mut i8, value);
+    }
+  }
+
+  /// Sets the current state of `property_name` to `value`.
+  ///
+  /// _Since 1.4_
+  fn set_final_state(&mut self, property_name: &str, value: &Value) {
+    unsafe {
+      use std::ffi::CString;
+      clutter_animatable_set_final_state(self.as_animatable(), property_name.to_c_str().unwrap() as *mut i8, value);
+    }
+  }
+}
+
+/// Asks `animatable` to interpolate the `property_name` between the initial
+/// and final values of `interval`, using `progress` as the interpolation
+/// value.
+///
+/// Returns __None__ if the interpolation could not be performed.
+///
+/// _Since 1.8_
+pub fn interpolate_value<A: Animatable + ?Sized, T: IntervalValue>(animatable: &mut A, property_name: &str, interval: &Interval<T>, progress: f64) -> Option<T> {
+  unsafe {
+    use std::ffi::CString;
+    let mut value = Value::new(<T as IntervalValue>::value_type());
+    let foreign_result = clutter_animatable_interpolate_value(animatable.as_animatable(), property_name.to_c_str().unwrap() as *mut i8, interval.as_interval(), progress, &mut value);
+    if foreign_result == 0 {
+      return None;
+    }
+
+    return Some(<T as IntervalValue>::from_value(&value));
+  }
+}
+
+impl Animatable for AnimatableRef {
+  fn as_animatable(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+impl Animatable for ActorRef {
+  fn as_animatable(&self) -> *mut libc::c_void {
+    return self.as_actor();
+  }
+}
+
+/// The description of an animatable property of an AnimatableImpl.
+///
+/// Each variant holds the name of the property, followed by its minimum,
+/// maximum and default values.
+pub enum Property {
+  /// An integer property, with its minimum, maximum and default values
+  Int(&'static str, i32, i32, i32),
+
+  /// A float property, with its minimum, maximum and default values
+  Float(&'static str, f32, f32, f32),
+
+  /// A double property, with its minimum, maximum and default values
+  Double(&'static str, f64, f64, f64)
+}
+
+/// An animatable actor implemented in Rust.
+///
+/// Implementations of this trait can be wrapped in an AnimatableActorRef,
+/// which is a real Actor implementing the Animatable interface: the
+/// properties returned by `.properties()` are installed on its class, and
+/// the transitions driving them, such as a PropertyTransition, call into the
+/// implementation to read, interpolate and set their values. Setting the
+/// properties with `g_object_set()` or from a Script goes through
+/// `.set_final_state()` as well.
+///
+/// The properties of Actor itself are still handled by Clutter.
+///
+/// The implementation must not set its own properties through the actor
+/// from inside its methods; such calls are ignored with a warning.
+pub trait AnimatableImpl {
+  /// Returns the name used to register the type of the actor.
+  ///
+  /// The name must be unique to the implementation.
+  fn type_name(&self) -> &'static str;
+
+  /// Returns the animatable properties of the actor.
+  ///
+  /// This is only called once per type, when the first instance is created.
+  fn properties(&self) -> Vec<Property>;
+
+  /// Stores the current value of `property_name` in `value`, which is
+  /// initialised to the type of the property.
+  fn get_initial_state(&mut self, actor: &mut ActorHandle, property_name: &str, value: &mut Value);
+
+  /// Sets `property_name` to `value`.
+  fn set_final_state(&mut self, actor: &mut ActorHandle, property_name: &str, value: &Value);
+
+  /// Interpolates `property_name` between `initial` and `final_value` at
+  /// `progress`.
+  ///
+  /// The default implementation returns __None__, which interpolates the
+  /// values linearly.
+  #[allow(unused_variables)]
+  fn interpolate_value(&mut self, actor: &mut ActorHandle, property_name: &str, initial: &Value, final_value: &Value, progress: f64) -> Option<Value> {
+    return None;
+  }
+}
+
+/// Opaque struct which holds a reference to the underlying Clutter object.
+#[repr(C)]
+pub struct AnimatableActorRef {
+  opaque: *mut libc::c_void
+}
+
+impl AnimatableActorRef {
+  /// Creates a new actor delegating its animatable properties to
+  /// `implementation`.
+  ///
+  /// The actor takes ownership of the implementation, which is dropped
+  /// together with the actor.
+  ///
+  /// Fails if the type name of the implementation is taken by a type
+  /// registered elsewhere.
+  pub fn new<T: AnimatableImpl + 'static>(implementation: T) -> Result<AnimatableActorRef, TypeNameTaken> {
+    unsafe {
+      let actor_type = try!(register_type(&implementation));
+      let instance = std::boxed::Box::new(Instance {
+        implementation: RefCell::new(Some(std::boxed::Box::new(implementation) as std::boxed::Box<AnimatableImpl>))
+      });
+
+      let null_void: *mut libc::c_void = std::ptr::null_mut();
+      let foreign_result = g_object_newv(actor_type, 0, null_void);
+      g_object_set_qdata_full(foreign_result, instance_quark(), std::mem::transmute(instance), free_instance);
+      return Ok(AnimatableActorRef { opaque: foreign_result });
+    }
+  }
+}
+
+impl Animatable for AnimatableActorRef {
+  fn as_animatable(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+impl Actor for AnimatableActorRef {
+  fn as_actor(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+/// The data attached to each AnimatableActorRef.
+///
+/// The implementation is taken out of the cell while one of its methods
+/// runs, so that a re-entrant call finds it missing instead of aliasing it.
+struct Instance {
+  implementation: RefCell<Option<std::boxed::Box<AnimatableImpl + 'static>>>
+}
+
+/// Mirror of ClutterAnimatableIface.
+#[repr(C)]
+struct AnimatableIface {
+  g_type: Type,
+  g_instance_type: Type,
+  animate_property: *mut libc::c_void,
+  find_property: *mut libc::c_void,
+  get_initial_state: extern "C" fn(*mut libc::c_void, *const libc::c_char, *mut Value),
+  set_final_state: extern "C" fn(*mut libc::c_void, *const libc::c_char, *const Value),
+  interpolate_value: extern "C" fn(*mut libc::c_void, *const libc::c_char, *mut libc::c_void, f64, *mut Value) -> i32
+}
+
+/// Mirror of GInterfaceInfo.
+#[repr(C)]
+struct InterfaceInfo {
+  interface_init: extern "C" fn(*mut libc::c_void, *mut libc::c_void),
+  interface_finalize: *mut libc::c_void,
+  interface_data: *mut libc::c_void
+}
+
+/// Registers the actor type for `implementation`, unless it already exists.
+///
+/// The type is a subclass of Actor overriding the Animatable interface; the
+/// methods of the interface fall back to the implementation of Actor for
+/// the properties that are not declared by `implementation`.
+///
+/// Fails if the name is taken by a type that was not registered here.
+unsafe fn register_type(implementation: &AnimatableImpl) -> Result<Type, TypeNameTaken> {
+  let name = implementation.type_name();
+  if let Some(existing) = try!(ffi::find_own_type(name, class_init)) {
+    return Ok(existing);
+  }
+
+  let properties = std::boxed::Box::new(implementation.properties());
+  let actor_type = ffi::register_subclass(clutter_actor_get_type(), name, class_init, std::mem::transmute(properties));
+  let null_void: *mut libc::c_void = std::ptr::null_mut();
+  let info = InterfaceInfo { interface_init: iface_init, interface_finalize: null_void, interface_data: null_void };
+  g_type_add_interface_static(actor_type, clutter_animatable_get_type(), &info);
+  return Ok(actor_type);
+}
+
+fn instance_quark() -> u32 {
+  unsafe {
+    return g_quark_from_static_string("rust-animatable-instance\0".as_ptr() as *const i8);
+  }
+}
+
+extern "C" fn free_instance(instance: *mut libc::c_void) {
+  unsafe {
+    let instance: std::boxed::Box<Instance> = std::mem::transmute(instance);
+    drop(instance);
+  }
+}
+
+/// Calls `f` with the implementation of `actor`, unless the implementation
+/// is already running, in which case __None__ is returned.
+unsafe fn with_implementation<R, F>(actor: *mut libc::c_void, f: F) -> Option<R> where F: FnOnce(&mut AnimatableImpl, &mut ActorHandle) -> R {
+  let instance: &Instance = std::mem::transmute(g_object_get_qdata(actor, instance_quark()));
+  let taken = instance.implementation.borrow_mut().take();
+  let mut implementation = match taken {
+    Some(implementation) => implementation,
+    None => {
+      ffi::warn("AnimatableImpl: the implementation cannot be called re-entrantly");
+      return None;
+    }
+  };
+
+  let mut handle: ActorHandle = std::mem::transmute(actor);
+  let result = f(&mut *implementation, &mut handle);
+  *instance.implementation.borrow_mut() = Some(implementation);
+  return Some(result);
+}
+
+/// Mirror of the public fields of GParamSpec.
+#[repr(C)]
+struct ParamSpec {
+  g_type_instance: *mut libc::c_void,
+  name: *const libc::c_char,
+  flags: u32,
+  value_type: Type,
+  owner_type: Type
+}
+
+/// Checks whether `property_name` is declared by the implementation of
+/// `actor`, rather than inherited from Actor.
+unsafe fn is_own_property(actor: *mut libc::c_void, property_name: *const libc::c_char) -> bool {
+  let pspec = g_object_class_find_property(*(actor as *const *mut libc::c_void), property_name) as *const ParamSpec;
+  return !pspec.is_null() && (*pspec).owner_type == ffi::type_of(actor);
+}
+
+/// Returns the implementation of the interface by Actor.
+unsafe fn parent_iface(actor: *mut libc::c_void) -> &'static AnimatableIface {
+  let iface = g_type_interface_peek(*(actor as *const *mut libc::c_void), clutter_animatable_get_type());
+  return &*(g_type_interface_peek_parent(iface) as *const AnimatableIface);
+}
+
+extern "C" fn class_init(klass: *mut libc::c_void, class_data: *mut libc::c_void) {
+  unsafe {
+    use std::ffi::CString;
+    let object_class = &mut *(klass as *mut ObjectClass);
+    object_class.set_property = set_property;
+    object_class.get_property = get_property;
+
+    let properties = &*(class_data as *const Vec<Property>);
+    let null_char: *const libc::c_char = std::ptr::null();
+    let mut property_id = 1;
+    for property in properties.iter() {
+      let pspec = match *property {
+        Property::Int(name, min, max, default) =>
+          g_param_spec_int(name.to_c_str().unwrap() as *const i8, null_char, null_char, min, max, default, PARAM_READWRITE | PARAM_ANIMATABLE),
+        Property::Float(name, min, max, default) =>
+          g_param_spec_float(name.to_c_str().unwrap() as *const i8, null_char, null_char, min, max, default, PARAM_READWRITE | PARAM_ANIMATABLE),
+        Property::Double(name, min, max, default) =>
+          g_param_spec_double(name.to_c_str().unwrap() as *const i8, null_char, null_char, min, max, default, PARAM_READWRITE | PARAM_ANIMATABLE)
+      };
+      g_object_class_install_property(klass, property_id, pspec);
+      property_id += 1;
+    }
+  }
+}
+
+extern "C" fn iface_init(iface: *mut libc::c_void, _iface_data: *mut libc::c_void) {
+  unsafe {
+    let iface = &mut *(iface as *mut AnimatableIface);
+    iface.get_initial_state = iface_get_initial_state;
+    iface.set_final_state = iface_set_final_state;
+    iface.interpolate_value = iface_interpolate_value;
+  }
+}
+
+extern "C" fn set_property(actor: *mut libc::c_void, _property_id: u32, value: *const Value, pspec: *mut libc::c_void) {
+  unsafe {
+    let name = ffi::c_str_to_string((*(pspec as *const ParamSpec)).name);
+    with_implementation(actor, |implementation, handle| implementation.set_final_state(handle, name.as_slice(), &*value));
+  }
+}
+
+extern "C" fn get_property(actor: *mut libc::c_void, _property_id: u32, value: *mut Value, pspec: *mut libc::c_void) {
+  unsafe {
+    let name = ffi::c_str_to_string((*(pspec as *const ParamSpec)).name);
+    with_implementation(actor, |implementation, handle| implementation.get_initial_state(handle, name.as_slice(), &mut *value));
+  }
+}
+
+extern "C" fn iface_get_initial_state(actor: *mut libc::c_void, property_name: *const libc::c_char, value: *mut Value) {
+  unsafe {
+    if !is_own_property(actor, property_name) {
+      (parent_iface(actor).get_initial_state)(actor, property_name, value);
+      return;
+    }
+
+    let name = ffi::c_str_to_string(property_name);
+    with_implementation(actor, |implementation, handle| implementation.get_initial_state(handle, name.as_slice(), &mut *value));
+  }
+}
+
+extern "C" fn iface_set_final_state(actor: *mut libc::c_void, property_name: *const libc::c_char, value: *const Value) {
+  unsafe {
+    if !is_own_property(actor, property_name) {
+      (parent_iface(actor).set_final_state)(actor, property_name, value);
+      return;
+    }
+
+    let name = ffi::c_str_to_string(property_name);
+    let set = with_implementation(actor, |implementation, handle| implementation.set_final_state(handle, name.as_slice(), &*value));
+    if set.is_some() {
+      g_object_notify(actor, property_name);
+    }
+  }
+}
+
+extern "C" fn iface_interpolate_value(actor: *mut libc::c_void, property_name: *const libc::c_char, interval: *mut libc::c_void, progress: f64, value: *mut Value) -> i32 {
+  unsafe {
+    if is_own_property(actor, property_name) {
+      let name = ffi::c_str_to_string(property_name);
+      let initial = clutter_interval_peek_initial_value(interval);
+      let final_value = clutter_interval_peek_final_value(interval);
+      let interpolated = with_implementation(actor, |implementation, handle| {
+        implementation.interpolate_value(handle, name.as_slice(), &*initial, &*final_value, progress)
+      });
+      if let Some(Some(ref result)) = interpolated {
+        g_value_copy(result, value);
+        return 1;
+      }
+    }
+
+    return (parent_iface(actor).interpolate_value)(actor, property_name, interval, progress, value);
+  }
+}
+
+const PARAM_READWRITE: u32 = 3;
+
+/// CLUTTER_PARAM_ANIMATABLE, which marks the properties implicit transitions
+/// may animate.
+const PARAM_ANIMATABLE: u32 = 1 << 8;
+
+extern {
+  fn clutter_animatable_find_property(self_value: *mut libc::c_void, property_name: *mut libc::c_char) -> *mut libc::c_void;
+  fn clutter_animatable_get_initial_state(self_value: *mut libc::c_void, property_name: *mut libc::c_char, value: *mut Value);
+  fn clutter_animatable_set_final_state(self_value: *mut libc::c_void, property_name: *mut libc::c_char, value: *const Value);
+  fn clutter_animatable_interpolate_value(self_value: *mut libc::c_void, property_name: *mut libc::c_char, interval: *mut libc::c_void, progress: f64, value: *mut Value) -> i32;
+  fn clutter_animatable_get_type() -> Type;
+  fn clutter_actor_get_type() -> Type;
+  fn clutter_interval_peek_initial_value(interval: *mut libc::c_void) -> *mut Value;
+  fn clutter_interval_peek_final_value(interval: *mut libc::c_void) -> *mut Value;
+  fn g_type_add_interface_static(instance_type: Type, interface_type: Type, info: *const InterfaceInfo);
+  fn g_type_interface_peek(instance_class: *mut libc::c_void, iface_type: Type) -> *mut libc::c_void;
+  fn g_type_interface_peek_parent(g_iface: *mut libc::c_void) -> *mut libc::c_void;
+  fn g_object_newv(object_type: Type, n_parameters: u32, parameters: *mut libc::c_void) -> *mut libc::c_void;
+  fn g_object_notify(object: *mut libc::c_void, property_name: *const libc::c_char);
+  fn g_object_class_find_property(oclass: *mut libc::c_void, property_name: *const libc::c_char) -> *mut libc::c_void;
+  fn g_object_class_install_property(oclass: *mut libc::c_void, property_id: u32, pspec: *mut libc::c_void);
+  fn g_object_get_qdata(object: *mut libc::c_void, quark: u32) -> *mut libc::c_void;
+  fn g_object_set_qdata_full(object: *mut libc::c_void, quark: u32, data: *mut libc::c_void, destroy: extern "C" fn(*mut libc::c_void));
+  fn g_quark_from_static_string(string: *const libc::c_char) -> u32;
+  fn g_param_spec_int(name: *const libc::c_char, nick: *const libc::c_char, blurb: *const libc::c_char, minimum: i32, maximum: i32, default_value: i32, flags: u32) -> *mut libc::c_void;
+  fn g_param_spec_float(name: *const libc::c_char, nick: *const libc::c_char, blurb: *const libc::c_char, minimum: f32, maximum: f32, default_value: f32, flags: u32) -> *mut libc::c_void;
+  fn g_param_spec_double(name: *const libc::c_char, nick: *const libc::c_char, blurb: *const libc::c_char, minimum: f64, maximum: f64, default_value: f64, flags: u32) -> *mut libc::c_void;
+  fn g_value_copy(src_value: *const Value, dest_value: *mut Value);
+}
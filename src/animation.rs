@@ -0,0 +1,151 @@
+#![stable]
+
+/// The easing modes used by implicit animations and timelines.
+///
+/// Every global easing mode is a function mapping the elapsed time of a
+/// transition to its progress.
+///
+/// _Since 1.0_
+#[repr(i32)]
+pub enum AnimationMode {
+  /// Custom progress function
+  CustomMode = 0,
+
+  /// Linear tweening
+  Linear = 1,
+
+  /// Quad tweening, easing in
+  EaseInQuad = 2,
+
+  /// Quad tweening, easing out
+  EaseOutQuad = 3,
+
+  /// Quad tweening, applying easing on both ends
+  EaseInOutQuad = 4,
+
+  /// Cubic tweening, easing in
+  EaseInCubic = 5,
+
+  /// Cubic tweening, easing out
+  EaseOutCubic = 6,
+
+  /// Cubic tweening, applying easing on both ends
+  EaseInOutCubic = 7,
+
+  /// Quart tweening, easing in
+  EaseInQuart = 8,
+
+  /// Quart tweening, easing out
+  EaseOutQuart = 9,
+
+  /// Quart tweening, applying easing on both ends
+  EaseInOutQuart = 10,
+
+  /// Quint tweening, easing in
+  EaseInQuint = 11,
+
+  /// Quint tweening, easing out
+  EaseOutQuint = 12,
+
+  /// Quint tweening, applying easing on both ends
+  EaseInOutQuint = 13,
+
+  /// Sine tweening, easing in
+  EaseInSine = 14,
+
+  /// Sine tweening, easing out
+  EaseOutSine = 15,
+
+  /// Sine tweening, applying easing on both ends
+  EaseInOutSine = 16,
+
+  /// Expo tweening, easing in
+  EaseInExpo = 17,
+
+  /// Expo tweening, easing out
+  EaseOutExpo = 18,
+
+  /// Expo tweening, applying easing on both ends
+  EaseInOutExpo = 19,
+
+  /// Circ tweening, easing in
+  EaseInCirc = 20,
+
+  /// Circ tweening, easing out
+  EaseOutCirc = 21,
+
+  /// Circ tweening, applying easing on both ends
+  EaseInOutCirc = 22,
+
+  /// Elastic tweening, easing in
+  EaseInElastic = 23,
+
+  /// Elastic tweening, easing out
+  EaseOutElastic = 24,
+
+  /// Elastic tweening, applying easing on both ends
+  EaseInOutElastic = 25,
+
+  /// Back tweening, easing in
+  EaseInBack = 26,
+
+  /// Back tweening, easing out
+  EaseOutBack = 27,
+
+  /// Back tweening, applying easing on both ends
+  EaseInOutBack = 28,
+
+  /// Bounce tweening, easing in
+  EaseInBounce = 29,
+
+  /// Bounce tweening, easing out
+  EaseOutBounce = 30,
+
+  /// Bounce tweening, applying easing on both ends
+  EaseInOutBounce = 31,
+
+  /// Parametrized step function; see `Timeline#set_step_progress()`
+  ///
+  /// _Since 1.12_
+  Steps = 32,
+
+  /// Equivalent to `Steps` with a number of steps equal to 1, and a step mode
+  /// of start
+  ///
+  /// _Since 1.12_
+  StepStart = 33,
+
+  /// Equivalent to `Steps` with a number of steps equal to 1, and a step mode
+  /// of end
+  ///
+  /// _Since 1.12_
+  StepEnd = 34,
+
+  /// Cubic bezier between (0, 0) and (1, 1) with two control points; see
+  /// `Timeline#set_cubic_bezier_progress()`
+  ///
+  /// _Since 1.12_
+  CubicBezier = 35,
+
+  /// Equivalent to `CubicBezier` with control points in (0.25, 0.1) and
+  /// (0.25, 1.0)
+  ///
+  /// _Since 1.12_
+  Ease = 36,
+
+  /// Equivalent to `CubicBezier` with control points in (0.42, 0) and (1, 1)
+  ///
+  /// _Since 1.12_
+  EaseIn = 37,
+
+  /// Equivalent to `CubicBezier` with control points in (0, 0) and (0.58, 1)
+  ///
+  /// _Since 1.12_
+  EaseOut = 38,
+
+  /// Equivalent to `CubicBezier` with control points in (0.42, 0) and
+  /// (0.58, 1)
+  ///
+  /// _Since 1.12_
+  EaseInOut = 39
+}
@@ -0,0 +1,206 @@
+#![stable]
+
+use libc;
+use std;
+use std::collections::HashMap;
+use super::action::Action;
+use super::actor::{Actor, ActorHandle, ActorRef};
+use super::constraint::Constraint;
+use super::script::ScriptObject;
+use super::signal::{self, Handler};
+use super::value::Type;
+
+/// Declares a tree of actors.
+///
+/// Each node is an expression creating the actor, followed by calls to the
+/// methods of ActorBuilder, separated by commas, and optionally by `=>` and
+/// the list of children, each in braces. The macro returns the ActorBuilder
+/// of the root:
+///
+/// ```ignore
+/// let scene = actor_tree!(ActorRef::new(), name("root"), size(400.0, 300.0) => [
+///   { TextRef::new(), name("title"), position(10.0, 10.0) },
+///   { ActorRef::new(), name("button"), size(80.0, 30.0), reactive(true),
+///     on("button-press-event", Box::new(|_| None)) }
+/// ]).build();
+///
+/// let title: TextRef = scene.get("title").unwrap();
+/// ```
+#[macro_export]
+macro_rules! actor_tree {
+  ($actor:expr $(, $method:ident ( $($arg:expr),* ))* $(=> [ $({ $($child:tt)* }),* ])*) => {{
+    let builder = $crate::builder::ActorBuilder::new($actor);
+    $( let builder = builder.$method($($arg),*); )*
+    $( $( let builder = builder.child(actor_tree!($($child)*)); )* )*
+    builder
+  }};
+}
+
+/// Builds an actor and its descendants.
+///
+/// Every method applies its change to the actor right away and returns the
+/// builder, so calls can be chained. Actors given a name with `.name()` can
+/// be retrieved from the Scene returned by `.build()`.
+///
+/// A builder dropped before `.build()` destroys its actor.
+pub struct ActorBuilder {
+  actor: *mut libc::c_void,
+  named: HashMap<String, *mut libc::c_void>
+}
+
+impl ActorBuilder {
+  /// Creates a builder for `actor`, taking ownership of it.
+  pub fn new<T: Actor>(actor: T) -> ActorBuilder {
+    let raw = actor.as_actor();
+    std::mem::forget(actor);
+    return ActorBuilder { actor: raw, named: HashMap::new() };
+  }
+
+  fn handle(&self) -> ActorHandle {
+    unsafe {
+      return std::mem::transmute(self.actor);
+    }
+  }
+
+  /// Sets the name of the actor, making it available from `Scene#get()`.
+  ///
+  /// Names must be unique within the tree: giving the same name to two
+  /// actors panics, either here or in `.child()` when the subtrees are
+  /// joined.
+  pub fn name(mut self, name: &str) -> ActorBuilder {
+    self.handle().set_name(name);
+    let actor = self.actor;
+    self.add_name(name.to_string(), actor);
+    return self;
+  }
+
+  /// Sets the size of the actor.
+  pub fn size(self, width: f32, height: f32) -> ActorBuilder {
+    self.handle().set_size(width, height);
+    return self;
+  }
+
+  /// Sets the fixed position of the actor.
+  pub fn position(self, x: f32, y: f32) -> ActorBuilder {
+    self.handle().set_position(x, y);
+    return self;
+  }
+
+  /// Sets whether the actor reacts to events.
+  pub fn reactive(self, reactive: bool) -> ActorBuilder {
+    self.handle().set_reactive(reactive);
+    return self;
+  }
+
+  /// Calls `setter` with the actor, to set any other property.
+  pub fn with<F: FnOnce(&mut ActorHandle)>(self, setter: F) -> ActorBuilder {
+    setter(&mut self.handle());
+    return self;
+  }
+
+  /// Adds `constraint` to the actor.
+  pub fn constraint<T: Constraint>(self, mut constraint: T) -> ActorBuilder {
+    self.handle().add_constraint(&mut constraint);
+    return self;
+  }
+
+  /// Adds `action` to the actor.
+  pub fn action<T: Action>(self, mut action: T) -> ActorBuilder {
+    self.handle().add_action(&mut action);
+    return self;
+  }
+
+  /// Connects `handler` to the signal `detailed_signal` of the actor.
+  ///
+  /// See `signal::connect()`.
+  pub fn on(self, detailed_signal: &str, handler: Handler) -> ActorBuilder {
+    unsafe {
+      signal::connect(self.actor, detailed_signal, handler, false);
+    }
+    return self;
+  }
+
+  /// Adds the actor built by `child` as the last child of the actor.
+  pub fn child(mut self, mut child: ActorBuilder) -> ActorBuilder {
+    let mut child_handle = child.handle();
+    self.handle().add_child(&mut child_handle);
+    child.actor = std::ptr::null_mut();
+    for (name, actor) in child.named.drain() {
+      self.add_name(name, actor);
+    }
+    return self;
+  }
+
+  fn add_name(&mut self, name: String, actor: *mut libc::c_void) {
+    if let Some(&named) = self.named.get(&name) {
+      assert!(named == actor, "the name {:?} is given to more than one actor", name);
+    }
+    self.named.insert(name, actor);
+  }
+
+  /// Finishes the tree.
+  pub fn build(mut self) -> Scene {
+    let scene = Scene {
+      root: self.actor,
+      named: std::mem::replace(&mut self.named, HashMap::new())
+    };
+    self.actor = std::ptr::null_mut();
+    return scene;
+  }
+}
+
+impl std::ops::Drop for ActorBuilder {
+  fn drop(&mut self) {
+    unsafe {
+      if !self.actor.is_null() {
+        clutter_actor_destroy(self.actor);
+      }
+    }
+  }
+}
+
+/// A tree of actors created by an ActorBuilder.
+///
+/// The Scene does not own the actors: they belong to the scene graph once
+/// the root has been added to a stage, or to the ActorRef returned by
+/// `.into_root()`.
+pub struct Scene {
+  root: *mut libc::c_void,
+  named: HashMap<String, *mut libc::c_void>
+}
+
+impl Scene {
+  /// Retrieves the root of the tree.
+  pub fn root(&self) -> ActorHandle {
+    unsafe {
+      return std::mem::transmute(self.root);
+    }
+  }
+
+  /// Turns the scene into the ActorRef of its root, which destroys the tree
+  /// when it is dropped.
+  pub fn into_root(self) -> ActorRef {
+    return self.root().into_actor_ref();
+  }
+
+  /// Retrieves the actor named `name`, if it exists and is an instance of
+  /// the type of `T`.
+  pub fn get<T: ScriptObject>(&self, name: &str) -> Option<T> {
+    unsafe {
+      return match self.named.get(name) {
+        Some(actor) if g_type_check_instance_is_a(*actor, T::object_type()) != 0 => Some(T::from_object(*actor)),
+        _ => None
+      };
+    }
+  }
+
+  /// Retrieves the names given to actors of the tree.
+  pub fn names(&self) -> Vec<String> {
+    return self.named.keys().map(|name| name.clone()).collect();
+  }
+}
+
+extern {
+  fn clutter_actor_destroy(self_value: *mut libc::c_void);
+  fn g_type_check_instance_is_a(instance: *mut libc::c_void, iface_type: Type) -> i32;
+}
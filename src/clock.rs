@@ -0,0 +1,489 @@
+#![stable]
+
+use libc;
+use std;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use super::ffi;
+use super::value::{Value, Type};
+
+/// A manually stepped clock, for testing animations deterministically.
+///
+/// While a TestClock exists, the position of every timeline started in the
+/// process is driven by it instead of the wall clock: implicit transitions
+/// created by changing an animatable property after
+/// `Actor#set_easing_duration()`, explicit transitions, and standalone
+/// timelines alike. The timelines then only move forward when calling
+/// `.advance()`, which moves them by exact amounts of milliseconds and lets
+/// Clutter run a frame for them, and runs the pending events, relayouts and
+/// redraws.
+///
+/// The master clock of Clutter is not frozen, as Clutter 1.x has no API to
+/// replace it: the timelines are paused between steps, but each step waits
+/// for Clutter to run a real frame, so `.advance()` takes about one frame
+/// interval of wall-clock time per step, and needs a running Clutter, with
+/// a display. Only the positions the timelines reach are independent of the
+/// wall clock.
+///
+/// The timelines are stepped by Clutter itself, so their `new-frame`,
+/// `marker-reached`, `completed` and `stopped` signals are emitted as usual,
+/// and their repeat count and auto-reverse are honoured. Frames are cut at
+/// the end of each cycle of the timelines, so that a cycle always ends at
+/// its exact duration.
+///
+/// Between two frames the timelines are paused, so `is_playing()` returns
+/// false for them and pausing one of them has no effect; stop a timeline
+/// instead to take it away from the clock. The delay of a timeline is still
+/// waited for on the wall clock, and timelines started before the clock was
+/// created are not driven by it.
+///
+/// ```ignore
+/// let mut clock = TestClock::new();
+///
+/// actor.save_easing_state();
+/// actor.set_easing_mode(AnimationMode::Linear);
+/// actor.set_easing_duration(250);
+/// actor.set_x(100.0);
+/// actor.restore_easing_state();
+///
+/// clock.advance(125);
+/// assert_eq!(actor.get_x(), 50.0);
+/// ```
+pub struct TestClock {
+  elapsed: u32,
+  frame_interval: u32,
+  shared: Rc<Shared>,
+  hooks: Vec<(u32, u64)>
+}
+
+/// The state shared by a TestClock and its emission hooks.
+///
+/// The hooks run from inside Clutter, so the cells are never borrowed while
+/// a Clutter function is running.
+struct Shared {
+  timelines: RefCell<Vec<Driven>>,
+  released: RefCell<Vec<Driven>>,
+  ticked: RefCell<Vec<*mut libc::c_void>>,
+  resuming: Cell<bool>
+}
+
+/// A timeline driven by the clock, holding a reference on it.
+///
+/// The delay of the timeline is reset while it is driven, as resuming it
+/// would wait for the delay again.
+struct Driven {
+  timeline: *mut libc::c_void,
+  delay: u32
+}
+
+impl TestClock {
+  /// Creates a new clock, stopped at zero.
+  ///
+  /// The clock steps in frames of 16 milliseconds by default.
+  pub fn new() -> TestClock {
+    let shared = Rc::new(Shared {
+      timelines: RefCell::new(Vec::new()),
+      released: RefCell::new(Vec::new()),
+      ticked: RefCell::new(Vec::new()),
+      resuming: Cell::new(false)
+    });
+
+    let mut hooks = Vec::new();
+    unsafe {
+      let data = &*shared as *const Shared as *mut libc::c_void;
+      let timeline_type = clutter_timeline_get_type();
+      for &(name, hook) in [("started\0", started_hook as EmissionHook), ("new-frame\0", new_frame_hook as EmissionHook), ("stopped\0", stopped_hook as EmissionHook)].iter() {
+        let signal_id = g_signal_lookup(name.as_ptr() as *const i8, timeline_type);
+        hooks.push((signal_id, g_signal_add_emission_hook(signal_id, 0, hook, data, std::ptr::null_mut())));
+      }
+    }
+
+    return TestClock {
+      elapsed: 0,
+      frame_interval: 16,
+      shared: shared,
+      hooks: hooks
+    };
+  }
+
+  /// Retrieves the time elapsed on the clock, in milliseconds.
+  pub fn get_elapsed(&self) -> u32 {
+    return self.elapsed;
+  }
+
+  /// Sets the length of the frames run by `.advance()`, in milliseconds.
+  ///
+  /// A zero `msecs` is ignored.
+  pub fn set_frame_interval(&mut self, msecs: u32) {
+    if msecs > 0 {
+      self.frame_interval = msecs;
+    }
+  }
+
+  /// Retrieves the value set using `.set_frame_interval()`.
+  pub fn get_frame_interval(&self) -> u32 {
+    return self.frame_interval;
+  }
+
+  /// Moves the clock forward by `msecs` milliseconds.
+  ///
+  /// The time is split into frames of at most `.get_frame_interval()`; at
+  /// each frame the driven timelines are moved forward and Clutter runs a
+  /// frame for them, then the pending events, relayouts and redraws are
+  /// processed. Timelines started during a frame begin at the clock time of
+  /// that frame.
+  pub fn advance(&mut self, msecs: u32) {
+    let mut remaining = msecs;
+    while remaining > 0 {
+      let timelines = self.shared.driven_timelines();
+      let mut step = std::cmp::min(remaining, self.frame_interval);
+      for timeline in timelines.iter() {
+        let left = unsafe { cycle_left(*timeline) };
+        if left > 0 && left < step {
+          step = left;
+        }
+      }
+
+      self.elapsed += step;
+      remaining -= step;
+      unsafe {
+        self.step_timelines(&timelines, step);
+      }
+      self.run_frame();
+    }
+  }
+
+  /// Processes the pending events, relayouts and redraws without moving the
+  /// clock.
+  ///
+  /// At most `MAX_FRAME_ITERATIONS` iterations of the main loop are run, so
+  /// that a source which is always ready cannot hang the test.
+  pub fn run_frame(&mut self) {
+    unsafe {
+      let null_void: *mut libc::c_void = std::ptr::null_mut();
+      let mut iterations = 0;
+      while iterations < MAX_FRAME_ITERATIONS && g_main_context_pending(null_void) != 0 {
+        g_main_context_iteration(null_void, 0);
+        iterations += 1;
+      }
+    }
+
+    self.shared.release_stopped();
+  }
+
+  /// Moves each of `timelines` forward by `msecs`, then lets Clutter run a
+  /// single frame for them before pausing them again.
+  unsafe fn step_timelines(&mut self, timelines: &Vec<*mut libc::c_void>, msecs: u32) {
+    if timelines.is_empty() {
+      return;
+    }
+
+    for timeline in timelines.iter() {
+      let elapsed = clutter_timeline_get_elapsed_time(*timeline);
+      let position = if clutter_timeline_get_direction(*timeline) == DIRECTION_FORWARD {
+        elapsed + msecs
+      } else {
+        elapsed - std::cmp::min(elapsed, msecs)
+      };
+      clutter_timeline_advance(*timeline, position);
+    }
+
+    // The first frame of a resumed timeline does not add any time to it, so
+    // it lands exactly on the position set above.
+    self.shared.ticked.borrow_mut().clear();
+    self.shared.resuming.set(true);
+    for timeline in timelines.iter() {
+      resume(*timeline);
+    }
+    self.shared.resuming.set(false);
+
+    let null_void: *mut libc::c_void = std::ptr::null_mut();
+    let mut iterations = 0;
+    while !self.shared.all_ticked(timelines) {
+      if iterations == MAX_FRAME_ITERATIONS {
+        ffi::warn("TestClock: Clutter did not run a frame for the driven timelines");
+        break;
+      }
+
+      g_main_context_iteration(null_void, 1);
+      iterations += 1;
+    }
+
+    for timeline in self.shared.driven_timelines().iter() {
+      if clutter_timeline_is_playing(*timeline) != 0 {
+        clutter_timeline_pause(*timeline);
+      }
+    }
+  }
+}
+
+impl Shared {
+  fn driven_timelines(&self) -> Vec<*mut libc::c_void> {
+    return self.timelines.borrow().iter().map(|driven| driven.timeline).collect();
+  }
+
+  /// Checks whether each of `timelines` that is still playing has run its
+  /// frame.
+  unsafe fn all_ticked(&self, timelines: &Vec<*mut libc::c_void>) -> bool {
+    for timeline in timelines.iter() {
+      let ticked = self.ticked.borrow().contains(timeline);
+      if !ticked && clutter_timeline_is_playing(*timeline) != 0 {
+        return false;
+      }
+    }
+
+    return true;
+  }
+
+  /// Gives back the timelines which stopped since the last call.
+  fn release_stopped(&self) {
+    let released = std::mem::replace(&mut *self.released.borrow_mut(), Vec::new());
+    for driven in released.iter() {
+      unsafe {
+        clutter_timeline_set_delay(driven.timeline, driven.delay);
+        g_object_unref(driven.timeline);
+      }
+    }
+  }
+}
+
+impl std::ops::Drop for TestClock {
+  /// Releases the driven timelines. Timelines still in progress are resumed,
+  /// and continue with the wall clock.
+  fn drop(&mut self) {
+    unsafe {
+      for &(signal_id, hook_id) in self.hooks.iter() {
+        g_signal_remove_emission_hook(signal_id, hook_id);
+      }
+
+      self.shared.release_stopped();
+      let timelines = std::mem::replace(&mut *self.shared.timelines.borrow_mut(), Vec::new());
+      for driven in timelines.iter() {
+        // The delay has already been waited for.
+        resume(driven.timeline);
+        clutter_timeline_set_delay(driven.timeline, driven.delay);
+        g_object_unref(driven.timeline);
+      }
+    }
+  }
+}
+
+/// Returns the time left until the end of the current cycle of `timeline`.
+unsafe fn cycle_left(timeline: *mut libc::c_void) -> u32 {
+  let elapsed = clutter_timeline_get_elapsed_time(timeline);
+  if clutter_timeline_get_direction(timeline) == DIRECTION_FORWARD {
+    let duration = clutter_timeline_get_duration(timeline);
+    return duration - std::cmp::min(duration, elapsed);
+  }
+
+  return elapsed;
+}
+
+/// Resumes a paused timeline without emitting `started` to its handlers,
+/// as the timeline did not start again.
+unsafe fn resume(timeline: *mut libc::c_void) {
+  let signal_id = g_signal_lookup("started\0".as_ptr() as *const i8, clutter_timeline_get_type());
+  let null_void: *mut libc::c_void = std::ptr::null_mut();
+  g_signal_handlers_block_matched(timeline, SIGNAL_MATCH_ID, signal_id, 0, null_void, null_void, null_void);
+  clutter_timeline_start(timeline);
+  g_signal_handlers_unblock_matched(timeline, SIGNAL_MATCH_ID, signal_id, 0, null_void, null_void, null_void);
+}
+
+type EmissionHook = extern "C" fn(*mut libc::c_void, u32, *const Value, *mut libc::c_void) -> i32;
+
+/// Takes over the timelines as they start, pausing them until the next
+/// frame of the clock.
+extern "C" fn started_hook(_ihint: *mut libc::c_void, _n_param_values: u32, param_values: *const Value, data: *mut libc::c_void) -> i32 {
+  unsafe {
+    let shared = &*(data as *const Shared);
+    let timeline = (*param_values).get_object();
+    if shared.resuming.get() || shared.driven_timelines().contains(&timeline) {
+      return 1;
+    }
+
+    g_object_ref(timeline);
+    let delay = clutter_timeline_get_delay(timeline);
+    clutter_timeline_set_delay(timeline, 0);
+    clutter_timeline_pause(timeline);
+    shared.timelines.borrow_mut().push(Driven { timeline: timeline, delay: delay });
+    return 1;
+  }
+}
+
+extern "C" fn new_frame_hook(_ihint: *mut libc::c_void, _n_param_values: u32, param_values: *const Value, data: *mut libc::c_void) -> i32 {
+  unsafe {
+    let shared = &*(data as *const Shared);
+    shared.ticked.borrow_mut().push((*param_values).get_object());
+    return 1;
+  }
+}
+
+/// Stops driving the timelines which complete or are stopped.
+///
+/// The timelines are only released after the frame, as they are still in
+/// use by the emission.
+extern "C" fn stopped_hook(_ihint: *mut libc::c_void, _n_param_values: u32, param_values: *const Value, data: *mut libc::c_void) -> i32 {
+  unsafe {
+    let shared = &*(data as *const Shared);
+    let timeline = (*param_values).get_object();
+    let position = shared.timelines.borrow().iter().position(|driven| driven.timeline == timeline);
+    if let Some(index) = position {
+      let driven = shared.timelines.borrow_mut().remove(index);
+      shared.released.borrow_mut().push(driven);
+    }
+
+    return 1;
+  }
+}
+
+/// The maximum number of main loop iterations run to process a frame.
+pub const MAX_FRAME_ITERATIONS: u32 = 1000;
+
+const DIRECTION_FORWARD: i32 = 0;
+const SIGNAL_MATCH_ID: u32 = 1;
+
+#[cfg(test)]
+mod tests {
+  use libc;
+  use std;
+  use std::cell::Cell;
+  use std::rc::Rc;
+  use super::TestClock;
+  use super::super::signal;
+
+  struct Counters {
+    completed: Rc<Cell<u32>>,
+    finished: Rc<Cell<u32>>
+  }
+
+  fn new_timeline(duration: u32) -> (*mut libc::c_void, Counters) {
+    unsafe {
+      let timeline = clutter_timeline_new(duration);
+      let completed = Rc::new(Cell::new(0));
+      let finished = Rc::new(Cell::new(0));
+      let completed_count = completed.clone();
+      let finished_count = finished.clone();
+      signal::connect(timeline, "completed", std::boxed::Box::new(move |_| {
+        completed_count.set(completed_count.get() + 1);
+        return None;
+      }), false);
+      signal::connect(timeline, "stopped", std::boxed::Box::new(move |params| {
+        if params[1].get_boolean() {
+          finished_count.set(finished_count.get() + 1);
+        }
+        return None;
+      }), false);
+
+      return (timeline, Counters { completed: completed, finished: finished });
+    }
+  }
+
+  fn completed_fires_after_duration() {
+    unsafe {
+      let mut clock = TestClock::new();
+      let (timeline, counters) = new_timeline(100);
+      clutter_timeline_start(timeline);
+
+      clock.advance(50);
+      assert_eq!(clutter_timeline_get_elapsed_time(timeline), 50);
+      assert_eq!(counters.completed.get(), 0);
+
+      clock.advance(49);
+      assert_eq!(counters.completed.get(), 0);
+
+      clock.advance(1);
+      assert_eq!(counters.completed.get(), 1);
+      assert_eq!(counters.finished.get(), 1);
+      assert!(clutter_timeline_is_playing(timeline) == 0);
+      g_object_unref(timeline);
+    }
+  }
+
+  fn repeat_count_is_honoured() {
+    unsafe {
+      let mut clock = TestClock::new();
+      let (timeline, counters) = new_timeline(100);
+      clutter_timeline_set_repeat_count(timeline, 1);
+      clutter_timeline_start(timeline);
+
+      // A single step crossing the end of the first cycle is cut there.
+      clock.advance(150);
+      assert_eq!(counters.completed.get(), 1);
+      assert_eq!(counters.finished.get(), 0);
+      assert_eq!(clutter_timeline_get_elapsed_time(timeline), 50);
+
+      clock.advance(50);
+      assert_eq!(counters.completed.get(), 2);
+      assert_eq!(counters.finished.get(), 1);
+      g_object_unref(timeline);
+    }
+  }
+
+  fn auto_reverse_is_honoured() {
+    unsafe {
+      let mut clock = TestClock::new();
+      let (timeline, counters) = new_timeline(100);
+      clutter_timeline_set_repeat_count(timeline, 1);
+      clutter_timeline_set_auto_reverse(timeline, 1);
+      clutter_timeline_start(timeline);
+
+      clock.advance(100);
+      assert_eq!(counters.completed.get(), 1);
+      assert_eq!(clutter_timeline_get_direction(timeline), 1);
+
+      clock.advance(25);
+      assert_eq!(clutter_timeline_get_elapsed_time(timeline), 75);
+
+      clock.advance(75);
+      assert_eq!(counters.completed.get(), 2);
+      assert_eq!(counters.finished.get(), 1);
+      g_object_unref(timeline);
+    }
+  }
+
+  /// Clutter is not thread-safe, so the scenarios run from a single test.
+  ///
+  /// The test initializes Clutter, which needs a display, so it only runs
+  /// with `cargo test -- --ignored`.
+  #[test]
+  #[ignore]
+  fn drives_timelines() {
+    super::super::init();
+    completed_fires_after_duration();
+    repeat_count_is_honoured();
+    auto_reverse_is_honoured();
+  }
+
+  extern {
+    fn clutter_timeline_new(msecs: u32) -> *mut libc::c_void;
+    fn clutter_timeline_start(self_value: *mut libc::c_void);
+    fn clutter_timeline_is_playing(self_value: *mut libc::c_void) -> i32;
+    fn clutter_timeline_get_elapsed_time(self_value: *mut libc::c_void) -> u32;
+    fn clutter_timeline_get_direction(self_value: *mut libc::c_void) -> i32;
+    fn clutter_timeline_set_repeat_count(self_value: *mut libc::c_void, count: i32);
+    fn clutter_timeline_set_auto_reverse(self_value: *mut libc::c_void, reverse: i32);
+    fn g_object_unref(object: *mut libc::c_void);
+  }
+}
+
+extern {
+  fn g_object_ref(object: *mut libc::c_void) -> *mut libc::c_void;
+  fn g_object_unref(object: *mut libc::c_void);
+  fn g_main_context_pending(context: *mut libc::c_void) -> i32;
+  fn g_main_context_iteration(context: *mut libc::c_void, may_block: i32) -> i32;
+  fn g_signal_lookup(name: *const libc::c_char, itype: Type) -> u32;
+  fn g_signal_add_emission_hook(signal_id: u32, detail: u32, hook_func: EmissionHook, hook_data: *mut libc::c_void, data_destroy: *mut libc::c_void) -> u64;
+  fn g_signal_remove_emission_hook(signal_id: u32, hook_id: u64);
+  fn g_signal_handlers_block_matched(instance: *mut libc::c_void, mask: u32, signal_id: u32, detail: u32, closure: *mut libc::c_void, func: *mut libc::c_void, data: *mut libc::c_void) -> u32;
+  fn g_signal_handlers_unblock_matched(instance: *mut libc::c_void, mask: u32, signal_id: u32, detail: u32, closure: *mut libc::c_void, func: *mut libc::c_void, data: *mut libc::c_void) -> u32;
+  fn clutter_timeline_get_type() -> Type;
+  fn clutter_timeline_start(self_value: *mut libc::c_void);
+  fn clutter_timeline_pause(self_value: *mut libc::c_void);
+  fn clutter_timeline_is_playing(self_value: *mut libc::c_void) -> i32;
+  fn clutter_timeline_advance(self_value: *mut libc::c_void, msecs: u32);
+  fn clutter_timeline_get_elapsed_time(self_value: *mut libc::c_void) -> u32;
+  fn clutter_timeline_get_direction(self_value: *mut libc::c_void) -> i32;
+  fn clutter_timeline_get_delay(self_value: *mut libc::c_void) -> u32;
+  fn clutter_timeline_set_delay(self_value: *mut libc::c_void, msecs: u32);
+  fn clutter_timeline_get_duration(self_value: *mut libc::c_void) -> u32;
+}
@@ -0,0 +1,99 @@
+#![stable]
+
+use libc;
+use std;
+use super::actor::{Actor, ActorHandle, FromActor};
+
+/// Opaque struct which holds a reference to the underlying Clutter object.
+#[repr(C)]
+pub struct CloneRef {
+  opaque: *mut libc::c_void
+}
+
+impl CloneRef {
+  /// Creates a new Actor which clones `source`.
+  ///
+  /// A Clone paints its source, scaled to its own allocation, without
+  /// duplicating the source's scene graph: the source keeps a single
+  /// position in the tree, and changes to it are reflected by all of its
+  /// clones.
+  ///
+  /// _Since 1.0_
+  pub fn new<T: Actor>(source: &mut T) -> CloneRef {
+    unsafe {
+      let foreign_result = clutter_clone_new(source.as_actor());
+      return foreign_result;
+    }
+  }
+}
+
+/// Actor that paints the contents of another actor.
+///
+/// The trait is not named `Clone`, so that it does not shadow the standard
+/// one when imported.
+///
+/// _Since 1.0_
+pub trait CloneActor {
+  /// Returns a pointer to the the underlying C object.
+  ///
+  /// Generally only used internally.
+  fn as_clone(&self) -> *mut libc::c_void;
+
+  /// Sets `source` as the source actor to be cloned.
+  ///
+  /// _Since 1.0_
+  fn set_source<T: Actor>(&mut self, source: &mut T) {
+    unsafe {
+      clutter_clone_set_source(self.as_clone(), source.as_actor());
+    }
+  }
+
+  /// Stops cloning the source actor; the clone then paints nothing.
+  ///
+  /// _Since 1.0_
+  fn unset_source(&mut self) {
+    unsafe {
+      clutter_clone_set_source(self.as_clone(), std::ptr::null_mut());
+    }
+  }
+
+  /// Retrieves the source actor being cloned, if any.
+  ///
+  /// The source is not owned by the clone.
+  ///
+  /// _Since 1.0_
+  fn get_source(&mut self) -> Option<ActorHandle> {
+    unsafe {
+      let foreign_result = clutter_clone_get_source(self.as_clone());
+      if foreign_result.is_null() {
+        return None;
+      }
+
+      return Some(std::mem::transmute(foreign_result));
+    }
+  }
+}
+
+impl CloneActor for CloneRef {
+  fn as_clone(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+impl Actor for CloneRef {
+  fn as_actor(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+impl FromActor for CloneRef {
+  unsafe fn from_actor(actor: *mut libc::c_void) -> CloneRef {
+    return CloneRef { opaque: actor };
+  }
+}
+
+extern {
+  fn clutter_clone_new(source: *mut libc::c_void) -> CloneRef;
+  fn clutter_clone_set_source(self_value: *mut libc::c_void, source: *mut libc::c_void);
+  fn clutter_clone_get_source(self_value: *mut libc::c_void) -> *mut libc::c_void;
+}
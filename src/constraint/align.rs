@@ -0,0 +1,19 @@
+#![stable]
+
+/// Specifies the axis on which AlignConstraint should maintain the
+/// alignment.
+///
+/// _Since 1.4_
+#[repr(i32)]
+pub enum AlignAxis {
+  /// Maintain the alignment on the X axis
+  X = 0,
+
+  /// Maintain the alignment on the Y axis
+  Y = 1,
+
+  /// Maintain the alignment on both the X and Y axis
+  ///
+  /// _Since 1.12_
+  Both = 2
+}
@@ -0,0 +1,552 @@
+#![stable]
+
+use libc;
+use std;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use super::super::actor::{Actor, Box};
+use super::super::ffi;
+use super::super::signal;
+use super::super::value::Type;
+use super::solver::{Solver, Variable, Expression, LinearConstraint, ConstraintHandle, SolverError, STRONG, WEAK};
+
+/// The variables describing the geometry of an actor in a LinearSystem.
+///
+/// Each method returns an expression that can be combined with the
+/// expressions of other actors to build constraints, for instance:
+///
+/// ```ignore
+/// let a = system.add_actor(&mut first);
+/// let b = system.add_actor(&mut second);
+/// let parent = system.parent();
+///
+/// system.add_constraint((a.right() + 8.0).less_than_or_equal_to(b.left()));
+/// system.add_constraint(a.width().equal_to(parent.width() * 0.5));
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Anchors {
+  left: Variable,
+  top: Variable,
+  width: Variable,
+  height: Variable
+}
+
+impl Anchors {
+  /// The X coordinate of the left edge.
+  pub fn left(&self) -> Expression {
+    return Expression::from_variable(self.left);
+  }
+
+  /// The X coordinate of the right edge.
+  pub fn right(&self) -> Expression {
+    return self.left() + self.width();
+  }
+
+  /// The Y coordinate of the top edge.
+  pub fn top(&self) -> Expression {
+    return Expression::from_variable(self.top);
+  }
+
+  /// The Y coordinate of the bottom edge.
+  pub fn bottom(&self) -> Expression {
+    return self.top() + self.height();
+  }
+
+  /// The width.
+  pub fn width(&self) -> Expression {
+    return Expression::from_variable(self.width);
+  }
+
+  /// The height.
+  pub fn height(&self) -> Expression {
+    return Expression::from_variable(self.height);
+  }
+
+  /// The X coordinate of the horizontal center.
+  pub fn center_x(&self) -> Expression {
+    return self.left() + self.width() * 0.5;
+  }
+
+  /// The Y coordinate of the vertical center.
+  pub fn center_y(&self) -> Expression {
+    return self.top() + self.height() * 0.5;
+  }
+}
+
+/// A set of linear relationships between the children of a container.
+///
+/// Every actor added with `.add_actor()` gets a LinearConstraintRef applied
+/// to it; during each allocation pass the constraint updates the size of the
+/// parent and the preferred sizes of the actors in the solver, solves the
+/// system and replaces the allocation of its actor with the solution.
+///
+/// The system is solved once for all the actors: the solution is kept until
+/// the size of the parent changes, the constraints change, or one of the
+/// actors queues a relayout. If the solver fails, a warning is logged and the
+/// allocations of the actors are left untouched.
+///
+/// Constraints between the actors are linear equalities or inequalities with
+/// a strength, as described in the `solver` module. The preferred sizes of
+/// the actors are weak preferences and the size of the parent is a strong
+/// one, so required and strong constraints win over them.
+///
+/// All the actors must be children of the same parent, whose geometry is
+/// described by `.parent()`, with its origin at (0, 0).
+#[derive(Clone)]
+pub struct LinearSystem {
+  state: Rc<State>
+}
+
+/// The state shared by a LinearSystem and its constraints.
+///
+/// The solver is only borrowed while no Clutter function is running, as
+/// Clutter may call back into the constraints; the rest of the state uses
+/// Cells, which the callbacks can update at any time.
+struct State {
+  solver: RefCell<Solver>,
+  parent: Anchors,
+  actors: RefCell<Vec<Rc<Slot>>>,
+  solved_for: Cell<Option<(f32, f32)>>
+}
+
+/// An actor of the system; the actor is null once its constraint has been
+/// removed from it.
+///
+/// `handler` is the id of the `queue-relayout` handler connected to the
+/// actor while the constraint is attached to it, or 0.
+struct Slot {
+  actor: Cell<*mut libc::c_void>,
+  handler: Cell<u64>,
+  anchors: Anchors
+}
+
+impl LinearSystem {
+  /// Creates an empty system.
+  pub fn new() -> LinearSystem {
+    let mut solver = Solver::new();
+    let parent = new_anchors(&mut solver);
+    let zero = Expression::from_constant(0.0);
+    // The variables are fresh, so none of these can conflict.
+    solver.add_constraint(parent.left().equal_to(zero.clone())).ok().expect("the parent origin cannot conflict");
+    solver.add_constraint(parent.top().equal_to(zero)).ok().expect("the parent origin cannot conflict");
+    solver.add_edit_variable(parent.width, STRONG).ok().expect("the parent width is not edited yet");
+    solver.add_edit_variable(parent.height, STRONG).ok().expect("the parent height is not edited yet");
+
+    let state = State {
+      solver: RefCell::new(solver),
+      parent: parent,
+      actors: RefCell::new(Vec::new()),
+      solved_for: Cell::new(None)
+    };
+    return LinearSystem { state: Rc::new(state) };
+  }
+
+  /// Retrieves the anchors of the parent of the actors.
+  pub fn parent(&self) -> Anchors {
+    return self.state.parent;
+  }
+
+  /// Adds `actor` to the system and returns its anchors.
+  ///
+  /// A constraint is added to the actor, which applies the solution of the
+  /// system to its allocation. Adding an actor which is already part of the
+  /// system returns its current anchors.
+  pub fn add_actor<T: Actor>(&mut self, actor: &mut T) -> Anchors {
+    unsafe {
+      let raw = actor.as_actor();
+      if let Some(slot) = self.state.actors.borrow().iter().find(|slot| slot.actor.get() == raw) {
+        return slot.anchors;
+      }
+
+      let anchors = self.state.add_anchors();
+      let slot = Rc::new(Slot { actor: Cell::new(std::ptr::null_mut()), handler: Cell::new(0), anchors: anchors });
+      self.state.actors.borrow_mut().push(slot.clone());
+
+      // The constraint takes the actor over in set_actor().
+      let constraint = LinearConstraintRef::new(self, slot);
+      clutter_actor_add_constraint(raw, constraint.opaque);
+      return anchors;
+    }
+  }
+
+  /// Adds a constraint to the system and queues a relayout of the actors.
+  ///
+  /// Fails if `constraint` is required and conflicts with the other
+  /// required constraints.
+  pub fn add_constraint(&mut self, constraint: LinearConstraint) -> Result<ConstraintHandle, SolverError> {
+    let handle = try!(self.state.solver.borrow_mut().add_constraint(constraint));
+    self.queue_relayout();
+    return Ok(handle);
+  }
+
+  /// Removes a constraint from the system and queues a relayout of the
+  /// actors.
+  pub fn remove_constraint(&mut self, handle: ConstraintHandle) -> Result<(), SolverError> {
+    try!(self.state.solver.borrow_mut().remove_constraint(handle));
+    self.queue_relayout();
+    return Ok(());
+  }
+
+  fn queue_relayout(&self) {
+    self.state.solved_for.set(None);
+    for actor in self.state.live_actors().into_iter() {
+      unsafe {
+        clutter_actor_queue_relayout(actor);
+      }
+    }
+  }
+}
+
+fn new_anchors(solver: &mut Solver) -> Anchors {
+  return Anchors {
+    left: solver.new_variable(),
+    top: solver.new_variable(),
+    width: solver.new_variable(),
+    height: solver.new_variable()
+  };
+}
+
+impl State {
+  /// Creates the anchors of a new actor, whose size can shrink to zero but
+  /// not below.
+  fn add_anchors(&self) -> Anchors {
+    let mut solver = self.solver.borrow_mut();
+    let anchors = new_anchors(&mut *solver);
+    let zero = Expression::from_constant(0.0);
+    // The variables are fresh, so none of these can conflict.
+    solver.add_constraint(anchors.width().greater_than_or_equal_to(zero.clone())).ok().expect("a new size cannot conflict");
+    solver.add_constraint(anchors.height().greater_than_or_equal_to(zero)).ok().expect("a new size cannot conflict");
+    solver.add_edit_variable(anchors.width, WEAK).ok().expect("a new width is not edited yet");
+    solver.add_edit_variable(anchors.height, WEAK).ok().expect("a new height is not edited yet");
+    self.solved_for.set(None);
+    return anchors;
+  }
+
+  fn live_actors(&self) -> Vec<*mut libc::c_void> {
+    return self.actors.borrow().iter()
+      .map(|slot| slot.actor.get())
+      .filter(|actor| !actor.is_null())
+      .collect();
+  }
+
+  /// Computes the allocation of `slot` in `parent`, solving the system
+  /// first unless the current solution is still valid.
+  unsafe fn allocation(&self, parent: *mut libc::c_void, slot: &Slot) -> Result<Box, SolverError> {
+    let mut parent_size = None;
+    if !parent.is_null() {
+      let mut parent_box: Box = std::intrinsics::init();
+      clutter_actor_get_allocation_box(parent, &mut parent_box);
+      parent_size = Some((parent_box.get_width(), parent_box.get_height()));
+    }
+
+    try!(self.ensure_solved(parent_size, || self.natural_sizes()));
+    let (x, y, width, height) = self.solution(slot.anchors);
+    return Ok(Box::from_rect(x, y, width, height));
+  }
+
+  /// Solves the system unless the current solution was computed for the
+  /// same size of the parent and is still valid.
+  ///
+  /// `natural_sizes` is only called when the system has to be solved.
+  fn ensure_solved<F>(&self, parent_size: Option<(f32, f32)>, natural_sizes: F) -> Result<(), SolverError> where F: FnOnce() -> Vec<(Anchors, f32, f32)> {
+    let solved_for = parent_size.unwrap_or((0.0, 0.0));
+    if self.solved_for.get() == Some(solved_for) {
+      return Ok(());
+    }
+
+    // Set first, so that a relayout queued while solving invalidates the
+    // new solution.
+    self.solved_for.set(Some(solved_for));
+    let result = self.solve(parent_size, natural_sizes());
+    if result.is_err() {
+      self.solved_for.set(None);
+    }
+
+    return result;
+  }
+
+  /// Retrieves the position and size given to `anchors` by the current
+  /// solution.
+  fn solution(&self, anchors: Anchors) -> (f32, f32, f32, f32) {
+    let solver = self.solver.borrow();
+    return (solver.value(anchors.left) as f32, solver.value(anchors.top) as f32,
+            solver.value(anchors.width) as f32, solver.value(anchors.height) as f32);
+  }
+
+  /// Retrieves the natural sizes of the actors still in the system.
+  unsafe fn natural_sizes(&self) -> Vec<(Anchors, f32, f32)> {
+    let slots: Vec<Rc<Slot>> = self.actors.borrow().iter()
+      .filter(|slot| !slot.actor.get().is_null())
+      .map(|slot| slot.clone())
+      .collect();
+
+    let null_f32: *mut f32 = std::ptr::null_mut();
+    let mut natural_sizes = Vec::new();
+    for slot in slots.iter() {
+      let mut natural_width: f32 = 0.0;
+      let mut natural_height: f32 = 0.0;
+      clutter_actor_get_preferred_size(slot.actor.get(), null_f32, null_f32, &mut natural_width, &mut natural_height);
+      natural_sizes.push((slot.anchors, natural_width, natural_height));
+    }
+
+    return natural_sizes;
+  }
+
+  /// Solves the system for the given size of the parent, if known, and the
+  /// natural sizes of the actors.
+  fn solve(&self, parent_size: Option<(f32, f32)>, natural_sizes: Vec<(Anchors, f32, f32)>) -> Result<(), SolverError> {
+    let mut solver = self.solver.borrow_mut();
+    if let Some((width, height)) = parent_size {
+      try!(solver.suggest_value(self.parent.width, width as f64));
+      try!(solver.suggest_value(self.parent.height, height as f64));
+    }
+
+    for &(anchors, natural_width, natural_height) in natural_sizes.iter() {
+      try!(solver.suggest_value(anchors.width, natural_width as f64));
+      try!(solver.suggest_value(anchors.height, natural_height as f64));
+    }
+
+    return Ok(());
+  }
+}
+
+/// Opaque struct which holds a reference to the underlying Clutter object.
+///
+/// A LinearConstraintRef is created by `LinearSystem#add_actor()`; it is a
+/// Constraint implemented in Rust, applying the solution of the system to
+/// the allocation of the actor.
+#[repr(C)]
+pub struct LinearConstraintRef {
+  opaque: *mut libc::c_void
+}
+
+impl LinearConstraintRef {
+  fn new(system: &LinearSystem, slot: Rc<Slot>) -> LinearConstraintRef {
+    unsafe {
+      let instance = std::boxed::Box::new(Instance { state: system.state.clone(), slot: slot });
+      let null_void: *mut libc::c_void = std::ptr::null_mut();
+      let foreign_result = g_object_newv(register_type(), 0, null_void);
+      g_object_set_qdata_full(foreign_result, instance_quark(), std::mem::transmute(instance), free_instance);
+      return LinearConstraintRef { opaque: foreign_result };
+    }
+  }
+}
+
+impl super::Constraint for LinearConstraintRef {
+  fn as_constraint(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+impl super::super::actor::ActorMeta for LinearConstraintRef {
+  fn as_actor_meta(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+/// The data attached to each LinearConstraintRef.
+struct Instance {
+  state: Rc<State>,
+  slot: Rc<Slot>
+}
+
+/// Mirror of ClutterConstraintClass, up to the virtual functions that are
+/// overridden.
+#[repr(C)]
+struct ConstraintClass {
+  object_class: [*mut libc::c_void; 17],
+  set_actor: extern "C" fn(*mut libc::c_void, *mut libc::c_void),
+  meta_padding: [*mut libc::c_void; 7],
+  update_allocation: extern "C" fn(*mut libc::c_void, *mut libc::c_void, *mut Box)
+}
+
+/// Registers the type of LinearConstraintRef, unless it already exists.
+unsafe fn register_type() -> Type {
+  let name = "RustLinearConstraint";
+  return match ffi::find_type(name) {
+    Some(existing) => existing,
+    None => ffi::register_subclass(clutter_constraint_get_type(), name, class_init, std::ptr::null_mut())
+  };
+}
+
+fn instance_quark() -> u32 {
+  unsafe {
+    return g_quark_from_static_string("rust-linear-constraint-instance\0".as_ptr() as *const i8);
+  }
+}
+
+unsafe fn get_instance<'a>(constraint: *mut libc::c_void) -> &'a Instance {
+  return std::mem::transmute(g_object_get_qdata(constraint, instance_quark()));
+}
+
+extern "C" fn free_instance(instance: *mut libc::c_void) {
+  unsafe {
+    let instance: std::boxed::Box<Instance> = std::mem::transmute(instance);
+    // Clutter removes the constraint from its actor before finalizing it,
+    // so the handler is already disconnected. Only Cells are updated, as
+    // the qdata may be freed at any time.
+    instance.slot.actor.set(std::ptr::null_mut());
+    instance.state.solved_for.set(None);
+    drop(instance);
+  }
+}
+
+extern "C" fn class_init(klass: *mut libc::c_void, _class_data: *mut libc::c_void) {
+  unsafe {
+    let klass = &mut *(klass as *mut ConstraintClass);
+    klass.set_actor = set_actor;
+    klass.update_allocation = update_allocation;
+  }
+}
+
+/// Called by Clutter when the constraint is added to an actor or removed
+/// from it, including when the actor is destroyed.
+///
+/// While attached, the constraint listens to `queue-relayout` on its actor,
+/// as a relayout may change the preferred size of the actor.
+extern "C" fn set_actor(constraint: *mut libc::c_void, actor: *mut libc::c_void) {
+  unsafe {
+    let instance = get_instance(constraint);
+    let slot = &*instance.slot;
+    let previous = slot.actor.get();
+    if previous != actor {
+      if !previous.is_null() && slot.handler.get() != 0 {
+        g_signal_handler_disconnect(previous, slot.handler.get());
+      }
+      slot.handler.set(0);
+      slot.actor.set(actor);
+      instance.state.solved_for.set(None);
+
+      if !actor.is_null() {
+        let state = instance.state.clone();
+        let handler = signal::connect(actor, "queue-relayout", std::boxed::Box::new(move |_| {
+          state.solved_for.set(None);
+          return None;
+        }), false);
+        slot.handler.set(handler);
+      }
+    }
+
+    let parent_class = g_type_class_peek(clutter_constraint_get_type()) as *const ConstraintClass;
+    ((*parent_class).set_actor)(constraint, actor);
+  }
+}
+
+extern "C" fn update_allocation(constraint: *mut libc::c_void, actor: *mut libc::c_void, allocation: *mut Box) {
+  unsafe {
+    let instance = get_instance(constraint);
+    let parent = clutter_actor_get_parent(actor);
+    match instance.state.allocation(parent, &*instance.slot) {
+      Ok(solution) => *allocation = solution,
+      Err(error) => ffi::warn(format!("RustLinearConstraint: cannot solve the system: {:?}", error).as_slice())
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::cell::Cell;
+  use super::LinearSystem;
+  use super::super::solver::{Expression, Variable};
+
+  fn assert_near(actual: f32, expected: f32) {
+    assert!((actual - expected).abs() < 1.0e-4, "expected {}, got {}", expected, actual);
+  }
+
+  /// Solves the system for a parent of 200x100, and returns the value of
+  /// `expression` in the solution.
+  fn value_of(system: &LinearSystem, expression: Expression) -> f32 {
+    let probe: Variable = system.state.solver.borrow_mut().new_variable();
+    system.state.solver.borrow_mut().add_constraint(Expression::from_variable(probe).equal_to(expression)).unwrap();
+    system.state.solved_for.set(None);
+    system.state.ensure_solved(Some((200.0, 100.0)), || Vec::new()).unwrap();
+    return system.state.solver.borrow().value(probe) as f32;
+  }
+
+  #[test]
+  fn anchors_expressions() {
+    let mut system = LinearSystem::new();
+    let a = system.state.add_anchors();
+    system.add_constraint(a.left().equal_to(Expression::from_constant(10.0))).unwrap();
+    system.add_constraint(a.top().equal_to(Expression::from_constant(20.0))).unwrap();
+    system.add_constraint(a.width().equal_to(Expression::from_constant(30.0))).unwrap();
+    system.add_constraint(a.height().equal_to(Expression::from_constant(40.0))).unwrap();
+
+    assert_near(value_of(&system, a.right()), 40.0);
+    assert_near(value_of(&system, a.bottom()), 60.0);
+    assert_near(value_of(&system, a.center_x()), 25.0);
+    assert_near(value_of(&system, a.center_y()), 40.0);
+    assert_eq!(system.state.solution(a), (10.0, 20.0, 30.0, 40.0));
+  }
+
+  #[test]
+  fn parent_follows_its_size() {
+    let system = LinearSystem::new();
+    let parent = system.parent();
+    assert_near(value_of(&system, parent.left()), 0.0);
+    assert_near(value_of(&system, parent.right()), 200.0);
+    assert_near(value_of(&system, parent.center_y()), 50.0);
+  }
+
+  #[test]
+  fn natural_sizes_yield_to_constraints() {
+    let mut system = LinearSystem::new();
+    let parent = system.parent();
+    let a = system.state.add_anchors();
+    let b = system.state.add_anchors();
+    system.add_constraint(a.left().equal_to(parent.left())).unwrap();
+    system.add_constraint(a.width().equal_to(parent.width() * 0.5)).unwrap();
+    system.add_constraint((a.right() + 8.0).equal_to(b.left())).unwrap();
+
+    system.state.ensure_solved(Some((200.0, 100.0)), || vec![(a, 50.0, 20.0), (b, 60.0, 30.0)]).unwrap();
+    let (_, _, width, height) = system.state.solution(a);
+    assert_near(width, 100.0);
+    assert_near(height, 20.0);
+    let (x, _, width, _) = system.state.solution(b);
+    assert_near(x, 108.0);
+    assert_near(width, 60.0);
+  }
+
+  #[test]
+  fn solution_is_reused_per_parent_size() {
+    let system = LinearSystem::new();
+    let a = system.state.add_anchors();
+    let solves = Cell::new(0);
+    let natural_sizes = || {
+      solves.set(solves.get() + 1);
+      return vec![(a, 50.0, 20.0)];
+    };
+
+    system.state.ensure_solved(Some((200.0, 100.0)), &natural_sizes).unwrap();
+    system.state.ensure_solved(Some((200.0, 100.0)), &natural_sizes).unwrap();
+    assert_eq!(solves.get(), 1);
+
+    system.state.ensure_solved(Some((300.0, 100.0)), &natural_sizes).unwrap();
+    assert_eq!(solves.get(), 2);
+
+    // What a queued relayout of an actor does.
+    system.state.solved_for.set(None);
+    system.state.ensure_solved(Some((300.0, 100.0)), &natural_sizes).unwrap();
+    assert_eq!(solves.get(), 3);
+
+    // Adding an actor invalidates the solution as well.
+    system.state.add_anchors();
+    system.state.ensure_solved(Some((300.0, 100.0)), &natural_sizes).unwrap();
+    assert_eq!(solves.get(), 4);
+  }
+}
+
+extern {
+  fn clutter_constraint_get_type() -> Type;
+  fn clutter_actor_add_constraint(self_value: *mut libc::c_void, constraint: *mut libc::c_void);
+  fn clutter_actor_queue_relayout(self_value: *mut libc::c_void);
+  fn clutter_actor_get_parent(self_value: *mut libc::c_void) -> *mut libc::c_void;
+  fn clutter_actor_get_allocation_box(self_value: *mut libc::c_void, allocation: *mut Box);
+  fn clutter_actor_get_preferred_size(self_value: *mut libc::c_void, min_width_p: *mut f32, min_height_p: *mut f32, natural_width_p: *mut f32, natural_height_p: *mut f32);
+  fn g_object_newv(object_type: Type, n_parameters: u32, parameters: *mut libc::c_void) -> *mut libc::c_void;
+  fn g_object_get_qdata(object: *mut libc::c_void, quark: u32) -> *mut libc::c_void;
+  fn g_object_set_qdata_full(object: *mut libc::c_void, quark: u32, data: *mut libc::c_void, destroy: extern "C" fn(*mut libc::c_void));
+  fn g_quark_from_static_string(string: *const libc::c_char) -> u32;
+  fn g_signal_handler_disconnect(instance: *mut libc::c_void, handler_id: u64);
+  fn g_type_class_peek(g_type: Type) -> *mut libc::c_void;
+}
@@ -1,10 +1,17 @@
 #![stable]
 
 use libc;
+use std;
 use super::actor::Actor;
+use super::actor::ActorHandle;
 use super::actor::ActorMeta;
+use super::path::Path;
 
+pub mod align;
 pub mod bind;
+pub mod linear;
+pub mod snap;
+pub mod solver;
 
 /// Opaque struct which holds a reference to the underlying Clutter object.
 #[repr(C)]
@@ -68,6 +75,69 @@ pub trait BindConstraint {
   ///
   /// Generally only used internally.
   fn as_bind_constraint(&self) -> *mut libc::c_void;
+
+  /// Sets the source Actor for the constraint.
+  ///
+  /// _Since 1.4_
+  fn set_source<T: Actor>(&mut self, source: &mut T) {
+    unsafe {
+      clutter_bind_constraint_set_source(self.as_bind_constraint(), source.as_actor());
+    }
+  }
+
+  /// Retrieves the Actor set using `.set_source()`, if any.
+  ///
+  /// The source is not owned by the constraint.
+  ///
+  /// _Since 1.4_
+  fn get_source(&mut self) -> Option<ActorHandle> {
+    unsafe {
+      let foreign_result = clutter_bind_constraint_get_source(self.as_bind_constraint());
+      if foreign_result.is_null() {
+        return None;
+      }
+
+      return Some(std::mem::transmute(foreign_result));
+    }
+  }
+
+  /// Sets the coordinate to bind in the constraint.
+  ///
+  /// _Since 1.4_
+  fn set_coordinate(&mut self, coordinate: bind::Coordinate) {
+    unsafe {
+      clutter_bind_constraint_set_coordinate(self.as_bind_constraint(), coordinate);
+    }
+  }
+
+  /// Retrieves the bound coordinate of the constraint.
+  ///
+  /// _Since 1.4_
+  fn get_coordinate(&mut self) -> bind::Coordinate {
+    unsafe {
+      let foreign_result = clutter_bind_constraint_get_coordinate(self.as_bind_constraint());
+      return foreign_result;
+    }
+  }
+
+  /// Sets the offset to be applied to the constraint.
+  ///
+  /// _Since 1.4_
+  fn set_offset(&mut self, offset: f32) {
+    unsafe {
+      clutter_bind_constraint_set_offset(self.as_bind_constraint(), offset);
+    }
+  }
+
+  /// Retrieves the offset set using `.set_offset()`.
+  ///
+  /// _Since 1.4_
+  fn get_offset(&mut self) -> f32 {
+    unsafe {
+      let foreign_result = clutter_bind_constraint_get_offset(self.as_bind_constraint());
+      return foreign_result;
+    }
+  }
 }
 
 impl BindConstraint for BindConstraintRef {
@@ -82,6 +152,392 @@ impl Constraint for BindConstraintRef {
   }
 }
 
+impl ActorMeta for BindConstraintRef {
+  fn as_actor_meta(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
 extern {
   fn clutter_bind_constraint_new(source: *mut libc::c_void, coordinate: bind::Coordinate, offset: f32) -> BindConstraintRef;
+  fn clutter_bind_constraint_set_source(self_value: *mut libc::c_void, source: *mut libc::c_void);
+  fn clutter_bind_constraint_get_source(self_value: *mut libc::c_void) -> *mut libc::c_void;
+  fn clutter_bind_constraint_set_coordinate(self_value: *mut libc::c_void, coordinate: bind::Coordinate);
+  fn clutter_bind_constraint_get_coordinate(self_value: *mut libc::c_void) -> bind::Coordinate;
+  fn clutter_bind_constraint_set_offset(self_value: *mut libc::c_void, offset: f32);
+  fn clutter_bind_constraint_get_offset(self_value: *mut libc::c_void) -> f32;
+}
+
+/// Opaque struct which holds a reference to the underlying Clutter object.
+#[repr(C)]
+pub struct AlignConstraintRef {
+  opaque: *mut libc::c_void
+}
+
+impl AlignConstraintRef {
+  /// Creates a new constraint, aligning an Actor's position with regards of
+  /// the size of the actor to `source`, with the given alignment `factor`.
+  ///
+  /// _Since 1.4_
+  pub fn new<T: Actor>(source: &mut T, axis: align::AlignAxis, factor: f32) -> AlignConstraintRef {
+    unsafe {
+      let foreign_result = clutter_align_constraint_new(source.as_actor(), axis, factor);
+      return foreign_result;
+    }
+  }
+}
+
+/// A constraint aligning the position of an actor.
+///
+/// AlignConstraint is a Constraint that aligns the position of the Actor to
+/// which it is applied to the size of another Actor using an alignment
+/// factor.
+///
+/// The alignment factor is a normalized value between 0.0, the left or top
+/// edge of the source, and 1.0, the right or bottom edge; 0.5 centers the
+/// actor on the source.
+///
+/// The source actor must not be a child or a grandchild of the actor being
+/// aligned.
+///
+/// _Since 1.4_
+pub trait AlignConstraint {
+  /// Returns a pointer to the the underlying C object.
+  ///
+  /// Generally only used internally.
+  fn as_align_constraint(&self) -> *mut libc::c_void;
+
+  /// Sets the source of the alignment constraint.
+  ///
+  /// _Since 1.4_
+  fn set_source<T: Actor>(&mut self, source: &mut T) {
+    unsafe {
+      clutter_align_constraint_set_source(self.as_align_constraint(), source.as_actor());
+    }
+  }
+
+  /// Retrieves the source of the alignment, if any.
+  ///
+  /// The source is not owned by the constraint.
+  ///
+  /// _Since 1.4_
+  fn get_source(&mut self) -> Option<ActorHandle> {
+    unsafe {
+      let foreign_result = clutter_align_constraint_get_source(self.as_align_constraint());
+      if foreign_result.is_null() {
+        return None;
+      }
+
+      return Some(std::mem::transmute(foreign_result));
+    }
+  }
+
+  /// Sets the axis to which the alignment refers to.
+  ///
+  /// _Since 1.4_
+  fn set_align_axis(&mut self, axis: align::AlignAxis) {
+    unsafe {
+      clutter_align_constraint_set_align_axis(self.as_align_constraint(), axis);
+    }
+  }
+
+  /// Retrieves the value set using `.set_align_axis()`.
+  ///
+  /// _Since 1.4_
+  fn get_align_axis(&mut self) -> align::AlignAxis {
+    unsafe {
+      let foreign_result = clutter_align_constraint_get_align_axis(self.as_align_constraint());
+      return foreign_result;
+    }
+  }
+
+  /// Sets the alignment factor of the constraint.
+  ///
+  /// The factor depends on the `align-axis` property and it is a value
+  /// between 0.0 (meaning left, when the axis is X, or top, when the axis is
+  /// Y) and 1.0 (meaning right or bottom). A value of 0.5 aligns in the
+  /// middle in either axis.
+  ///
+  /// _Since 1.4_
+  fn set_factor(&mut self, factor: f32) {
+    unsafe {
+      clutter_align_constraint_set_factor(self.as_align_constraint(), factor);
+    }
+  }
+
+  /// Retrieves the factor set using `.set_factor()`.
+  ///
+  /// _Since 1.4_
+  fn get_factor(&mut self) -> f32 {
+    unsafe {
+      let foreign_result = clutter_align_constraint_get_factor(self.as_align_constraint());
+      return foreign_result;
+    }
+  }
+}
+
+impl AlignConstraint for AlignConstraintRef {
+  fn as_align_constraint(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+impl Constraint for AlignConstraintRef {
+  fn as_constraint(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+impl ActorMeta for AlignConstraintRef {
+  fn as_actor_meta(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+extern {
+  fn clutter_align_constraint_new(source: *mut libc::c_void, axis: align::AlignAxis, factor: f32) -> AlignConstraintRef;
+  fn clutter_align_constraint_set_source(self_value: *mut libc::c_void, source: *mut libc::c_void);
+  fn clutter_align_constraint_get_source(self_value: *mut libc::c_void) -> *mut libc::c_void;
+  fn clutter_align_constraint_set_align_axis(self_value: *mut libc::c_void, axis: align::AlignAxis);
+  fn clutter_align_constraint_get_align_axis(self_value: *mut libc::c_void) -> align::AlignAxis;
+  fn clutter_align_constraint_set_factor(self_value: *mut libc::c_void, factor: f32);
+  fn clutter_align_constraint_get_factor(self_value: *mut libc::c_void) -> f32;
+}
+
+/// Opaque struct which holds a reference to the underlying Clutter object.
+#[repr(C)]
+pub struct SnapConstraintRef {
+  opaque: *mut libc::c_void
+}
+
+impl SnapConstraintRef {
+  /// Creates a new SnapConstraint that will snap the `from_edge` of the
+  /// actor to the `to_edge` of `source`, with the given `offset`.
+  ///
+  /// _Since 1.6_
+  pub fn new<T: Actor>(source: &mut T, from_edge: snap::Edge, to_edge: snap::Edge, offset: f32) -> SnapConstraintRef {
+    unsafe {
+      let foreign_result = clutter_snap_constraint_new(source.as_actor(), from_edge, to_edge, offset);
+      return foreign_result;
+    }
+  }
+}
+
+/// A constraint snapping two actors together.
+///
+/// SnapConstraint is a constraint that snaps the edges of two actors
+/// together, expanding the actor's allocation if necessary.
+///
+/// An offset can be applied to the constraint, to provide spacing.
+///
+/// _Since 1.6_
+pub trait SnapConstraint {
+  /// Returns a pointer to the the underlying C object.
+  ///
+  /// Generally only used internally.
+  fn as_snap_constraint(&self) -> *mut libc::c_void;
+
+  /// Sets the source Actor for the constraint.
+  ///
+  /// _Since 1.6_
+  fn set_source<T: Actor>(&mut self, source: &mut T) {
+    unsafe {
+      clutter_snap_constraint_set_source(self.as_snap_constraint(), source.as_actor());
+    }
+  }
+
+  /// Retrieves the Actor set using `.set_source()`, if any.
+  ///
+  /// The source is not owned by the constraint.
+  ///
+  /// _Since 1.6_
+  fn get_source(&mut self) -> Option<ActorHandle> {
+    unsafe {
+      let foreign_result = clutter_snap_constraint_get_source(self.as_snap_constraint());
+      if foreign_result.is_null() {
+        return None;
+      }
+
+      return Some(std::mem::transmute(foreign_result));
+    }
+  }
+
+  /// Sets the edges to be used by the constraint.
+  ///
+  /// The `from_edge` is the edge on the Actor to which the constraint has
+  /// been added. The `to_edge` is the edge of the Actor inside the `source`
+  /// property.
+  ///
+  /// _Since 1.6_
+  fn set_edges(&mut self, from_edge: snap::Edge, to_edge: snap::Edge) {
+    unsafe {
+      clutter_snap_constraint_set_edges(self.as_snap_constraint(), from_edge, to_edge);
+    }
+  }
+
+  /// Retrieves the edges used by the constraint, as a `(from_edge, to_edge)`
+  /// pair.
+  ///
+  /// _Since 1.6_
+  fn get_edges(&mut self) -> (snap::Edge, snap::Edge) {
+    unsafe {
+      let mut from_edge:snap::Edge = std::intrinsics::init();
+      let mut to_edge:snap::Edge = std::intrinsics::init();
+      clutter_snap_constraint_get_edges(self.as_snap_constraint(), &mut from_edge, &mut to_edge);
+      return (from_edge, to_edge);
+    }
+  }
+
+  /// Sets the offset to be applied to the constraint.
+  ///
+  /// _Since 1.6_
+  fn set_offset(&mut self, offset: f32) {
+    unsafe {
+      clutter_snap_constraint_set_offset(self.as_snap_constraint(), offset);
+    }
+  }
+
+  /// Retrieves the offset set using `.set_offset()`.
+  ///
+  /// _Since 1.6_
+  fn get_offset(&mut self) -> f32 {
+    unsafe {
+      let foreign_result = clutter_snap_constraint_get_offset(self.as_snap_constraint());
+      return foreign_result;
+    }
+  }
+}
+
+impl SnapConstraint for SnapConstraintRef {
+  fn as_snap_constraint(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+impl Constraint for SnapConstraintRef {
+  fn as_constraint(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+impl ActorMeta for SnapConstraintRef {
+  fn as_actor_meta(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+extern {
+  fn clutter_snap_constraint_new(source: *mut libc::c_void, from_edge: snap::Edge, to_edge: snap::Edge, offset: f32) -> SnapConstraintRef;
+  fn clutter_snap_constraint_set_source(self_value: *mut libc::c_void, source: *mut libc::c_void);
+  fn clutter_snap_constraint_get_source(self_value: *mut libc::c_void) -> *mut libc::c_void;
+  fn clutter_snap_constraint_set_edges(self_value: *mut libc::c_void, from_edge: snap::Edge, to_edge: snap::Edge);
+  fn clutter_snap_constraint_get_edges(self_value: *mut libc::c_void, from_edge: *mut snap::Edge, to_edge: *mut snap::Edge);
+  fn clutter_snap_constraint_set_offset(self_value: *mut libc::c_void, offset: f32);
+  fn clutter_snap_constraint_get_offset(self_value: *mut libc::c_void) -> f32;
+}
+
+/// Opaque struct which holds a reference to the underlying Clutter object.
+#[repr(C)]
+pub struct PathConstraintRef {
+  opaque: *mut libc::c_void
+}
+
+impl PathConstraintRef {
+  /// Creates a new PathConstraint with the given `path` and `offset`.
+  ///
+  /// _Since 1.6_
+  pub fn new<T: Path>(path: &mut T, offset: f32) -> PathConstraintRef {
+    unsafe {
+      let foreign_result = clutter_path_constraint_new(path.as_path(), offset);
+      return foreign_result;
+    }
+  }
+}
+
+/// A constraint that follows a path.
+///
+/// PathConstraint is a simple constraint that modifies the allocation of the
+/// Actor to which it has been applied using a Path.
+///
+/// By setting the `offset` property it is possible to control how far along
+/// the path the Actor should be.
+///
+/// The `offset` property is animatable, so an actor can be moved along the
+/// path by creating a transition on it.
+///
+/// _Since 1.6_
+pub trait PathConstraint {
+  /// Returns a pointer to the the underlying C object.
+  ///
+  /// Generally only used internally.
+  fn as_path_constraint(&self) -> *mut libc::c_void;
+
+  /// Sets the path to be followed by the constraint.
+  ///
+  /// The constraint will take ownership of the Path passed to it.
+  ///
+  /// _Since 1.6_
+  fn set_path<T: Path>(&mut self, path: &mut T) {
+    unsafe {
+      clutter_path_constraint_set_path(self.as_path_constraint(), path.as_path());
+    }
+  }
+
+  /// Retrieves a pointer to the Path used by the constraint.
+  ///
+  /// _Since 1.6_
+  fn get_path(&mut self) -> super::path::PathRef {
+    unsafe {
+      let foreign_result = clutter_path_constraint_get_path(self.as_path_constraint());
+      return foreign_result;
+    }
+  }
+
+  /// Sets the offset along the Path used by the constraint.
+  ///
+  /// The offset is a value between 0.0, the beginning of the path, and 1.0,
+  /// the end of the path.
+  ///
+  /// _Since 1.6_
+  fn set_offset(&mut self, offset: f32) {
+    unsafe {
+      clutter_path_constraint_set_offset(self.as_path_constraint(), offset);
+    }
+  }
+
+  /// Retrieves the offset along the Path used by the constraint.
+  ///
+  /// _Since 1.6_
+  fn get_offset(&mut self) -> f32 {
+    unsafe {
+      let foreign_result = clutter_path_constraint_get_offset(self.as_path_constraint());
+      return foreign_result;
+    }
+  }
+}
+
+impl PathConstraint for PathConstraintRef {
+  fn as_path_constraint(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+impl Constraint for PathConstraintRef {
+  fn as_constraint(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+impl ActorMeta for PathConstraintRef {
+  fn as_actor_meta(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+extern {
+  fn clutter_path_constraint_new(path: *mut libc::c_void, offset: f32) -> PathConstraintRef;
+  fn clutter_path_constraint_set_path(self_value: *mut libc::c_void, path: *mut libc::c_void);
+  fn clutter_path_constraint_get_path(self_value: *mut libc::c_void) -> super::path::PathRef;
+  fn clutter_path_constraint_set_offset(self_value: *mut libc::c_void, offset: f32);
+  fn clutter_path_constraint_get_offset(self_value: *mut libc::c_void) -> f32;
 }
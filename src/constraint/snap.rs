@@ -0,0 +1,19 @@
+#![stable]
+
+/// The edge to snap.
+///
+/// _Since 1.6_
+#[repr(i32)]
+pub enum Edge {
+  /// The top edge
+  Top = 0,
+
+  /// The right edge
+  Right = 1,
+
+  /// The bottom edge
+  Bottom = 2,
+
+  /// The left edge
+  Left = 3
+}
@@ -0,0 +1,965 @@
+#![stable]
+
+//! An incremental solver for systems of linear equalities and inequalities.
+//!
+//! This is an implementation of the Cassowary algorithm: every constraint has
+//! a strength, and the solver finds the solution that satisfies all of the
+//! required constraints while minimizing the weighted error of the others.
+//! Constraints can be added and removed, and the values of edit variables
+//! changed, without solving the whole system from scratch.
+
+use std;
+use std::collections::BTreeMap;
+use std::ops::{Add, Sub, Mul, Neg};
+
+/// A constraint that must be satisfied.
+pub const REQUIRED: f64 = 1001001000.0;
+
+/// A strong preference.
+pub const STRONG: f64 = 1000000.0;
+
+/// A medium preference.
+pub const MEDIUM: f64 = 1000.0;
+
+/// A weak preference.
+pub const WEAK: f64 = 1.0;
+
+/// An unknown in a system of constraints.
+///
+/// Variables are created by `Solver#new_variable()`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Variable {
+  id: usize
+}
+
+/// A linear expression: a sum of weighted variables plus a constant.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Expression {
+  /// The variables of the expression, with their coefficients
+  pub terms: Vec<(Variable, f64)>,
+
+  /// The constant of the expression
+  pub constant: f64
+}
+
+impl Expression {
+  /// Creates an expression holding the given variable.
+  pub fn from_variable(variable: Variable) -> Expression {
+    return Expression { terms: vec![(variable, 1.0)], constant: 0.0 };
+  }
+
+  /// Creates an expression holding only a constant.
+  pub fn from_constant(constant: f64) -> Expression {
+    return Expression { terms: Vec::new(), constant: constant };
+  }
+
+  /// Creates the constraint `self == other`.
+  pub fn equal_to(self, other: Expression) -> LinearConstraint {
+    return LinearConstraint::new(self - other, Relation::Equal);
+  }
+
+  /// Creates the constraint `self <= other`.
+  pub fn less_than_or_equal_to(self, other: Expression) -> LinearConstraint {
+    return LinearConstraint::new(self - other, Relation::LessOrEqual);
+  }
+
+  /// Creates the constraint `self >= other`.
+  pub fn greater_than_or_equal_to(self, other: Expression) -> LinearConstraint {
+    return LinearConstraint::new(self - other, Relation::GreaterOrEqual);
+  }
+}
+
+impl Add for Expression {
+  type Output = Expression;
+
+  fn add(mut self, other: Expression) -> Expression {
+    self.terms.extend(other.terms.into_iter());
+    self.constant += other.constant;
+    return self;
+  }
+}
+
+impl Sub for Expression {
+  type Output = Expression;
+
+  fn sub(self, other: Expression) -> Expression {
+    return self + (-other);
+  }
+}
+
+impl Add<f64> for Expression {
+  type Output = Expression;
+
+  fn add(mut self, constant: f64) -> Expression {
+    self.constant += constant;
+    return self;
+  }
+}
+
+impl Sub<f64> for Expression {
+  type Output = Expression;
+
+  fn sub(mut self, constant: f64) -> Expression {
+    self.constant -= constant;
+    return self;
+  }
+}
+
+impl Mul<f64> for Expression {
+  type Output = Expression;
+
+  fn mul(mut self, factor: f64) -> Expression {
+    for term in self.terms.iter_mut() {
+      term.1 *= factor;
+    }
+    self.constant *= factor;
+    return self;
+  }
+}
+
+impl Mul<Expression> for f64 {
+  type Output = Expression;
+
+  fn mul(self, expression: Expression) -> Expression {
+    return expression * self;
+  }
+}
+
+impl Neg for Expression {
+  type Output = Expression;
+
+  fn neg(self) -> Expression {
+    return self * -1.0;
+  }
+}
+
+/// The relation between the two sides of a constraint.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Relation {
+  /// The left side is less than or equal to the right side
+  LessOrEqual,
+
+  /// Both sides are equal
+  Equal,
+
+  /// The left side is greater than or equal to the right side
+  GreaterOrEqual
+}
+
+/// A linear relation between variables, with a strength.
+///
+/// The constraint is stored as `expression <relation> 0`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct LinearConstraint {
+  /// The expression compared to zero
+  pub expression: Expression,
+
+  /// How the expression compares to zero
+  pub relation: Relation,
+
+  /// The strength of the constraint, between `WEAK` and `REQUIRED`
+  pub strength: f64
+}
+
+impl LinearConstraint {
+  /// Creates a required constraint.
+  pub fn new(expression: Expression, relation: Relation) -> LinearConstraint {
+    return LinearConstraint { expression: expression, relation: relation, strength: REQUIRED };
+  }
+
+  /// Returns the same constraint with the given `strength`.
+  pub fn with_strength(mut self, strength: f64) -> LinearConstraint {
+    self.strength = clip_strength(strength);
+    return self;
+  }
+}
+
+/// Identifies a constraint added to a Solver.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct ConstraintHandle {
+  id: usize
+}
+
+/// The errors reported by the Solver.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SolverError {
+  /// A required constraint conflicts with the other required constraints
+  UnsatisfiableConstraint,
+
+  /// The constraint has not been added to the solver
+  UnknownConstraint,
+
+  /// The variable is already an edit variable
+  DuplicateEditVariable,
+
+  /// The variable is not an edit variable
+  UnknownEditVariable,
+
+  /// Edit variables cannot have a required strength
+  BadRequiredStrength,
+
+  /// The solver reached an inconsistent state
+  InternalError
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+enum SymbolKind {
+  Invalid,
+  External,
+  Slack,
+  Error,
+  Dummy
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+struct Symbol {
+  id: usize,
+  kind: SymbolKind
+}
+
+impl Symbol {
+  fn invalid() -> Symbol {
+    return Symbol { id: 0, kind: SymbolKind::Invalid };
+  }
+
+  fn is_restricted(&self) -> bool {
+    return self.kind == SymbolKind::Slack || self.kind == SymbolKind::Error;
+  }
+}
+
+/// The symbols added to the tableau for a constraint.
+#[derive(Clone, Copy)]
+struct Tag {
+  marker: Symbol,
+  other: Symbol
+}
+
+struct Edit {
+  handle: ConstraintHandle,
+  tag: Tag,
+  constant: f64
+}
+
+/// A row of the tableau: `basic symbol = constant + sum(coefficient * symbol)`.
+#[derive(Clone)]
+struct Row {
+  cells: BTreeMap<Symbol, f64>,
+  constant: f64
+}
+
+impl Row {
+  fn new(constant: f64) -> Row {
+    return Row { cells: BTreeMap::new(), constant: constant };
+  }
+
+  fn add(&mut self, value: f64) -> f64 {
+    self.constant += value;
+    return self.constant;
+  }
+
+  fn insert_symbol(&mut self, symbol: Symbol, coefficient: f64) {
+    let updated = self.coefficient_for(symbol) + coefficient;
+    if near_zero(updated) {
+      self.cells.remove(&symbol);
+    } else {
+      self.cells.insert(symbol, updated);
+    }
+  }
+
+  fn insert_row(&mut self, other: &Row, coefficient: f64) {
+    self.constant += other.constant * coefficient;
+    for (&symbol, &value) in other.cells.iter() {
+      self.insert_symbol(symbol, value * coefficient);
+    }
+  }
+
+  fn remove(&mut self, symbol: Symbol) -> Option<f64> {
+    return self.cells.remove(&symbol);
+  }
+
+  fn reverse_sign(&mut self) {
+    self.constant = -self.constant;
+    for value in self.cells.values_mut() {
+      *value = -*value;
+    }
+  }
+
+  /// Solves the row for `symbol`, which must be in the row, turning the row
+  /// into `symbol = ...`.
+  fn solve_for(&mut self, symbol: Symbol) {
+    let coefficient = -1.0 / self.cells.remove(&symbol).unwrap();
+    self.constant *= coefficient;
+    for value in self.cells.values_mut() {
+      *value *= coefficient;
+    }
+  }
+
+  /// Solves the row `lhs = ...` for `rhs`, which must be in the row.
+  fn solve_for_symbols(&mut self, lhs: Symbol, rhs: Symbol) {
+    self.insert_symbol(lhs, -1.0);
+    self.solve_for(rhs);
+  }
+
+  fn coefficient_for(&self, symbol: Symbol) -> f64 {
+    return match self.cells.get(&symbol) {
+      Some(value) => *value,
+      None => 0.0
+    };
+  }
+
+  fn substitute(&mut self, symbol: Symbol, row: &Row) {
+    match self.remove(symbol) {
+      Some(coefficient) => self.insert_row(row, coefficient),
+      None => ()
+    }
+  }
+}
+
+/// An incremental solver for linear constraints.
+///
+/// Required constraints are always satisfied; if a new required constraint
+/// conflicts with them, `.add_constraint()` fails. Non-required constraints
+/// are satisfied as well as possible, stronger ones taking precedence.
+///
+/// Edit variables are variables whose value is suggested from the outside,
+/// for instance the size of a container; `.suggest_value()` updates the
+/// solution incrementally.
+pub struct Solver {
+  constraints: BTreeMap<ConstraintHandle, (Tag, LinearConstraint)>,
+  rows: BTreeMap<Symbol, Row>,
+  variables: BTreeMap<Variable, Symbol>,
+  edits: BTreeMap<Variable, Edit>,
+  infeasible_rows: Vec<Symbol>,
+  objective: Row,
+  artificial: Option<Row>,
+  next_symbol: usize,
+  next_variable: usize,
+  next_constraint: usize
+}
+
+impl Solver {
+  /// Creates an empty solver.
+  pub fn new() -> Solver {
+    return Solver {
+      constraints: BTreeMap::new(),
+      rows: BTreeMap::new(),
+      variables: BTreeMap::new(),
+      edits: BTreeMap::new(),
+      infeasible_rows: Vec::new(),
+      objective: Row::new(0.0),
+      artificial: None,
+      next_symbol: 1,
+      next_variable: 0,
+      next_constraint: 0
+    };
+  }
+
+  /// Creates a new variable.
+  ///
+  /// The value of a variable that is not part of any constraint is 0.
+  pub fn new_variable(&mut self) -> Variable {
+    let variable = Variable { id: self.next_variable };
+    self.next_variable += 1;
+    return variable;
+  }
+
+  /// Retrieves the value of `variable` in the current solution.
+  pub fn value(&self, variable: Variable) -> f64 {
+    return match self.variables.get(&variable) {
+      Some(symbol) => match self.rows.get(symbol) {
+        Some(row) => row.constant,
+        None => 0.0
+      },
+      None => 0.0
+    };
+  }
+
+  /// Adds a constraint to the solver and updates the solution.
+  ///
+  /// If the constraint cannot be added, the solver is left as it was.
+  pub fn add_constraint(&mut self, constraint: LinearConstraint) -> Result<ConstraintHandle, SolverError> {
+    // Adding a row pivots the tableau before knowing whether the row can be
+    // satisfied, so the tableau is restored on failure.
+    let rows = self.rows.clone();
+    let objective = self.objective.clone();
+    let tag = match self.add_row(&constraint) {
+      Ok(tag) => tag,
+      Err(error) => {
+        self.rows = rows;
+        self.objective = objective;
+        self.artificial = None;
+        self.infeasible_rows.clear();
+        return Err(error);
+      }
+    };
+
+    let handle = ConstraintHandle { id: self.next_constraint };
+    self.next_constraint += 1;
+    self.constraints.insert(handle, (tag, constraint));
+    return Ok(handle);
+  }
+
+  /// Removes a constraint from the solver and updates the solution.
+  pub fn remove_constraint(&mut self, handle: ConstraintHandle) -> Result<(), SolverError> {
+    let (tag, constraint) = match self.constraints.remove(&handle) {
+      Some(entry) => entry,
+      None => return Err(SolverError::UnknownConstraint)
+    };
+
+    self.remove_constraint_effects(&tag, constraint.strength);
+
+    if self.rows.remove(&tag.marker).is_none() {
+      let leaving = self.get_marker_leaving_symbol(tag.marker);
+      if leaving.kind == SymbolKind::Invalid {
+        return Err(SolverError::InternalError);
+      }
+
+      let mut row = self.rows.remove(&leaving).unwrap();
+      row.solve_for_symbols(leaving, tag.marker);
+      self.substitute(tag.marker, &row);
+    }
+
+    return self.optimize(false);
+  }
+
+  /// Checks whether the constraint identified by `handle` is in the solver.
+  pub fn has_constraint(&self, handle: ConstraintHandle) -> bool {
+    return self.constraints.contains_key(&handle);
+  }
+
+  /// Makes `variable` an edit variable with the given `strength`, which must
+  /// be weaker than `REQUIRED`.
+  pub fn add_edit_variable(&mut self, variable: Variable, strength: f64) -> Result<(), SolverError> {
+    if self.edits.contains_key(&variable) {
+      return Err(SolverError::DuplicateEditVariable);
+    }
+
+    let strength = clip_strength(strength);
+    if strength >= REQUIRED {
+      return Err(SolverError::BadRequiredStrength);
+    }
+
+    let constraint = LinearConstraint::new(Expression::from_variable(variable), Relation::Equal).with_strength(strength);
+    let handle = try!(self.add_constraint(constraint));
+    let tag = self.constraints.get(&handle).unwrap().0;
+    self.edits.insert(variable, Edit { handle: handle, tag: tag, constant: 0.0 });
+    return Ok(());
+  }
+
+  /// Removes the edit variable `variable` from the solver.
+  pub fn remove_edit_variable(&mut self, variable: Variable) -> Result<(), SolverError> {
+    return match self.edits.remove(&variable) {
+      Some(edit) => self.remove_constraint(edit.handle),
+      None => Err(SolverError::UnknownEditVariable)
+    };
+  }
+
+  /// Checks whether `variable` is an edit variable.
+  pub fn has_edit_variable(&self, variable: Variable) -> bool {
+    return self.edits.contains_key(&variable);
+  }
+
+  /// Suggests a `value` for the edit variable `variable` and updates the
+  /// solution.
+  pub fn suggest_value(&mut self, variable: Variable, value: f64) -> Result<(), SolverError> {
+    let (tag, delta) = match self.edits.get_mut(&variable) {
+      Some(edit) => {
+        let delta = value - edit.constant;
+        edit.constant = value;
+        (edit.tag, delta)
+      },
+      None => return Err(SolverError::UnknownEditVariable)
+    };
+
+    if let Some(row) = self.rows.get_mut(&tag.marker) {
+      if row.add(-delta) < 0.0 {
+        self.infeasible_rows.push(tag.marker);
+      }
+      return self.dual_optimize();
+    }
+
+    if let Some(row) = self.rows.get_mut(&tag.other) {
+      if row.add(delta) < 0.0 {
+        self.infeasible_rows.push(tag.other);
+      }
+      return self.dual_optimize();
+    }
+
+    for (&symbol, row) in self.rows.iter_mut() {
+      let coefficient = row.coefficient_for(tag.marker);
+      if coefficient != 0.0 && row.add(delta * coefficient) < 0.0 && symbol.kind != SymbolKind::External {
+        self.infeasible_rows.push(symbol);
+      }
+    }
+
+    return self.dual_optimize();
+  }
+
+  /// Adds the row of `constraint` to the tableau and optimizes it.
+  fn add_row(&mut self, constraint: &LinearConstraint) -> Result<Tag, SolverError> {
+    let (mut row, tag) = self.create_row(constraint);
+    let mut subject = choose_subject(&row, &tag);
+
+    if subject.kind == SymbolKind::Invalid && all_dummies(&row) {
+      if !near_zero(row.constant) {
+        return Err(SolverError::UnsatisfiableConstraint);
+      }
+      subject = tag.marker;
+    }
+
+    if subject.kind == SymbolKind::Invalid {
+      if !try!(self.add_with_artificial_variable(row)) {
+        return Err(SolverError::UnsatisfiableConstraint);
+      }
+    } else {
+      row.solve_for(subject);
+      self.substitute(subject, &row);
+      self.rows.insert(subject, row);
+    }
+
+    try!(self.optimize(false));
+    return Ok(tag);
+  }
+
+  fn new_symbol(&mut self, kind: SymbolKind) -> Symbol {
+    let symbol = Symbol { id: self.next_symbol, kind: kind };
+    self.next_symbol += 1;
+    return symbol;
+  }
+
+  fn variable_symbol(&mut self, variable: Variable) -> Symbol {
+    if let Some(symbol) = self.variables.get(&variable) {
+      return *symbol;
+    }
+
+    let symbol = self.new_symbol(SymbolKind::External);
+    self.variables.insert(variable, symbol);
+    return symbol;
+  }
+
+  /// Creates a row for `constraint`, expressed in terms of the current
+  /// parametric symbols, along with the symbols identifying the constraint.
+  fn create_row(&mut self, constraint: &LinearConstraint) -> (Row, Tag) {
+    let expression = &constraint.expression;
+    let mut row = Row::new(expression.constant);
+
+    for &(variable, coefficient) in expression.terms.iter() {
+      if near_zero(coefficient) {
+        continue;
+      }
+
+      let symbol = self.variable_symbol(variable);
+      match self.rows.get(&symbol) {
+        Some(basic) => row.insert_row(basic, coefficient),
+        None => row.insert_symbol(symbol, coefficient)
+      }
+    }
+
+    let mut tag = Tag { marker: Symbol::invalid(), other: Symbol::invalid() };
+    match constraint.relation {
+      Relation::LessOrEqual | Relation::GreaterOrEqual => {
+        let coefficient = if constraint.relation == Relation::LessOrEqual { 1.0 } else { -1.0 };
+        let slack = self.new_symbol(SymbolKind::Slack);
+        tag.marker = slack;
+        row.insert_symbol(slack, coefficient);
+        if constraint.strength < REQUIRED {
+          let error = self.new_symbol(SymbolKind::Error);
+          tag.other = error;
+          row.insert_symbol(error, -coefficient);
+          self.objective.insert_symbol(error, constraint.strength);
+        }
+      },
+      Relation::Equal => {
+        if constraint.strength < REQUIRED {
+          let error_plus = self.new_symbol(SymbolKind::Error);
+          let error_minus = self.new_symbol(SymbolKind::Error);
+          tag.marker = error_plus;
+          tag.other = error_minus;
+          row.insert_symbol(error_plus, -1.0);
+          row.insert_symbol(error_minus, 1.0);
+          self.objective.insert_symbol(error_plus, constraint.strength);
+          self.objective.insert_symbol(error_minus, constraint.strength);
+        } else {
+          let dummy = self.new_symbol(SymbolKind::Dummy);
+          tag.marker = dummy;
+          row.insert_symbol(dummy, 1.0);
+        }
+      }
+    }
+
+    if row.constant < 0.0 {
+      row.reverse_sign();
+    }
+
+    return (row, tag);
+  }
+
+  /// Adds `row` to the tableau using an artificial variable, returning
+  /// whether the row could be satisfied.
+  fn add_with_artificial_variable(&mut self, row: Row) -> Result<bool, SolverError> {
+    let artificial = self.new_symbol(SymbolKind::Slack);
+    self.rows.insert(artificial, row.clone());
+    self.artificial = Some(row);
+
+    try!(self.optimize(true));
+    let success = near_zero(self.artificial.as_ref().unwrap().constant);
+    self.artificial = None;
+
+    if let Some(mut basic) = self.rows.remove(&artificial) {
+      if basic.cells.is_empty() {
+        return Ok(success);
+      }
+
+      let entering = any_pivotable_symbol(&basic);
+      if entering.kind == SymbolKind::Invalid {
+        return Ok(false);
+      }
+
+      basic.solve_for_symbols(artificial, entering);
+      self.substitute(entering, &basic);
+      self.rows.insert(entering, basic);
+    }
+
+    for basic in self.rows.values_mut() {
+      basic.remove(artificial);
+    }
+    self.objective.remove(artificial);
+    return Ok(success);
+  }
+
+  /// Replaces `symbol` by `row` in the tableau and the objective functions.
+  fn substitute(&mut self, symbol: Symbol, row: &Row) {
+    for (&basic, basic_row) in self.rows.iter_mut() {
+      basic_row.substitute(symbol, row);
+      if basic.kind != SymbolKind::External && basic_row.constant < 0.0 {
+        self.infeasible_rows.push(basic);
+      }
+    }
+
+    self.objective.substitute(symbol, row);
+    if let Some(ref mut artificial) = self.artificial {
+      artificial.substitute(symbol, row);
+    }
+  }
+
+  /// Optimizes the objective function, or the artificial objective function
+  /// if `artificial` is true, using the primal simplex method.
+  fn optimize(&mut self, artificial: bool) -> Result<(), SolverError> {
+    loop {
+      let entering = if artificial {
+        get_entering_symbol(self.artificial.as_ref().unwrap())
+      } else {
+        get_entering_symbol(&self.objective)
+      };
+      if entering.kind == SymbolKind::Invalid {
+        return Ok(());
+      }
+
+      let leaving = self.get_leaving_symbol(entering);
+      if leaving.kind == SymbolKind::Invalid {
+        return Err(SolverError::InternalError);
+      }
+
+      let mut row = self.rows.remove(&leaving).unwrap();
+      row.solve_for_symbols(leaving, entering);
+      self.substitute(entering, &row);
+      self.rows.insert(entering, row);
+    }
+  }
+
+  /// Restores the feasibility of the tableau after edit variables have been
+  /// changed, using the dual simplex method.
+  fn dual_optimize(&mut self) -> Result<(), SolverError> {
+    while let Some(leaving) = self.infeasible_rows.pop() {
+      let entering = match self.rows.get(&leaving) {
+        Some(row) if row.constant < 0.0 => get_dual_entering_symbol(row, &self.objective),
+        _ => continue
+      };
+      if entering.kind == SymbolKind::Invalid {
+        return Err(SolverError::InternalError);
+      }
+
+      let mut row = self.rows.remove(&leaving).unwrap();
+      row.solve_for_symbols(leaving, entering);
+      self.substitute(entering, &row);
+      self.rows.insert(entering, row);
+    }
+
+    return Ok(());
+  }
+
+  /// Finds the row which holds the exit symbol for a pivot.
+  fn get_leaving_symbol(&self, entering: Symbol) -> Symbol {
+    let mut ratio = std::f64::MAX;
+    let mut found = Symbol::invalid();
+    for (&symbol, row) in self.rows.iter() {
+      if symbol.kind == SymbolKind::External {
+        continue;
+      }
+
+      let coefficient = row.coefficient_for(entering);
+      if coefficient < 0.0 {
+        let candidate = -row.constant / coefficient;
+        if candidate < ratio {
+          ratio = candidate;
+          found = symbol;
+        }
+      }
+    }
+
+    return found;
+  }
+
+  /// Finds the row to pivot when removing the constraint identified by
+  /// `marker`, which is not basic.
+  fn get_marker_leaving_symbol(&self, marker: Symbol) -> Symbol {
+    let mut first_ratio = std::f64::MAX;
+    let mut second_ratio = std::f64::MAX;
+    let mut first = Symbol::invalid();
+    let mut second = Symbol::invalid();
+    let mut third = Symbol::invalid();
+    for (&symbol, row) in self.rows.iter() {
+      let coefficient = row.coefficient_for(marker);
+      if coefficient == 0.0 {
+        continue;
+      }
+
+      if symbol.kind == SymbolKind::External {
+        third = symbol;
+      } else if coefficient < 0.0 {
+        let candidate = -row.constant / coefficient;
+        if candidate < first_ratio {
+          first_ratio = candidate;
+          first = symbol;
+        }
+      } else {
+        let candidate = row.constant / coefficient;
+        if candidate < second_ratio {
+          second_ratio = candidate;
+          second = symbol;
+        }
+      }
+    }
+
+    if first.kind != SymbolKind::Invalid {
+      return first;
+    }
+    if second.kind != SymbolKind::Invalid {
+      return second;
+    }
+    return third;
+  }
+
+  /// Removes the error symbols of a constraint from the objective function.
+  fn remove_constraint_effects(&mut self, tag: &Tag, strength: f64) {
+    if tag.marker.kind == SymbolKind::Error {
+      self.remove_marker_effects(tag.marker, strength);
+    }
+    if tag.other.kind == SymbolKind::Error {
+      self.remove_marker_effects(tag.other, strength);
+    }
+  }
+
+  fn remove_marker_effects(&mut self, marker: Symbol, strength: f64) {
+    match self.rows.get(&marker) {
+      Some(row) => self.objective.insert_row(row, -strength),
+      None => self.objective.insert_symbol(marker, -strength)
+    }
+  }
+}
+
+/// Chooses the symbol to solve a new row for, or an invalid symbol if the row
+/// can only be added using an artificial variable.
+fn choose_subject(row: &Row, tag: &Tag) -> Symbol {
+  for symbol in row.cells.keys() {
+    if symbol.kind == SymbolKind::External {
+      return *symbol;
+    }
+  }
+
+  if tag.marker.is_restricted() && row.coefficient_for(tag.marker) < 0.0 {
+    return tag.marker;
+  }
+  if tag.other.is_restricted() && row.coefficient_for(tag.other) < 0.0 {
+    return tag.other;
+  }
+
+  return Symbol::invalid();
+}
+
+fn all_dummies(row: &Row) -> bool {
+  return row.cells.keys().all(|symbol| symbol.kind == SymbolKind::Dummy);
+}
+
+fn any_pivotable_symbol(row: &Row) -> Symbol {
+  for symbol in row.cells.keys() {
+    if symbol.is_restricted() {
+      return *symbol;
+    }
+  }
+
+  return Symbol::invalid();
+}
+
+/// Finds a symbol with a negative coefficient in the objective function.
+fn get_entering_symbol(objective: &Row) -> Symbol {
+  for (&symbol, &coefficient) in objective.cells.iter() {
+    if symbol.kind != SymbolKind::Dummy && coefficient < 0.0 {
+      return symbol;
+    }
+  }
+
+  return Symbol::invalid();
+}
+
+/// Finds the symbol entering the basis to make the infeasible `row`
+/// feasible again, keeping the objective function optimal.
+fn get_dual_entering_symbol(row: &Row, objective: &Row) -> Symbol {
+  let mut ratio = std::f64::MAX;
+  let mut entering = Symbol::invalid();
+  for (&symbol, &coefficient) in row.cells.iter() {
+    if coefficient > 0.0 && symbol.kind != SymbolKind::Dummy {
+      let candidate = objective.coefficient_for(symbol) / coefficient;
+      if candidate < ratio {
+        ratio = candidate;
+        entering = symbol;
+      }
+    }
+  }
+
+  return entering;
+}
+
+fn clip_strength(strength: f64) -> f64 {
+  return strength.max(0.0).min(REQUIRED);
+}
+
+fn near_zero(value: f64) -> bool {
+  return value.abs() < 1.0e-8;
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{Solver, Expression, SolverError, REQUIRED, STRONG, MEDIUM, WEAK};
+
+  fn constant(value: f64) -> Expression {
+    return Expression::from_constant(value);
+  }
+
+  fn assert_near(actual: f64, expected: f64) {
+    assert!((actual - expected).abs() < 1.0e-6, "expected {}, got {}", expected, actual);
+  }
+
+  #[test]
+  fn add_and_remove_constraint() {
+    let mut solver = Solver::new();
+    let x = solver.new_variable();
+    let weak = Expression::from_variable(x).equal_to(constant(10.0)).with_strength(WEAK);
+    solver.add_constraint(weak).unwrap();
+    assert_near(solver.value(x), 10.0);
+
+    let handle = solver.add_constraint(Expression::from_variable(x).equal_to(constant(20.0))).unwrap();
+    assert!(solver.has_constraint(handle));
+    assert_near(solver.value(x), 20.0);
+
+    solver.remove_constraint(handle).unwrap();
+    assert!(!solver.has_constraint(handle));
+    assert_near(solver.value(x), 10.0);
+    assert_eq!(solver.remove_constraint(handle), Err(SolverError::UnknownConstraint));
+  }
+
+  #[test]
+  fn required_constraints_win_over_weak_ones() {
+    let mut solver = Solver::new();
+    let x = solver.new_variable();
+    solver.add_constraint(Expression::from_variable(x).less_than_or_equal_to(constant(100.0))).unwrap();
+    solver.add_constraint(Expression::from_variable(x).equal_to(constant(150.0)).with_strength(WEAK)).unwrap();
+    assert_near(solver.value(x), 100.0);
+  }
+
+  #[test]
+  fn stronger_preferences_win() {
+    let mut solver = Solver::new();
+    let x = solver.new_variable();
+    solver.add_constraint(Expression::from_variable(x).equal_to(constant(10.0)).with_strength(WEAK)).unwrap();
+    solver.add_constraint(Expression::from_variable(x).equal_to(constant(30.0)).with_strength(STRONG)).unwrap();
+    solver.add_constraint(Expression::from_variable(x).equal_to(constant(20.0)).with_strength(MEDIUM)).unwrap();
+    assert_near(solver.value(x), 30.0);
+  }
+
+  #[test]
+  fn linear_relations_between_variables() {
+    let mut solver = Solver::new();
+    let left = solver.new_variable();
+    let width = solver.new_variable();
+    let right = Expression::from_variable(left) + Expression::from_variable(width);
+    solver.add_constraint(Expression::from_variable(left).equal_to(constant(10.0))).unwrap();
+    solver.add_constraint(right.equal_to(constant(110.0))).unwrap();
+    solver.add_constraint((2.0 * Expression::from_variable(width)).greater_than_or_equal_to(constant(0.0))).unwrap();
+    assert_near(solver.value(width), 100.0);
+  }
+
+  #[test]
+  fn unsatisfiable_constraint() {
+    let mut solver = Solver::new();
+    let x = solver.new_variable();
+    solver.add_constraint(Expression::from_variable(x).greater_than_or_equal_to(constant(10.0))).unwrap();
+    let conflict = Expression::from_variable(x).less_than_or_equal_to(constant(5.0));
+    assert_eq!(solver.add_constraint(conflict), Err(SolverError::UnsatisfiableConstraint));
+    assert_near(solver.value(x), 10.0);
+
+    let contradiction = constant(1.0).equal_to(constant(2.0));
+    assert_eq!(solver.add_constraint(contradiction), Err(SolverError::UnsatisfiableConstraint));
+  }
+
+  #[test]
+  fn duplicate_constraints() {
+    let mut solver = Solver::new();
+    let x = solver.new_variable();
+    let constraint = Expression::from_variable(x).equal_to(constant(42.0));
+    let first = solver.add_constraint(constraint.clone()).unwrap();
+    let second = solver.add_constraint(constraint).unwrap();
+    assert!(first != second);
+    assert_near(solver.value(x), 42.0);
+
+    solver.remove_constraint(first).unwrap();
+    assert_near(solver.value(x), 42.0);
+    solver.remove_constraint(second).unwrap();
+    assert_near(solver.value(x), 0.0);
+  }
+
+  #[test]
+  fn edit_variables() {
+    let mut solver = Solver::new();
+    let width = solver.new_variable();
+    let half = solver.new_variable();
+    solver.add_constraint((Expression::from_variable(width) * 0.5).equal_to(Expression::from_variable(half))).unwrap();
+
+    assert_eq!(solver.suggest_value(width, 10.0), Err(SolverError::UnknownEditVariable));
+    assert_eq!(solver.add_edit_variable(width, REQUIRED), Err(SolverError::BadRequiredStrength));
+    solver.add_edit_variable(width, STRONG).unwrap();
+    assert!(solver.has_edit_variable(width));
+    assert_eq!(solver.add_edit_variable(width, STRONG), Err(SolverError::DuplicateEditVariable));
+
+    solver.suggest_value(width, 200.0).unwrap();
+    assert_near(solver.value(half), 100.0);
+    solver.suggest_value(width, 50.0).unwrap();
+    assert_near(solver.value(half), 25.0);
+
+    solver.remove_edit_variable(width).unwrap();
+    assert!(!solver.has_edit_variable(width));
+    assert_eq!(solver.remove_edit_variable(width), Err(SolverError::UnknownEditVariable));
+  }
+
+  #[test]
+  fn edit_variables_respect_required_constraints() {
+    let mut solver = Solver::new();
+    let x = solver.new_variable();
+    solver.add_constraint(Expression::from_variable(x).less_than_or_equal_to(constant(100.0))).unwrap();
+    solver.add_edit_variable(x, STRONG).unwrap();
+    solver.suggest_value(x, 50.0).unwrap();
+    assert_near(solver.value(x), 50.0);
+    solver.suggest_value(x, 500.0).unwrap();
+    assert_near(solver.value(x), 100.0);
+  }
+}
@@ -0,0 +1,190 @@
+#![stable]
+
+//! Helpers shared by the bindings to read GLib data structures and to
+//! register GObject types implemented in Rust.
+
+use libc;
+use std;
+use super::value::{Type, TypeNameTaken, Value};
+
+/// Copies a NUL-terminated C string owned by GLib or Clutter into a String.
+///
+/// A null pointer gives an empty string.
+pub unsafe fn c_str_to_string(ptr: *const libc::c_char) -> String {
+  let mut bytes = Vec::new();
+  let mut cursor = ptr as *const u8;
+  while !cursor.is_null() && *cursor != 0 {
+    bytes.push(*cursor);
+    cursor = cursor.offset(1);
+  }
+
+  return String::from_utf8_lossy(bytes.as_slice()).into_owned();
+}
+
+/// Returns the type of a GTypeInstance, read from its class.
+pub unsafe fn type_of(instance: *mut libc::c_void) -> Type {
+  let class = *(instance as *const *const Type);
+  return *class;
+}
+
+/// Returns the name of the type of a GTypeInstance, such as `ClutterText`.
+pub unsafe fn type_name_of(instance: *mut libc::c_void) -> String {
+  return c_str_to_string(g_type_name(type_of(instance)));
+}
+
+/// A node of a doubly-linked GList.
+#[repr(C)]
+pub struct List {
+  pub data: *mut libc::c_void,
+  pub next: *mut List,
+  pub prev: *mut List
+}
+
+/// Collects the data pointers of `list`, then frees the list, but not the
+/// data.
+pub unsafe fn take_list(list: *mut List) -> Vec<*mut libc::c_void> {
+  let mut items = Vec::new();
+  let mut node = list;
+  while !node.is_null() {
+    items.push((*node).data);
+    node = (*node).next;
+  }
+
+  g_list_free(list);
+  return items;
+}
+
+/// Mirror of GTypeInfo.
+#[repr(C)]
+pub struct TypeInfo {
+  pub class_size: u16,
+  pub base_init: *mut libc::c_void,
+  pub base_finalize: *mut libc::c_void,
+  pub class_init: extern "C" fn(*mut libc::c_void, *mut libc::c_void),
+  pub class_finalize: *mut libc::c_void,
+  pub class_data: *mut libc::c_void,
+  pub instance_size: u16,
+  pub n_preallocs: u16,
+  pub instance_init: *mut libc::c_void,
+  pub value_table: *mut libc::c_void
+}
+
+/// Mirror of GTypeQuery.
+#[repr(C)]
+pub struct TypeQuery {
+  pub g_type: Type,
+  pub type_name: *const libc::c_char,
+  pub class_size: u32,
+  pub instance_size: u32
+}
+
+/// Mirror of GObjectClass.
+#[repr(C)]
+pub struct ObjectClass {
+  pub g_type: Type,
+  pub construct_properties: *mut libc::c_void,
+  pub constructor: *mut libc::c_void,
+  pub set_property: extern "C" fn(*mut libc::c_void, u32, *const Value, *mut libc::c_void),
+  pub get_property: extern "C" fn(*mut libc::c_void, u32, *mut Value, *mut libc::c_void),
+  pub dispose: *mut libc::c_void,
+  pub finalize: *mut libc::c_void,
+  pub dispatch_properties_changed: *mut libc::c_void,
+  pub notify: *mut libc::c_void,
+  pub constructed: *mut libc::c_void,
+  pub flags: libc::size_t,
+  pub pdummy: [*mut libc::c_void; 6]
+}
+
+/// Looks up the type registered under `name`.
+pub fn find_type(name: &str) -> Option<Type> {
+  unsafe {
+    use std::ffi::CString;
+    let existing = g_type_from_name(name.to_c_str().unwrap() as *const i8);
+    if existing == 0 {
+      return None;
+    }
+
+    return Some(existing);
+  }
+}
+
+/// Looks up the type registered under `name` by `register_subclass()` with
+/// `class_init`.
+///
+/// Returns __None__ if no type is registered under `name`, and an error if
+/// the name is taken by a type registered elsewhere, whose instances lack
+/// the data the Rust implementation relies on.
+pub fn find_own_type(name: &str, class_init: extern "C" fn(*mut libc::c_void, *mut libc::c_void)) -> Result<Option<Type>, TypeNameTaken> {
+  let existing = match find_type(name) {
+    Some(existing) => existing,
+    None => return Ok(None)
+  };
+
+  unsafe {
+    let owner = g_type_get_qdata(existing, class_init_quark());
+    if owner != class_init as *mut libc::c_void {
+      return Err(TypeNameTaken(name.to_string()));
+    }
+  }
+
+  return Ok(Some(existing));
+}
+
+/// Registers `name` as a static subclass of `parent`, with the class and
+/// instance sizes of the parent.
+///
+/// `class_init` receives `class_data`, which is never freed, as static types
+/// are never unloaded. The type is tagged with `class_init`, so that
+/// `find_own_type()` can tell it apart from a type registered elsewhere.
+pub unsafe fn register_subclass(parent: Type, name: &str, class_init: extern "C" fn(*mut libc::c_void, *mut libc::c_void), class_data: *mut libc::c_void) -> Type {
+  use std::ffi::CString;
+  let null_void: *mut libc::c_void = std::ptr::null_mut();
+  let mut query: TypeQuery = std::intrinsics::init();
+  g_type_query(parent, &mut query);
+  let info = TypeInfo {
+    class_size: query.class_size as u16,
+    base_init: null_void,
+    base_finalize: null_void,
+    class_init: class_init,
+    class_finalize: null_void,
+    class_data: class_data,
+    instance_size: query.instance_size as u16,
+    n_preallocs: 0,
+    instance_init: null_void,
+    value_table: null_void
+  };
+  let foreign_result = g_type_register_static(parent, name.to_c_str().unwrap() as *const i8, &info, 0);
+  g_type_set_qdata(foreign_result, class_init_quark(), class_init as *mut libc::c_void);
+  return foreign_result;
+}
+
+fn class_init_quark() -> u32 {
+  unsafe {
+    return g_quark_from_static_string("rust-class-init\0".as_ptr() as *const i8);
+  }
+}
+
+/// Logs `message` as a GLib warning.
+///
+/// Used by the callbacks called from C, which have no way to return an
+/// error to their caller.
+pub fn warn(message: &str) {
+  unsafe {
+    use std::ffi::CString;
+    g_log("Clutter-rs\0".as_ptr() as *const i8, LOG_LEVEL_WARNING, "%s\0".as_ptr() as *const i8, message.to_c_str().unwrap() as *const i8);
+  }
+}
+
+const LOG_LEVEL_WARNING: u32 = 1 << 4;
+
+extern {
+  fn g_type_name(g_type: Type) -> *const libc::c_char;
+  fn g_type_from_name(name: *const libc::c_char) -> Type;
+  fn g_type_query(g_type: Type, query: *mut TypeQuery);
+  fn g_type_register_static(parent_type: Type, type_name: *const libc::c_char, info: *const TypeInfo, flags: u32) -> Type;
+  fn g_type_get_qdata(g_type: Type, quark: u32) -> *mut libc::c_void;
+  fn g_type_set_qdata(g_type: Type, quark: u32, data: *mut libc::c_void);
+  fn g_quark_from_static_string(string: *const libc::c_char) -> u32;
+  fn g_list_free(list: *mut List);
+  fn g_log(log_domain: *const libc::c_char, log_level: u32, format: *const libc::c_char, ...);
+}
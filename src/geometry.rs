@@ -0,0 +1,440 @@
+#![stable]
+
+use std::ops::{Add, Sub, Mul};
+
+/// A point in 2D space.
+///
+/// _Since 1.12_
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Point {
+  /// X coordinate, in pixels
+  pub x: f32,
+
+  /// Y coordinate, in pixels
+  pub y: f32
+}
+
+impl Point {
+  /// Creates a new Point with the given coordinates.
+  ///
+  /// _Since 1.12_
+  pub fn new(x: f32, y: f32) -> Point {
+    return Point { x: x, y: y };
+  }
+
+  /// A point centered at (0, 0).
+  ///
+  /// _Since 1.12_
+  pub fn zero() -> Point {
+    return Point { x: 0.0, y: 0.0 };
+  }
+
+  /// Computes the distance between two points, returning it along with the
+  /// horizontal and vertical distances.
+  ///
+  /// _Since 1.12_
+  pub fn distance(&self, other: &Point) -> (f32, f32, f32) {
+    let x_d = other.x - self.x;
+    let y_d = other.y - self.y;
+    return ((x_d * x_d + y_d * y_d).sqrt(), x_d.abs(), y_d.abs());
+  }
+}
+
+impl Add for Point {
+  type Output = Point;
+
+  fn add(self, other: Point) -> Point {
+    return Point::new(self.x + other.x, self.y + other.y);
+  }
+}
+
+impl Sub for Point {
+  type Output = Point;
+
+  fn sub(self, other: Point) -> Point {
+    return Point::new(self.x - other.x, self.y - other.y);
+  }
+}
+
+impl Mul<f32> for Point {
+  type Output = Point;
+
+  fn mul(self, factor: f32) -> Point {
+    return Point::new(self.x * factor, self.y * factor);
+  }
+}
+
+/// A size, in 2D space.
+///
+/// _Since 1.12_
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Size {
+  /// The width, in pixels
+  pub width: f32,
+
+  /// The height, in pixels
+  pub height: f32
+}
+
+impl Size {
+  /// Creates a new Size with the given dimensions.
+  ///
+  /// _Since 1.12_
+  pub fn new(width: f32, height: f32) -> Size {
+    return Size { width: width, height: height };
+  }
+}
+
+impl Add for Size {
+  type Output = Size;
+
+  fn add(self, other: Size) -> Size {
+    return Size::new(self.width + other.width, self.height + other.height);
+  }
+}
+
+impl Sub for Size {
+  type Output = Size;
+
+  fn sub(self, other: Size) -> Size {
+    return Size::new(self.width - other.width, self.height - other.height);
+  }
+}
+
+impl Mul<f32> for Size {
+  type Output = Size;
+
+  fn mul(self, factor: f32) -> Size {
+    return Size::new(self.width * factor, self.height * factor);
+  }
+}
+
+/// The location and size of a rectangle.
+///
+/// The width and height of a Rect can be negative; Clutter considers a
+/// rectangle with an origin of (0.0, 0.0) and a size of (10.0, 10.0) to be
+/// equivalent to a rectangle with origin of (10.0, 10.0) and size of
+/// (-10.0, -10.0).
+///
+/// _Since 1.12_
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Rect {
+  /// The origin of the rectangle
+  pub origin: Point,
+
+  /// The size of the rectangle
+  pub size: Size
+}
+
+impl Rect {
+  /// Creates a new Rect with the given origin and size.
+  ///
+  /// _Since 1.12_
+  pub fn new(x: f32, y: f32, width: f32, height: f32) -> Rect {
+    return Rect { origin: Point::new(x, y), size: Size::new(width, height) };
+  }
+
+  /// A rectangle with origin in (0, 0) and a size of 0.
+  ///
+  /// _Since 1.12_
+  pub fn zero() -> Rect {
+    return Rect::new(0.0, 0.0, 0.0, 0.0);
+  }
+
+  /// Returns a copy of the rectangle with a positive width and height,
+  /// moving the origin to the top left corner if needed.
+  pub fn normalize(&self) -> Rect {
+    let mut result = *self;
+    if result.size.width < 0.0 {
+      result.origin.x += result.size.width;
+      result.size.width = -result.size.width;
+    }
+
+    if result.size.height < 0.0 {
+      result.origin.y += result.size.height;
+      result.size.height = -result.size.height;
+    }
+
+    return result;
+  }
+
+  /// Retrieves the X coordinate of the origin of the normalized rectangle.
+  pub fn get_x(&self) -> f32 {
+    return self.normalize().origin.x;
+  }
+
+  /// Retrieves the Y coordinate of the origin of the normalized rectangle.
+  pub fn get_y(&self) -> f32 {
+    return self.normalize().origin.y;
+  }
+
+  /// Retrieves the width of the normalized rectangle.
+  pub fn get_width(&self) -> f32 {
+    return self.normalize().size.width;
+  }
+
+  /// Retrieves the height of the normalized rectangle.
+  pub fn get_height(&self) -> f32 {
+    return self.normalize().size.height;
+  }
+
+  /// Retrieves the center of the rectangle.
+  pub fn get_center(&self) -> Point {
+    let r = self.normalize();
+    return Point::new(r.origin.x + r.size.width / 2.0, r.origin.y + r.size.height / 2.0);
+  }
+
+  /// Checks whether `point` is contained by the rectangle, after normalizing
+  /// it.
+  pub fn contains_point(&self, point: &Point) -> bool {
+    let r = self.normalize();
+    return point.x >= r.origin.x && point.y >= r.origin.y &&
+           point.x <= r.origin.x + r.size.width &&
+           point.y <= r.origin.y + r.size.height;
+  }
+
+  /// Checks whether `other` is contained by the rectangle, after normalizing
+  /// both.
+  pub fn contains_rect(&self, other: &Rect) -> bool {
+    return self.union(other) == self.normalize();
+  }
+
+  /// Computes the smallest possible rectangle capable of fully containing
+  /// both rectangles.
+  pub fn union(&self, other: &Rect) -> Rect {
+    let a = self.normalize();
+    let b = other.normalize();
+    let x_1 = a.origin.x.min(b.origin.x);
+    let y_1 = a.origin.y.min(b.origin.y);
+    let x_2 = (a.origin.x + a.size.width).max(b.origin.x + b.size.width);
+    let y_2 = (a.origin.y + a.size.height).max(b.origin.y + b.size.height);
+    return Rect::new(x_1, y_1, x_2 - x_1, y_2 - y_1);
+  }
+
+  /// Computes the intersection of the two rectangles.
+  ///
+  /// Returns __None__ if the rectangles do not intersect.
+  pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+    let a = self.normalize();
+    let b = other.normalize();
+    let x_1 = a.origin.x.max(b.origin.x);
+    let y_1 = a.origin.y.max(b.origin.y);
+    let x_2 = (a.origin.x + a.size.width).min(b.origin.x + b.size.width);
+    let y_2 = (a.origin.y + a.size.height).min(b.origin.y + b.size.height);
+    if x_1 >= x_2 || y_1 >= y_2 {
+      return None;
+    }
+
+    return Some(Rect::new(x_1, y_1, x_2 - x_1, y_2 - y_1));
+  }
+
+  /// Offsets the origin of the rectangle by the given values, after
+  /// normalizing it.
+  pub fn offset(&self, d_x: f32, d_y: f32) -> Rect {
+    let mut r = self.normalize();
+    r.origin.x += d_x;
+    r.origin.y += d_y;
+    return r;
+  }
+
+  /// Normalizes the rectangle and moves its edges inwards by `d_x` on the
+  /// left and right and by `d_y` on the top and bottom, keeping its center
+  /// in place.
+  ///
+  /// Positive values shrink the rectangle and negative values grow it; an
+  /// inset larger than half the size collapses the rectangle to its center.
+  pub fn inset(&self, d_x: f32, d_y: f32) -> Rect {
+    let mut r = self.normalize();
+    let d_x = d_x.min(r.size.width / 2.0);
+    let d_y = d_y.min(r.size.height / 2.0);
+    r.origin.x += d_x;
+    r.origin.y += d_y;
+    r.size.width -= d_x * 2.0;
+    r.size.height -= d_y * 2.0;
+    return r;
+  }
+
+  /// Rounds the origin of the rectangle down and its size up to the nearest
+  /// integer, so that the result fully contains the original rectangle.
+  pub fn clamp_to_pixel(&self) -> Rect {
+    let r = self.normalize();
+    let x_1 = r.origin.x.floor();
+    let y_1 = r.origin.y.floor();
+    let x_2 = (r.origin.x + r.size.width).ceil();
+    let y_2 = (r.origin.y + r.size.height).ceil();
+    return Rect::new(x_1, y_1, x_2 - x_1, y_2 - y_1);
+  }
+}
+
+/// A representation of the components of a margin.
+///
+/// _Since 1.10_
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Margin {
+  /// The margin from the left
+  pub left: f32,
+
+  /// The margin from the right
+  pub right: f32,
+
+  /// The margin from the top
+  pub top: f32,
+
+  /// The margin from the bottom
+  pub bottom: f32
+}
+
+impl Margin {
+  /// Creates a new Margin with the given components.
+  ///
+  /// _Since 1.10_
+  pub fn new(left: f32, right: f32, top: f32, bottom: f32) -> Margin {
+    return Margin { left: left, right: right, top: top, bottom: bottom };
+  }
+
+  /// Creates a new Margin with the same `value` on all sides.
+  pub fn uniform(value: f32) -> Margin {
+    return Margin::new(value, value, value, value);
+  }
+}
+
+impl Add for Margin {
+  type Output = Margin;
+
+  fn add(self, other: Margin) -> Margin {
+    return Margin::new(self.left + other.left, self.right + other.right, self.top + other.top, self.bottom + other.bottom);
+  }
+}
+
+impl Sub for Margin {
+  type Output = Margin;
+
+  fn sub(self, other: Margin) -> Margin {
+    return Margin::new(self.left - other.left, self.right - other.right, self.top - other.top, self.bottom - other.bottom);
+  }
+}
+
+impl Mul<f32> for Margin {
+  type Output = Margin;
+
+  fn mul(self, factor: f32) -> Margin {
+    return Margin::new(self.left * factor, self.right * factor, self.top * factor, self.bottom * factor);
+  }
+}
+
+/// A point in 3D space, expressed in pixels.
+///
+/// _Since 0.4_
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Vertex {
+  /// X coordinate of the vertex
+  pub x: f32,
+
+  /// Y coordinate of the vertex
+  pub y: f32,
+
+  /// Z coordinate of the vertex
+  pub z: f32
+}
+
+impl Vertex {
+  /// Creates a new Vertex with the given coordinates.
+  ///
+  /// _Since 1.10_
+  pub fn new(x: f32, y: f32, z: f32) -> Vertex {
+    return Vertex { x: x, y: y, z: z };
+  }
+
+  /// A vertex at (0, 0, 0).
+  pub fn zero() -> Vertex {
+    return Vertex { x: 0.0, y: 0.0, z: 0.0 };
+  }
+}
+
+impl Add for Vertex {
+  type Output = Vertex;
+
+  fn add(self, other: Vertex) -> Vertex {
+    return Vertex::new(self.x + other.x, self.y + other.y, self.z + other.z);
+  }
+}
+
+impl Sub for Vertex {
+  type Output = Vertex;
+
+  fn sub(self, other: Vertex) -> Vertex {
+    return Vertex::new(self.x - other.x, self.y - other.y, self.z - other.z);
+  }
+}
+
+impl Mul<f32> for Vertex {
+  type Output = Vertex;
+
+  fn mul(self, factor: f32) -> Vertex {
+    return Vertex::new(self.x * factor, self.y * factor, self.z * factor);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{Point, Rect};
+
+  #[test]
+  fn normalize_flips_negative_sizes() {
+    let r = Rect::new(10.0, 20.0, -4.0, -6.0).normalize();
+    assert_eq!(r, Rect::new(6.0, 14.0, 4.0, 6.0));
+    assert_eq!(r.normalize(), r);
+  }
+
+  #[test]
+  fn union_contains_both() {
+    let a = Rect::new(0.0, 0.0, 10.0, 10.0);
+    let b = Rect::new(5.0, -5.0, 10.0, 5.0);
+    let u = a.union(&b);
+    assert_eq!(u, Rect::new(0.0, -5.0, 15.0, 15.0));
+    assert!(u.contains_rect(&a));
+    assert!(u.contains_rect(&b));
+  }
+
+  #[test]
+  fn intersection_of_overlapping_and_disjoint() {
+    let a = Rect::new(0.0, 0.0, 10.0, 10.0);
+    let b = Rect::new(5.0, 5.0, 10.0, 10.0);
+    assert_eq!(a.intersection(&b), Some(Rect::new(5.0, 5.0, 5.0, 5.0)));
+
+    let c = Rect::new(10.0, 0.0, 5.0, 5.0);
+    assert_eq!(a.intersection(&c), None);
+  }
+
+  #[test]
+  fn contains_rect_normalizes() {
+    let a = Rect::new(10.0, 10.0, -10.0, -10.0);
+    assert!(a.contains_rect(&Rect::new(2.0, 2.0, 5.0, 5.0)));
+    assert!(!a.contains_rect(&Rect::new(2.0, 2.0, 10.0, 5.0)));
+    assert!(a.contains_point(&Point::new(10.0, 0.0)));
+  }
+
+  #[test]
+  fn clamp_to_pixel_contains_original() {
+    let r = Rect::new(0.5, 1.25, 2.0, 2.5);
+    let clamped = r.clamp_to_pixel();
+    assert_eq!(clamped, Rect::new(0.0, 1.0, 3.0, 3.0));
+    assert!(clamped.contains_rect(&r));
+  }
+
+  #[test]
+  fn inset_keeps_center() {
+    let r = Rect::new(0.0, 0.0, 10.0, 20.0);
+    assert_eq!(r.inset(2.0, 3.0), Rect::new(2.0, 3.0, 6.0, 14.0));
+    assert_eq!(r.inset(-1.0, -1.0), Rect::new(-1.0, -1.0, 12.0, 22.0));
+
+    let collapsed = r.inset(8.0, 30.0);
+    assert_eq!(collapsed, Rect::new(5.0, 10.0, 0.0, 0.0));
+    assert_eq!(collapsed.get_center(), r.get_center());
+  }
+}
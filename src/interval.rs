@@ -0,0 +1,294 @@
+#![stable]
+
+use libc;
+use std;
+use super::color::Color;
+use super::geometry::{Point, Size, Rect};
+use super::value::{Value, Type};
+
+/// Types which can be stored inside an Interval.
+///
+/// Implemented for `f32`, `f64`, `i32`, Color, Point, Size and Rect.
+pub trait IntervalValue {
+  /// Returns the type used to store values of this kind inside a Value.
+  fn value_type() -> Type;
+
+  /// Stores the value inside `value`, which must be of `value_type()`.
+  fn to_value(&self, value: &mut Value);
+
+  /// Reads a value back from `value`, which must be of `value_type()`.
+  fn from_value(value: &Value) -> Self;
+}
+
+impl IntervalValue for f32 {
+  fn value_type() -> Type {
+    return super::value::TYPE_FLOAT;
+  }
+
+  fn to_value(&self, value: &mut Value) {
+    value.set_float(*self);
+  }
+
+  fn from_value(value: &Value) -> f32 {
+    return value.get_float();
+  }
+}
+
+impl IntervalValue for f64 {
+  fn value_type() -> Type {
+    return super::value::TYPE_DOUBLE;
+  }
+
+  fn to_value(&self, value: &mut Value) {
+    value.set_double(*self);
+  }
+
+  fn from_value(value: &Value) -> f64 {
+    return value.get_double();
+  }
+}
+
+impl IntervalValue for i32 {
+  fn value_type() -> Type {
+    return super::value::TYPE_INT;
+  }
+
+  fn to_value(&self, value: &mut Value) {
+    value.set_int(*self);
+  }
+
+  fn from_value(value: &Value) -> i32 {
+    return value.get_int();
+  }
+}
+
+impl IntervalValue for Color {
+  fn value_type() -> Type {
+    unsafe {
+      return clutter_color_get_type();
+    }
+  }
+
+  fn to_value(&self, value: &mut Value) {
+    value.set_boxed(self.opaque as *const libc::c_void);
+  }
+
+  fn from_value(value: &Value) -> Color {
+    unsafe {
+      // The boxed color is owned by the Value, so hand out a copy of it.
+      let borrowed = Color { opaque: value.get_boxed() };
+      let result = borrowed.clone();
+      std::mem::forget(borrowed);
+      return result;
+    }
+  }
+}
+
+impl IntervalValue for Point {
+  fn value_type() -> Type {
+    unsafe {
+      return clutter_point_get_type();
+    }
+  }
+
+  fn to_value(&self, value: &mut Value) {
+    value.set_boxed(self as *const Point as *const libc::c_void);
+  }
+
+  fn from_value(value: &Value) -> Point {
+    unsafe {
+      return *(value.get_boxed() as *const Point);
+    }
+  }
+}
+
+impl IntervalValue for Size {
+  fn value_type() -> Type {
+    unsafe {
+      return clutter_size_get_type();
+    }
+  }
+
+  fn to_value(&self, value: &mut Value) {
+    value.set_boxed(self as *const Size as *const libc::c_void);
+  }
+
+  fn from_value(value: &Value) -> Size {
+    unsafe {
+      return *(value.get_boxed() as *const Size);
+    }
+  }
+}
+
+impl IntervalValue for Rect {
+  fn value_type() -> Type {
+    unsafe {
+      return clutter_rect_get_type();
+    }
+  }
+
+  fn to_value(&self, value: &mut Value) {
+    value.set_boxed(self as *const Rect as *const libc::c_void);
+  }
+
+  fn from_value(value: &Value) -> Rect {
+    unsafe {
+      return *(value.get_boxed() as *const Rect);
+    }
+  }
+}
+
+/// An object holding an interval of two values.
+///
+/// Interval is a simple object that can hold two values defining an interval.
+/// Interval can hold any value that can be enclosed inside a Value; the type
+/// parameter `T` keeps track of it, so that the initial, final and computed
+/// values are always handed out as `T`.
+///
+/// Once an Interval for a specific type has been instantiated, it is possible
+/// to compute the value at any progress between the initial and final values
+/// using `.compute()`.
+///
+/// _Since 1.0_
+pub struct Interval<T> {
+  opaque: *mut libc::c_void,
+  marker: std::marker::PhantomData<T>
+}
+
+impl<T: IntervalValue> Interval<T> {
+  /// Creates a new Interval holding values of type `T`, bounded by `initial`
+  /// and `final_value`.
+  ///
+  /// _Since 1.10_
+  pub fn new(initial: &T, final_value: &T) -> Interval<T> {
+    unsafe {
+      let mut initial_value = Value::new(<T as IntervalValue>::value_type());
+      let mut final_v = Value::new(<T as IntervalValue>::value_type());
+      initial.to_value(&mut initial_value);
+      final_value.to_value(&mut final_v);
+      let foreign_result = clutter_interval_new_with_values(<T as IntervalValue>::value_type(), &initial_value, &final_v);
+      // Intervals are created with a floating reference; sink it so that the
+      // reference released on drop is owned by the Interval
+      g_object_ref_sink(foreign_result);
+      return Interval { opaque: foreign_result, marker: std::marker::PhantomData };
+    }
+  }
+
+  /// Returns a pointer to the the underlying C object.
+  ///
+  /// Generally only used internally.
+  pub fn as_interval(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+
+  /// Sets the initial value of the interval.
+  ///
+  /// _Since 1.0_
+  pub fn set_initial(&mut self, initial: &T) {
+    unsafe {
+      let mut value = Value::new(<T as IntervalValue>::value_type());
+      initial.to_value(&mut value);
+      clutter_interval_set_initial_value(self.opaque, &value);
+    }
+  }
+
+  /// Retrieves the initial value of the interval.
+  ///
+  /// _Since 1.0_
+  pub fn get_initial(&self) -> T {
+    unsafe {
+      let foreign_result = clutter_interval_peek_initial_value(self.opaque);
+      return <T as IntervalValue>::from_value(&*foreign_result);
+    }
+  }
+
+  /// Sets the final value of the interval.
+  ///
+  /// _Since 1.0_
+  pub fn set_final(&mut self, final_value: &T) {
+    unsafe {
+      let mut value = Value::new(<T as IntervalValue>::value_type());
+      final_value.to_value(&mut value);
+      clutter_interval_set_final_value(self.opaque, &value);
+    }
+  }
+
+  /// Retrieves the final value of the interval.
+  ///
+  /// _Since 1.0_
+  pub fn get_final(&self) -> T {
+    unsafe {
+      let foreign_result = clutter_interval_peek_final_value(self.opaque);
+      return <T as IntervalValue>::from_value(&*foreign_result);
+    }
+  }
+
+  /// Computes the value between the interval boundaries given the `progress`.
+  ///
+  /// The `progress` is usually in the [0, 1] interval, but some easing modes
+  /// may overshoot it.
+  ///
+  /// Returns __None__ if the value could not be computed, for instance
+  /// because the interval is not valid.
+  ///
+  /// _Since 1.4_
+  pub fn compute(&self, progress: f64) -> Option<T> {
+    unsafe {
+      let foreign_result = clutter_interval_compute(self.opaque, progress);
+      if foreign_result.is_null() {
+        return None;
+      }
+
+      return Some(<T as IntervalValue>::from_value(&*foreign_result));
+    }
+  }
+
+  /// Checks if the interval has a valid initial and final values.
+  ///
+  /// _Since 1.12_
+  pub fn is_valid(&self) -> bool {
+    unsafe {
+      let foreign_result = clutter_interval_is_valid(self.opaque);
+      return foreign_result != 0;
+    }
+  }
+}
+
+impl<T> std::clone::Clone for Interval<T> {
+  /// Creates a copy of the interval.
+  ///
+  /// _Since 1.0_
+  fn clone(&self) -> Interval<T> {
+    unsafe {
+      let foreign_result = clutter_interval_clone(self.opaque);
+      g_object_ref_sink(foreign_result);
+      return Interval { opaque: foreign_result, marker: std::marker::PhantomData };
+    }
+  }
+}
+
+impl<T> std::ops::Drop for Interval<T> {
+  /// Releases the reference held on the interval.
+  fn drop(&mut self) {
+    unsafe {
+      g_object_unref(self.opaque);
+    }
+  }
+}
+
+extern {
+  fn clutter_color_get_type() -> Type;
+  fn clutter_point_get_type() -> Type;
+  fn clutter_size_get_type() -> Type;
+  fn clutter_rect_get_type() -> Type;
+  fn clutter_interval_new_with_values(gtype: Type, initial: *const Value, final_value: *const Value) -> *mut libc::c_void;
+  fn clutter_interval_clone(self_value: *mut libc::c_void) -> *mut libc::c_void;
+  fn clutter_interval_set_initial_value(self_value: *mut libc::c_void, value: *const Value);
+  fn clutter_interval_peek_initial_value(self_value: *mut libc::c_void) -> *mut Value;
+  fn clutter_interval_set_final_value(self_value: *mut libc::c_void, value: *const Value);
+  fn clutter_interval_peek_final_value(self_value: *mut libc::c_void) -> *mut Value;
+  fn clutter_interval_compute(self_value: *mut libc::c_void, factor: f64) -> *const Value;
+  fn clutter_interval_is_valid(self_value: *mut libc::c_void) -> i32;
+  fn g_object_ref_sink(object: *mut libc::c_void) -> *mut libc::c_void;
+  fn g_object_unref(object: *mut libc::c_void);
+}
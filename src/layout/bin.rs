@@ -0,0 +1,129 @@
+#![stable]
+
+use libc;
+use std;
+use super::super::actor::Actor;
+use super::LayoutManager;
+
+/// The alignment policies available on each axis for BinLayout.
+///
+/// This enumeration has been deprecated since version 1.12 and should not be
+/// used in newly-written code. Use `Actor#set_x_align()` and
+/// `Actor#set_y_align()` on the children instead.
+///
+/// _Since 1.2_
+#[repr(i32)]
+pub enum Alignment {
+  /// Fixed position alignment; the BinLayout will honour the fixed position
+  /// provided by the actors themselves when allocating them
+  Fixed = 0,
+
+  /// Fill the allocation size
+  Fill = 1,
+
+  /// Position the actors at the top or left side of the container,
+  /// depending on the axis
+  Start = 2,
+
+  /// Position the actors at the bottom or right side of the container,
+  /// depending on the axis
+  End = 3,
+
+  /// Position the actors at the center of the container, depending on the
+  /// axis
+  Center = 4
+}
+
+/// Opaque struct which holds a reference to the underlying Clutter object.
+#[repr(C)]
+pub struct BinLayoutRef {
+  opaque: *mut libc::c_void
+}
+
+impl BinLayoutRef {
+  /// Creates a new BinLayout layout manager.
+  ///
+  /// The alignment policies are only used for children which don't set their
+  /// own `x-align` and `y-align`; `Alignment::Center` is the usual choice.
+  ///
+  /// _Since 1.2_
+  pub fn new(x_align: Alignment, y_align: Alignment) -> BinLayoutRef {
+    unsafe {
+      let foreign_result = clutter_bin_layout_new(x_align, y_align);
+      return foreign_result;
+    }
+  }
+}
+
+/// A simple layout manager.
+///
+/// BinLayout is a layout manager which implements the following policy:
+///
+/// - the preferred size is the maximum preferred size between all the
+///   children of the container using the layout;
+/// - each child is allocated in "layers", one on top of the other;
+/// - for each layer there are horizontal and vertical alignment policies.
+///
+/// The alignment of each child is controlled by its own `x-align` and
+/// `y-align` properties, see `Actor#set_x_align()` and `Actor#set_y_align()`.
+/// Centring an actor inside a container is thus only a matter of setting
+/// `ActorAlign::Center` on both axes.
+///
+/// _Since 1.2_
+pub trait BinLayout {
+  /// Returns a pointer to the the underlying C object.
+  ///
+  /// Generally only used internally.
+  fn as_bin_layout(&self) -> *mut libc::c_void;
+
+  /// Sets the horizontal and vertical alignment policies to be applied to a
+  /// `child` of the layout.
+  ///
+  /// This method has been deprecated since version 1.12 and should not be
+  /// used in newly-written code. Use `Actor#set_x_align()` and
+  /// `Actor#set_y_align()` instead.
+  ///
+  /// _Since 1.2_
+  #[deprecated]
+  fn set_alignment<T: Actor>(&mut self, child: &mut T, x_align: Alignment, y_align: Alignment) {
+    unsafe {
+      clutter_bin_layout_set_alignment(self.as_bin_layout(), child.as_actor(), x_align, y_align);
+    }
+  }
+
+  /// Retrieves the horizontal and vertical alignment policies for a `child`
+  /// of the layout.
+  ///
+  /// This method has been deprecated since version 1.12 and should not be
+  /// used in newly-written code. Use `Actor#get_x_align()` and
+  /// `Actor#get_y_align()` instead.
+  ///
+  /// _Since 1.2_
+  #[deprecated]
+  fn get_alignment<T: Actor>(&mut self, child: &mut T) -> (Alignment, Alignment) {
+    unsafe {
+      let mut x_align:Alignment = std::intrinsics::init();
+      let mut y_align:Alignment = std::intrinsics::init();
+      clutter_bin_layout_get_alignment(self.as_bin_layout(), child.as_actor(), &mut x_align, &mut y_align);
+      return (x_align, y_align);
+    }
+  }
+}
+
+impl BinLayout for BinLayoutRef {
+  fn as_bin_layout(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+impl LayoutManager for BinLayoutRef {
+  fn as_layout_manager(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+extern {
+  fn clutter_bin_layout_new(x_align: Alignment, y_align: Alignment) -> BinLayoutRef;
+  fn clutter_bin_layout_set_alignment(self_value: *mut libc::c_void, child: *mut libc::c_void, x_align: Alignment, y_align: Alignment);
+  fn clutter_bin_layout_get_alignment(self_value: *mut libc::c_void, child: *mut libc::c_void, x_align: *mut Alignment, y_align: *mut Alignment);
+}
@@ -0,0 +1,221 @@
+#![stable]
+
+use libc;
+use super::LayoutManager;
+use super::Orientation;
+use super::super::animation::AnimationMode;
+
+/// Opaque struct which holds a reference to the underlying Clutter object.
+#[repr(C)]
+pub struct BoxLayoutRef {
+  opaque: *mut libc::c_void
+}
+
+impl BoxLayoutRef {
+  /// Creates a new BoxLayout layout manager.
+  ///
+  /// _Since 1.2_
+  pub fn new() -> BoxLayoutRef {
+    unsafe {
+      let foreign_result = clutter_box_layout_new();
+      return foreign_result;
+    }
+  }
+}
+
+/// A layout manager arranging children on a single line.
+///
+/// The BoxLayout is a LayoutManager implementing the following layout
+/// policy:
+///
+/// - all children are arranged on a single line
+/// - the axis used is controlled by the `orientation` property
+/// - each child will be allocated to its natural size or, if `x-expand` or
+///   `y-expand` are set, the available size
+/// - honours the Actor's `x-align` and `y-align` properties to fill the
+///   available size
+/// - if the `homogeneous` boolean property is set, then all widgets will get
+///   the same size, ignoring expand settings and the preferred sizes
+///
+/// It is possible to control the spacing between children of a BoxLayout by
+/// using `.set_spacing()`.
+///
+/// Changes in the layout can be animated by setting an easing state on the
+/// container actor, using `Actor#save_easing_state()` and
+/// `Actor#set_easing_duration()` before changing the layout properties.
+///
+/// _Since 1.2_
+pub trait BoxLayout {
+  /// Returns a pointer to the the underlying C object.
+  ///
+  /// Generally only used internally.
+  fn as_box_layout(&self) -> *mut libc::c_void;
+
+  /// Sets the orientation of the BoxLayout layout manager.
+  ///
+  /// _Since 1.12_
+  fn set_orientation(&mut self, orientation: Orientation) {
+    unsafe {
+      clutter_box_layout_set_orientation(self.as_box_layout(), orientation);
+    }
+  }
+
+  /// Retrieves the orientation of the layout.
+  ///
+  /// _Since 1.12_
+  fn get_orientation(&mut self) -> Orientation {
+    unsafe {
+      let foreign_result = clutter_box_layout_get_orientation(self.as_box_layout());
+      return foreign_result;
+    }
+  }
+
+  /// Sets the spacing between children of the layout.
+  ///
+  /// _Since 1.2_
+  fn set_spacing(&mut self, spacing: u32) {
+    unsafe {
+      clutter_box_layout_set_spacing(self.as_box_layout(), spacing);
+    }
+  }
+
+  /// Retrieves the spacing set using `.set_spacing()`.
+  ///
+  /// _Since 1.2_
+  fn get_spacing(&mut self) -> u32 {
+    unsafe {
+      let foreign_result = clutter_box_layout_get_spacing(self.as_box_layout());
+      return foreign_result;
+    }
+  }
+
+  /// Sets whether the size of the layout children should be homogeneous.
+  ///
+  /// _Since 1.4_
+  fn set_homogeneous(&mut self, homogeneous: bool) {
+    unsafe {
+      clutter_box_layout_set_homogeneous(self.as_box_layout(), (homogeneous as i32));
+    }
+  }
+
+  /// Retrieves if the children sizes are allocated homogeneously.
+  ///
+  /// _Since 1.4_
+  fn get_homogeneous(&mut self) -> bool {
+    unsafe {
+      let foreign_result = clutter_box_layout_get_homogeneous(self.as_box_layout());
+      return foreign_result != 0;
+    }
+  }
+
+  /// Sets whether children of the layout should be laid out by appending
+  /// them or by prepending them.
+  ///
+  /// _Since 1.2_
+  fn set_pack_start(&mut self, pack_start: bool) {
+    unsafe {
+      clutter_box_layout_set_pack_start(self.as_box_layout(), (pack_start as i32));
+    }
+  }
+
+  /// Retrieves the value set using `.set_pack_start()`.
+  ///
+  /// _Since 1.2_
+  fn get_pack_start(&mut self) -> bool {
+    unsafe {
+      let foreign_result = clutter_box_layout_get_pack_start(self.as_box_layout());
+      return foreign_result != 0;
+    }
+  }
+
+  /// Sets whether the layout should animate changes in the layout
+  /// properties.
+  ///
+  /// The duration of the animations is controlled by
+  /// `.set_easing_duration()`; the easing mode to be used by the animations is
+  /// controlled by `.set_easing_mode()`.
+  ///
+  /// This method has been deprecated since version 1.12 and should not be
+  /// used in newly-written code. Use the easing state of the container actor
+  /// instead.
+  ///
+  /// _Since 1.2_
+  #[deprecated]
+  fn set_use_animations(&mut self, animate: bool) {
+    unsafe {
+      clutter_box_layout_set_use_animations(self.as_box_layout(), (animate as i32));
+    }
+  }
+
+  /// Retrieves whether the layout is animating layout changes.
+  ///
+  /// This method has been deprecated since version 1.12 and should not be
+  /// used in newly-written code.
+  ///
+  /// _Since 1.2_
+  #[deprecated]
+  fn get_use_animations(&mut self) -> bool {
+    unsafe {
+      let foreign_result = clutter_box_layout_get_use_animations(self.as_box_layout());
+      return foreign_result != 0;
+    }
+  }
+
+  /// Sets the easing mode to be used by the layout when animating changes in
+  /// layout properties.
+  ///
+  /// This method has been deprecated since version 1.12 and should not be
+  /// used in newly-written code. Use `Actor#set_easing_mode()` on the
+  /// container instead.
+  ///
+  /// _Since 1.2_
+  #[deprecated]
+  fn set_easing_mode(&mut self, mode: AnimationMode) {
+    unsafe {
+      clutter_box_layout_set_easing_mode(self.as_box_layout(), mode as libc::c_ulong);
+    }
+  }
+
+  /// Sets the duration of the animations used by the layout when animating
+  /// changes in the layout properties.
+  ///
+  /// This method has been deprecated since version 1.12 and should not be
+  /// used in newly-written code. Use `Actor#set_easing_duration()` on the
+  /// container instead.
+  ///
+  /// _Since 1.2_
+  #[deprecated]
+  fn set_easing_duration(&mut self, msecs: u32) {
+    unsafe {
+      clutter_box_layout_set_easing_duration(self.as_box_layout(), msecs);
+    }
+  }
+}
+
+impl BoxLayout for BoxLayoutRef {
+  fn as_box_layout(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+impl LayoutManager for BoxLayoutRef {
+  fn as_layout_manager(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+extern {
+  fn clutter_box_layout_new() -> BoxLayoutRef;
+  fn clutter_box_layout_set_orientation(self_value: *mut libc::c_void, orientation: Orientation);
+  fn clutter_box_layout_get_orientation(self_value: *mut libc::c_void) -> Orientation;
+  fn clutter_box_layout_set_spacing(self_value: *mut libc::c_void, spacing: u32);
+  fn clutter_box_layout_get_spacing(self_value: *mut libc::c_void) -> u32;
+  fn clutter_box_layout_set_homogeneous(self_value: *mut libc::c_void, homogeneous: i32);
+  fn clutter_box_layout_get_homogeneous(self_value: *mut libc::c_void) -> i32;
+  fn clutter_box_layout_set_pack_start(self_value: *mut libc::c_void, pack_start: i32);
+  fn clutter_box_layout_get_pack_start(self_value: *mut libc::c_void) -> i32;
+  fn clutter_box_layout_set_use_animations(self_value: *mut libc::c_void, animate: i32);
+  fn clutter_box_layout_get_use_animations(self_value: *mut libc::c_void) -> i32;
+  fn clutter_box_layout_set_easing_mode(self_value: *mut libc::c_void, mode: libc::c_ulong);
+  fn clutter_box_layout_set_easing_duration(self_value: *mut libc::c_void, msecs: u32);
+}
@@ -0,0 +1,343 @@
+#![stable]
+
+use libc;
+use std;
+use std::cell::RefCell;
+use super::super::actor::{ActorHandle, Box};
+use super::super::actor::allocation::Flags;
+use super::super::ffi::{self, ObjectClass};
+use super::super::value::{Value, Type, TypeNameTaken};
+use super::{LayoutManager, LayoutManagerRef};
+
+/// The description of a per-child layout property.
+///
+/// Each variant holds the name of the property, followed by the minimum,
+/// maximum and default values where relevant.
+pub enum ChildProperty {
+  /// A boolean property, with its default value
+  Boolean(&'static str, bool),
+
+  /// An integer property, with its minimum, maximum and default values
+  Int(&'static str, i32, i32, i32),
+
+  /// A float property, with its minimum, maximum and default values
+  Float(&'static str, f32, f32, f32),
+
+  /// A double property, with its minimum, maximum and default values
+  Double(&'static str, f64, f64, f64)
+}
+
+/// A layout manager implemented in Rust.
+///
+/// Implementations of this trait can be wrapped in a CustomLayoutRef, which
+/// is a real LayoutManager: it can be set on any container using
+/// `Actor#set_layout_manager()`, and Clutter will call into the
+/// implementation when it needs the preferred size of the container or when
+/// the children of the container have to be allocated.
+///
+/// Per-child layout properties are declared with `.child_properties()`;
+/// Clutter then creates a LayoutMeta holding those properties for each child
+/// of the container. They can be read and written from the container side
+/// using `LayoutManager#child_get_property()` and
+/// `LayoutManager#child_set_property()`, including from inside the methods
+/// of the implementation, using the `manager` argument. Changing a child
+/// property queues a relayout of the container.
+pub trait LayoutManagerImpl {
+  /// Returns the name used to register the type of the layout manager.
+  ///
+  /// The name must be unique to the implementation; the layout meta type is
+  /// registered under the same name with a `Meta` suffix.
+  fn type_name(&self) -> &'static str;
+
+  /// Returns the per-child layout properties of the layout manager.
+  ///
+  /// This is only called once per type, when the first instance is created.
+  fn child_properties(&self) -> Vec<ChildProperty> {
+    return Vec::new();
+  }
+
+  /// Computes the minimum and natural widths of the `container`.
+  fn get_preferred_width(&mut self, manager: &mut LayoutManagerRef, container: &mut ActorHandle, for_height: f32) -> (f32, f32);
+
+  /// Computes the minimum and natural heights of the `container`.
+  fn get_preferred_height(&mut self, manager: &mut LayoutManagerRef, container: &mut ActorHandle, for_width: f32) -> (f32, f32);
+
+  /// Allocates the children of `container` inside `allocation`, which is
+  /// expressed in the coordinate space of the container.
+  fn allocate(&mut self, manager: &mut LayoutManagerRef, container: &mut ActorHandle, allocation: &Box, flags: Flags);
+}
+
+/// Opaque struct which holds a reference to the underlying Clutter object.
+#[repr(C)]
+pub struct CustomLayoutRef {
+  opaque: *mut libc::c_void
+}
+
+impl CustomLayoutRef {
+  /// Creates a new layout manager delegating to `implementation`.
+  ///
+  /// The layout manager takes ownership of the implementation, which is
+  /// dropped together with the layout manager.
+  ///
+  /// Fails if the type name of the implementation, or the name of its meta
+  /// type, is taken by a type registered elsewhere.
+  pub fn new<T: LayoutManagerImpl + 'static>(implementation: T) -> Result<CustomLayoutRef, TypeNameTaken> {
+    unsafe {
+      let (layout_type, meta_type) = try!(register_types(&implementation));
+      let instance = std::boxed::Box::new(Instance {
+        implementation: RefCell::new(Some(std::boxed::Box::new(implementation) as std::boxed::Box<LayoutManagerImpl>)),
+        meta_type: meta_type
+      });
+
+      let null_void: *mut libc::c_void = std::ptr::null_mut();
+      let foreign_result = g_object_newv(layout_type, 0, null_void);
+      g_object_set_qdata_full(foreign_result, instance_quark(), std::mem::transmute(instance), free_instance);
+      return Ok(CustomLayoutRef { opaque: foreign_result });
+    }
+  }
+}
+
+impl LayoutManager for CustomLayoutRef {
+  fn as_layout_manager(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+/// The data attached to each instance of a custom layout manager.
+///
+/// The implementation is taken out of the cell while one of its methods
+/// runs, so that a re-entrant call, for instance asking for the preferred
+/// size of the container from `allocate()`, finds it missing instead of
+/// aliasing it.
+struct Instance {
+  implementation: RefCell<Option<std::boxed::Box<LayoutManagerImpl + 'static>>>,
+  meta_type: Type
+}
+
+/// Mirror of ClutterLayoutManagerClass, up to the virtual functions that are
+/// overridden.
+#[repr(C)]
+struct LayoutManagerClass {
+  parent_class: ObjectClass,
+  get_preferred_width: extern "C" fn(*mut libc::c_void, *mut libc::c_void, f32, *mut f32, *mut f32),
+  get_preferred_height: extern "C" fn(*mut libc::c_void, *mut libc::c_void, f32, *mut f32, *mut f32),
+  allocate: extern "C" fn(*mut libc::c_void, *mut libc::c_void, *const Box, Flags),
+  set_container: *mut libc::c_void,
+  get_child_meta_type: extern "C" fn(*mut libc::c_void) -> Type
+}
+
+/// Registers the layout manager and layout meta types for `implementation`,
+/// unless they already exist.
+///
+/// Fails if either name is taken by a type that was not registered here.
+unsafe fn register_types(implementation: &LayoutManagerImpl) -> Result<(Type, Type), TypeNameTaken> {
+  let name = implementation.type_name();
+  let meta_name = format!("{}Meta", name);
+
+  let existing = try!(ffi::find_own_type(name, layout_class_init));
+  let existing_meta = try!(ffi::find_own_type(meta_name.as_slice(), meta_class_init));
+  match (existing, existing_meta) {
+    (Some(layout_type), Some(meta_type)) => return Ok((layout_type, meta_type)),
+    (Some(_), None) => return Err(TypeNameTaken(name.to_string())),
+    (None, Some(_)) => return Err(TypeNameTaken(meta_name)),
+    (None, None) => ()
+  }
+
+  let null_void: *mut libc::c_void = std::ptr::null_mut();
+  let layout_type = ffi::register_subclass(clutter_layout_manager_get_type(), name, layout_class_init, null_void);
+  let properties = std::boxed::Box::new(implementation.child_properties());
+  let meta_type = ffi::register_subclass(clutter_layout_meta_get_type(), meta_name.as_slice(), meta_class_init, std::mem::transmute(properties));
+  return Ok((layout_type, meta_type));
+}
+
+fn instance_quark() -> u32 {
+  unsafe {
+    return g_quark_from_static_string("rust-layout-manager-instance\0".as_ptr() as *const i8);
+  }
+}
+
+fn values_quark() -> u32 {
+  unsafe {
+    return g_quark_from_static_string("rust-layout-meta-values\0".as_ptr() as *const i8);
+  }
+}
+
+unsafe fn get_instance<'a>(manager: *mut libc::c_void) -> &'a Instance {
+  return std::mem::transmute(g_object_get_qdata(manager, instance_quark()));
+}
+
+/// Calls `f` with the implementation of `manager` and `container`, unless the
+/// implementation is already running, in which case __None__ is returned.
+unsafe fn with_implementation<R, F>(manager: *mut libc::c_void, container: *mut libc::c_void, f: F) -> Option<R> where F: FnOnce(&mut LayoutManagerImpl, &mut LayoutManagerRef, &mut ActorHandle) -> R {
+  let instance = get_instance(manager);
+  let taken = instance.implementation.borrow_mut().take();
+  let mut implementation = match taken {
+    Some(implementation) => implementation,
+    None => {
+      ffi::warn("LayoutManagerImpl: the implementation cannot be called re-entrantly");
+      return None;
+    }
+  };
+
+  let mut manager_r = LayoutManagerRef { opaque: manager };
+  // The container is owned by the scene graph.
+  let mut container_r = std::mem::transmute::<*mut libc::c_void, ActorHandle>(container);
+  let result = f(&mut *implementation, &mut manager_r, &mut container_r);
+  *instance.implementation.borrow_mut() = Some(implementation);
+  return Some(result);
+}
+
+extern "C" fn free_instance(instance: *mut libc::c_void) {
+  unsafe {
+    let instance: std::boxed::Box<Instance> = std::mem::transmute(instance);
+    drop(instance);
+  }
+}
+
+extern "C" fn free_values(values: *mut libc::c_void) {
+  unsafe {
+    let values: std::boxed::Box<RefCell<Vec<Option<Value>>>> = std::mem::transmute(values);
+    drop(values);
+  }
+}
+
+extern "C" fn layout_class_init(klass: *mut libc::c_void, _class_data: *mut libc::c_void) {
+  unsafe {
+    let klass = &mut *(klass as *mut LayoutManagerClass);
+    klass.get_preferred_width = layout_get_preferred_width;
+    klass.get_preferred_height = layout_get_preferred_height;
+    klass.allocate = layout_allocate;
+    klass.get_child_meta_type = layout_get_child_meta_type;
+  }
+}
+
+extern "C" fn layout_get_preferred_width(manager: *mut libc::c_void, container: *mut libc::c_void, for_height: f32, min_width_p: *mut f32, nat_width_p: *mut f32) {
+  unsafe {
+    let (min_width, nat_width) = with_implementation(manager, container, |implementation, manager_r, container_r| {
+      implementation.get_preferred_width(manager_r, container_r, for_height)
+    }).unwrap_or((0.0, 0.0));
+    if !min_width_p.is_null() {
+      *min_width_p = min_width;
+    }
+    if !nat_width_p.is_null() {
+      *nat_width_p = nat_width;
+    }
+  }
+}
+
+extern "C" fn layout_get_preferred_height(manager: *mut libc::c_void, container: *mut libc::c_void, for_width: f32, min_height_p: *mut f32, nat_height_p: *mut f32) {
+  unsafe {
+    let (min_height, nat_height) = with_implementation(manager, container, |implementation, manager_r, container_r| {
+      implementation.get_preferred_height(manager_r, container_r, for_width)
+    }).unwrap_or((0.0, 0.0));
+    if !min_height_p.is_null() {
+      *min_height_p = min_height;
+    }
+    if !nat_height_p.is_null() {
+      *nat_height_p = nat_height;
+    }
+  }
+}
+
+extern "C" fn layout_allocate(manager: *mut libc::c_void, container: *mut libc::c_void, allocation: *const Box, flags: Flags) {
+  unsafe {
+    with_implementation(manager, container, |implementation, manager_r, container_r| {
+      implementation.allocate(manager_r, container_r, &*allocation, flags)
+    });
+  }
+}
+
+extern "C" fn layout_get_child_meta_type(manager: *mut libc::c_void) -> Type {
+  unsafe {
+    return get_instance(manager).meta_type;
+  }
+}
+
+extern "C" fn meta_class_init(klass: *mut libc::c_void, class_data: *mut libc::c_void) {
+  unsafe {
+    use std::ffi::CString;
+    let object_class = &mut *(klass as *mut ObjectClass);
+    object_class.set_property = meta_set_property;
+    object_class.get_property = meta_get_property;
+
+    let properties = &*(class_data as *const Vec<ChildProperty>);
+    let null_char: *const libc::c_char = std::ptr::null();
+    let mut property_id = 1;
+    for property in properties.iter() {
+      let pspec = match *property {
+        ChildProperty::Boolean(name, default) =>
+          g_param_spec_boolean(name.to_c_str().unwrap() as *const i8, null_char, null_char, (default as i32), PARAM_READWRITE),
+        ChildProperty::Int(name, min, max, default) =>
+          g_param_spec_int(name.to_c_str().unwrap() as *const i8, null_char, null_char, min, max, default, PARAM_READWRITE),
+        ChildProperty::Float(name, min, max, default) =>
+          g_param_spec_float(name.to_c_str().unwrap() as *const i8, null_char, null_char, min, max, default, PARAM_READWRITE),
+        ChildProperty::Double(name, min, max, default) =>
+          g_param_spec_double(name.to_c_str().unwrap() as *const i8, null_char, null_char, min, max, default, PARAM_READWRITE)
+      };
+      g_object_class_install_property(klass, property_id, pspec);
+      property_id += 1;
+    }
+  }
+}
+
+/// Returns the storage of the child properties of a layout meta, creating it
+/// on first use.
+unsafe fn meta_values<'a>(meta: *mut libc::c_void) -> &'a RefCell<Vec<Option<Value>>> {
+  let mut values = g_object_get_qdata(meta, values_quark());
+  if values.is_null() {
+    let storage: std::boxed::Box<RefCell<Vec<Option<Value>>>> = std::boxed::Box::new(RefCell::new(Vec::new()));
+    values = std::mem::transmute(storage);
+    g_object_set_qdata_full(meta, values_quark(), values, free_values);
+  }
+
+  return std::mem::transmute(values);
+}
+
+extern "C" fn meta_set_property(meta: *mut libc::c_void, property_id: u32, value: *const Value, _pspec: *mut libc::c_void) {
+  unsafe {
+    {
+      let mut values = meta_values(meta).borrow_mut();
+      let index = (property_id - 1) as usize;
+      while values.len() <= index {
+        values.push(None);
+      }
+      values[index] = Some((*value).clone());
+    }
+
+    let manager = clutter_layout_meta_get_manager(meta);
+    if !manager.is_null() {
+      clutter_layout_manager_layout_changed(manager);
+    }
+  }
+}
+
+extern "C" fn meta_get_property(meta: *mut libc::c_void, property_id: u32, value: *mut Value, pspec: *mut libc::c_void) {
+  unsafe {
+    let values = meta_values(meta).borrow();
+    let index = (property_id - 1) as usize;
+    match values.get(index) {
+      Some(&Some(ref stored)) => g_value_copy(stored, value),
+      _ => g_param_value_set_default(pspec, value)
+    }
+  }
+}
+
+const PARAM_READWRITE: u32 = 3;
+
+extern {
+  fn clutter_layout_manager_get_type() -> Type;
+  fn clutter_layout_manager_layout_changed(self_value: *mut libc::c_void);
+  fn clutter_layout_meta_get_type() -> Type;
+  fn clutter_layout_meta_get_manager(self_value: *mut libc::c_void) -> *mut libc::c_void;
+  fn g_object_newv(object_type: Type, n_parameters: u32, parameters: *mut libc::c_void) -> *mut libc::c_void;
+  fn g_object_class_install_property(oclass: *mut libc::c_void, property_id: u32, pspec: *mut libc::c_void);
+  fn g_object_get_qdata(object: *mut libc::c_void, quark: u32) -> *mut libc::c_void;
+  fn g_object_set_qdata_full(object: *mut libc::c_void, quark: u32, data: *mut libc::c_void, destroy: extern "C" fn(*mut libc::c_void));
+  fn g_quark_from_static_string(string: *const libc::c_char) -> u32;
+  fn g_param_spec_boolean(name: *const libc::c_char, nick: *const libc::c_char, blurb: *const libc::c_char, default_value: i32, flags: u32) -> *mut libc::c_void;
+  fn g_param_spec_int(name: *const libc::c_char, nick: *const libc::c_char, blurb: *const libc::c_char, minimum: i32, maximum: i32, default_value: i32, flags: u32) -> *mut libc::c_void;
+  fn g_param_spec_float(name: *const libc::c_char, nick: *const libc::c_char, blurb: *const libc::c_char, minimum: f32, maximum: f32, default_value: f32, flags: u32) -> *mut libc::c_void;
+  fn g_param_spec_double(name: *const libc::c_char, nick: *const libc::c_char, blurb: *const libc::c_char, minimum: f64, maximum: f64, default_value: f64, flags: u32) -> *mut libc::c_void;
+  fn g_param_value_set_default(pspec: *mut libc::c_void, value: *mut Value);
+  fn g_value_copy(src_value: *const Value, dest_value: *mut Value);
+}
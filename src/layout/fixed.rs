@@ -0,0 +1,54 @@
+#![stable]
+
+use libc;
+use super::LayoutManager;
+
+/// Opaque struct which holds a reference to the underlying Clutter object.
+#[repr(C)]
+pub struct FixedLayoutRef {
+  opaque: *mut libc::c_void
+}
+
+impl FixedLayoutRef {
+  /// Creates a new FixedLayout.
+  ///
+  /// _Since 1.2_
+  pub fn new() -> FixedLayoutRef {
+    unsafe {
+      let foreign_result = clutter_fixed_layout_new();
+      return foreign_result;
+    }
+  }
+}
+
+/// A fixed layout manager.
+///
+/// FixedLayout is a layout manager implementing the same layout policies as
+/// the Group actor: children are positioned using their fixed position, as
+/// set with `Actor#set_position()`, and sized to their preferred size.
+///
+/// This is the default layout manager of Actor.
+///
+/// _Since 1.2_
+pub trait FixedLayout {
+  /// Returns a pointer to the the underlying C object.
+  ///
+  /// Generally only used internally.
+  fn as_fixed_layout(&self) -> *mut libc::c_void;
+}
+
+impl FixedLayout for FixedLayoutRef {
+  fn as_fixed_layout(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+impl LayoutManager for FixedLayoutRef {
+  fn as_layout_manager(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+extern {
+  fn clutter_fixed_layout_new() -> FixedLayoutRef;
+}
@@ -0,0 +1,240 @@
+#![stable]
+
+use libc;
+use std;
+use super::LayoutManager;
+
+/// The direction of the arrangement of the children inside a FlowLayout.
+///
+/// _Since 1.2_
+#[repr(i32)]
+pub enum Orientation {
+  /// Arrange the children of the flow layout horizontally first
+  Horizontal = 0,
+
+  /// Arrange the children of the flow layout vertically first
+  Vertical = 1
+}
+
+/// Opaque struct which holds a reference to the underlying Clutter object.
+#[repr(C)]
+pub struct FlowLayoutRef {
+  opaque: *mut libc::c_void
+}
+
+impl FlowLayoutRef {
+  /// Creates a new FlowLayout with the given `orientation`.
+  ///
+  /// _Since 1.2_
+  pub fn new(orientation: Orientation) -> FlowLayoutRef {
+    unsafe {
+      let foreign_result = clutter_flow_layout_new(orientation);
+      return foreign_result;
+    }
+  }
+}
+
+/// A reflowing layout manager.
+///
+/// FlowLayout is a layout manager which implements the following policy:
+///
+/// - the preferred natural size depends on the value of the `orientation`
+///   property; the layout will try to maintain all its children on a single
+///   row or column
+/// - if either the width or the height allocated are smaller than the
+///   preferred ones, the layout will wrap; in this case, the preferred height
+///   or width, respectively, will take into account the amount of columns
+///   and rows
+/// - each line (either column or row) in reflowing will have the size of the
+///   biggest cell on that line; if the `homogeneous` property is set to
+///   __false__ the actor will be allocated within that area, and if set to
+///   __true__ instead the actor will be given exactly that area
+/// - the size of the columns or rows can be controlled for both minimum and
+///   maximum; the spacing can also be controlled in both columns and rows
+///
+/// The layout reflows its children automatically every time the allocation
+/// of the container changes, so a gallery of tiles will rearrange itself
+/// when its parent is resized.
+///
+/// _Since 1.2_
+pub trait FlowLayout {
+  /// Returns a pointer to the the underlying C object.
+  ///
+  /// Generally only used internally.
+  fn as_flow_layout(&self) -> *mut libc::c_void;
+
+  /// Sets the orientation of the flow layout.
+  ///
+  /// The orientation controls the direction used to allocate the children:
+  /// either horizontally or vertically. The orientation also controls the
+  /// direction of the overflowing.
+  ///
+  /// _Since 1.2_
+  fn set_orientation(&mut self, orientation: Orientation) {
+    unsafe {
+      clutter_flow_layout_set_orientation(self.as_flow_layout(), orientation);
+    }
+  }
+
+  /// Retrieves the orientation of the layout.
+  ///
+  /// _Since 1.2_
+  fn get_orientation(&mut self) -> Orientation {
+    unsafe {
+      let foreign_result = clutter_flow_layout_get_orientation(self.as_flow_layout());
+      return foreign_result;
+    }
+  }
+
+  /// Sets whether the layout should allocate the same space for each child.
+  ///
+  /// _Since 1.2_
+  fn set_homogeneous(&mut self, homogeneous: bool) {
+    unsafe {
+      clutter_flow_layout_set_homogeneous(self.as_flow_layout(), (homogeneous as i32));
+    }
+  }
+
+  /// Retrieves whether the layout is homogeneous.
+  ///
+  /// _Since 1.2_
+  fn get_homogeneous(&mut self) -> bool {
+    unsafe {
+      let foreign_result = clutter_flow_layout_get_homogeneous(self.as_flow_layout());
+      return foreign_result != 0;
+    }
+  }
+
+  /// Sets the space between columns, in pixels.
+  ///
+  /// _Since 1.2_
+  fn set_column_spacing(&mut self, spacing: f32) {
+    unsafe {
+      clutter_flow_layout_set_column_spacing(self.as_flow_layout(), spacing);
+    }
+  }
+
+  /// Retrieves the spacing between columns.
+  ///
+  /// _Since 1.2_
+  fn get_column_spacing(&mut self) -> f32 {
+    unsafe {
+      let foreign_result = clutter_flow_layout_get_column_spacing(self.as_flow_layout());
+      return foreign_result;
+    }
+  }
+
+  /// Sets the spacing between rows, in pixels.
+  ///
+  /// _Since 1.2_
+  fn set_row_spacing(&mut self, spacing: f32) {
+    unsafe {
+      clutter_flow_layout_set_row_spacing(self.as_flow_layout(), spacing);
+    }
+  }
+
+  /// Retrieves the spacing between rows.
+  ///
+  /// _Since 1.2_
+  fn get_row_spacing(&mut self) -> f32 {
+    unsafe {
+      let foreign_result = clutter_flow_layout_get_row_spacing(self.as_flow_layout());
+      return foreign_result;
+    }
+  }
+
+  /// Sets the minimum and maximum widths that a column can have.
+  ///
+  /// A `max_width` of -1 means that columns have no maximum width.
+  ///
+  /// _Since 1.2_
+  fn set_column_width(&mut self, min_width: f32, max_width: f32) {
+    unsafe {
+      clutter_flow_layout_set_column_width(self.as_flow_layout(), min_width, max_width);
+    }
+  }
+
+  /// Retrieves the minimum and maximum column widths.
+  ///
+  /// _Since 1.2_
+  fn get_column_width(&mut self) -> (f32, f32) {
+    unsafe {
+      let mut min_width:f32 = std::intrinsics::init();
+      let mut max_width:f32 = std::intrinsics::init();
+      clutter_flow_layout_get_column_width(self.as_flow_layout(), &mut min_width, &mut max_width);
+      return (min_width, max_width);
+    }
+  }
+
+  /// Sets the minimum and maximum heights that a row can have.
+  ///
+  /// A `max_height` of -1 means that rows have no maximum height.
+  ///
+  /// _Since 1.2_
+  fn set_row_height(&mut self, min_height: f32, max_height: f32) {
+    unsafe {
+      clutter_flow_layout_set_row_height(self.as_flow_layout(), min_height, max_height);
+    }
+  }
+
+  /// Retrieves the minimum and maximum row heights.
+  ///
+  /// _Since 1.2_
+  fn get_row_height(&mut self) -> (f32, f32) {
+    unsafe {
+      let mut min_height:f32 = std::intrinsics::init();
+      let mut max_height:f32 = std::intrinsics::init();
+      clutter_flow_layout_get_row_height(self.as_flow_layout(), &mut min_height, &mut max_height);
+      return (min_height, max_height);
+    }
+  }
+
+  /// Whether the layout should place its children on a grid.
+  ///
+  /// _Since 1.16_
+  fn set_snap_to_grid(&mut self, snap_to_grid: bool) {
+    unsafe {
+      clutter_flow_layout_set_snap_to_grid(self.as_flow_layout(), (snap_to_grid as i32));
+    }
+  }
+
+  /// Retrieves the value set using `.set_snap_to_grid()`.
+  ///
+  /// _Since 1.16_
+  fn get_snap_to_grid(&mut self) -> bool {
+    unsafe {
+      let foreign_result = clutter_flow_layout_get_snap_to_grid(self.as_flow_layout());
+      return foreign_result != 0;
+    }
+  }
+}
+
+impl FlowLayout for FlowLayoutRef {
+  fn as_flow_layout(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+impl LayoutManager for FlowLayoutRef {
+  fn as_layout_manager(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+extern {
+  fn clutter_flow_layout_new(orientation: Orientation) -> FlowLayoutRef;
+  fn clutter_flow_layout_set_orientation(self_value: *mut libc::c_void, orientation: Orientation);
+  fn clutter_flow_layout_get_orientation(self_value: *mut libc::c_void) -> Orientation;
+  fn clutter_flow_layout_set_homogeneous(self_value: *mut libc::c_void, homogeneous: i32);
+  fn clutter_flow_layout_get_homogeneous(self_value: *mut libc::c_void) -> i32;
+  fn clutter_flow_layout_set_column_spacing(self_value: *mut libc::c_void, spacing: f32);
+  fn clutter_flow_layout_get_column_spacing(self_value: *mut libc::c_void) -> f32;
+  fn clutter_flow_layout_set_row_spacing(self_value: *mut libc::c_void, spacing: f32);
+  fn clutter_flow_layout_get_row_spacing(self_value: *mut libc::c_void) -> f32;
+  fn clutter_flow_layout_set_column_width(self_value: *mut libc::c_void, min_width: f32, max_width: f32);
+  fn clutter_flow_layout_get_column_width(self_value: *mut libc::c_void, min_width: *mut f32, max_width: *mut f32);
+  fn clutter_flow_layout_set_row_height(self_value: *mut libc::c_void, min_height: f32, max_height: f32);
+  fn clutter_flow_layout_get_row_height(self_value: *mut libc::c_void, min_height: *mut f32, max_height: *mut f32);
+  fn clutter_flow_layout_set_snap_to_grid(self_value: *mut libc::c_void, snap_to_grid: i32);
+  fn clutter_flow_layout_get_snap_to_grid(self_value: *mut libc::c_void) -> i32;
+}
@@ -0,0 +1,382 @@
+#![stable]
+
+use libc;
+use std;
+use super::super::actor::{Actor, ActorHandle, ActorRef};
+use super::super::value::{Value, TYPE_INT};
+use super::{LayoutManager, LayoutManagerRef};
+use super::Orientation;
+
+/// Grid position modes.
+///
+/// _Since 1.12_
+#[repr(i32)]
+pub enum Position {
+  /// Left position
+  Left = 0,
+
+  /// Right position
+  Right = 1,
+
+  /// Top position
+  Top = 2,
+
+  /// Bottom position
+  Bottom = 3
+}
+
+/// Opaque struct which holds a reference to the underlying Clutter object.
+#[repr(C)]
+pub struct GridLayoutRef {
+  opaque: *mut libc::c_void
+}
+
+impl GridLayoutRef {
+  /// Creates a new GridLayout.
+  ///
+  /// _Since 1.12_
+  pub fn new() -> GridLayoutRef {
+    unsafe {
+      let foreign_result = clutter_grid_layout_new();
+      return foreign_result;
+    }
+  }
+}
+
+/// A layout manager for a grid of actors.
+///
+/// GridLayout is a layout manager which arranges its children in rows and
+/// columns. It is very similar to BoxLayout, but it works on two axes.
+///
+/// Children are added using `.attach()`, which adds the child to the
+/// container and places it at the given cell; they can span multiple rows or
+/// columns. It is also possible to add a child next to an existing child,
+/// using `.attach_next_to()`.
+///
+/// Children added through `Actor#add_child()` on the container are placed
+/// automatically, next to the last child, along the `orientation` of the
+/// layout.
+///
+/// The behaviour of GridLayout when several children occupy the same grid
+/// cell is undefined.
+///
+/// _Since 1.12_
+pub trait GridLayout {
+  /// Returns a pointer to the the underlying C object.
+  ///
+  /// Generally only used internally.
+  fn as_grid_layout(&self) -> *mut libc::c_void;
+
+  /// Adds a widget to the grid.
+  ///
+  /// The position of `child` is determined by `left` and `top`. The number of
+  /// cells that `child` will occupy is determined by `width` and `height`.
+  ///
+  /// The layout must be set on a container using `Actor#set_layout_manager()`
+  /// before calling this method, as `child` is added to that container.
+  ///
+  /// _Since 1.12_
+  fn attach<T: Actor>(&mut self, child: &mut T, left: i32, top: i32, width: i32, height: i32) {
+    unsafe {
+      clutter_grid_layout_attach(self.as_grid_layout(), child.as_actor(), left, top, width, height);
+    }
+  }
+
+  /// Adds a widget to the grid.
+  ///
+  /// The widget is placed next to `sibling`, on the side determined by
+  /// `side`.
+  ///
+  /// Attaching widgets labeled [1], [2], [3] with `sibling` being the
+  /// previous widget and `side` being `Position::Bottom` results in a
+  /// vertical layout.
+  ///
+  /// _Since 1.12_
+  fn attach_next_to<T: Actor, U: Actor>(&mut self, child: &mut T, sibling: &mut U, side: Position, width: i32, height: i32) {
+    unsafe {
+      clutter_grid_layout_attach_next_to(self.as_grid_layout(), child.as_actor(), sibling.as_actor(), side, width, height);
+    }
+  }
+
+  /// Adds a widget to the grid, at the end of the row or column.
+  ///
+  /// This is `.attach_next_to()` without a sibling: `child` is placed at the
+  /// end or beginning of the first row or column, depending on `side`.
+  ///
+  /// _Since 1.12_
+  fn attach_at_edge<T: Actor>(&mut self, child: &mut T, side: Position, width: i32, height: i32) {
+    unsafe {
+      let null_void: *mut libc::c_void = std::ptr::null_mut();
+      clutter_grid_layout_attach_next_to(self.as_grid_layout(), child.as_actor(), null_void, side, width, height);
+    }
+  }
+
+  /// Gets the child whose area covers the grid cell whose upper left corner
+  /// is at `left`, `top`.
+  ///
+  /// Returns __None__ if no child covers the cell. The child is owned by the
+  /// container.
+  ///
+  /// _Since 1.12_
+  fn get_child_at(&mut self, left: i32, top: i32) -> Option<ActorHandle> {
+    unsafe {
+      let foreign_result = clutter_grid_layout_get_child_at(self.as_grid_layout(), left, top);
+      if foreign_result.is_null() {
+        return None;
+      }
+
+      return Some(std::mem::transmute(foreign_result));
+    }
+  }
+
+  /// Inserts a row at the specified position.
+  ///
+  /// Children which are attached at or below this position are moved one row
+  /// down. Children which span across this position are grown to span the
+  /// new row.
+  ///
+  /// _Since 1.12_
+  fn insert_row(&mut self, position: i32) {
+    unsafe {
+      clutter_grid_layout_insert_row(self.as_grid_layout(), position);
+    }
+  }
+
+  /// Inserts a column at the specified position.
+  ///
+  /// Children which are attached at or to the right of this position are
+  /// moved one column to the right. Children which span across this position
+  /// are grown to span the new column.
+  ///
+  /// _Since 1.12_
+  fn insert_column(&mut self, position: i32) {
+    unsafe {
+      clutter_grid_layout_insert_column(self.as_grid_layout(), position);
+    }
+  }
+
+  /// Inserts a row or column at the specified position.
+  ///
+  /// The new row or column is placed next to `sibling`, on the side
+  /// determined by `side`. If `side` is `Position::Top` or
+  /// `Position::Bottom`, a row is inserted. If `side` is `Position::Left` or
+  /// `Position::Right`, a column is inserted.
+  ///
+  /// _Since 1.12_
+  fn insert_next_to<T: Actor>(&mut self, sibling: &mut T, side: Position) {
+    unsafe {
+      clutter_grid_layout_insert_next_to(self.as_grid_layout(), sibling.as_actor(), side);
+    }
+  }
+
+  /// Removes a row from the grid of `container`.
+  ///
+  /// Children that are placed in this row are removed from the container,
+  /// spanning children that overlap this row have their height reduced by
+  /// one, and children below the row are moved up.
+  ///
+  /// Clutter 1.x has no function to remove a row, so this is implemented on
+  /// top of the `top-attach` and `height` child properties. The removed
+  /// children are returned, no longer owned by the container: they can be
+  /// added to another container, and they are destroyed when dropped.
+  fn remove_row<T: Actor>(&mut self, container: &mut T, position: i32) -> Vec<ActorRef> {
+    return remove_line(self.as_grid_layout(), container, position, "top-attach", "height");
+  }
+
+  /// Removes a column from the grid of `container`.
+  ///
+  /// Children that are placed in this column are removed from the container,
+  /// spanning children that overlap this column have their width reduced by
+  /// one, and children after the column are moved to the left.
+  ///
+  /// Clutter 1.x has no function to remove a column, so this is implemented
+  /// on top of the `left-attach` and `width` child properties. The removed
+  /// children are returned, as with `.remove_row()`.
+  fn remove_column<T: Actor>(&mut self, container: &mut T, position: i32) -> Vec<ActorRef> {
+    return remove_line(self.as_grid_layout(), container, position, "left-attach", "width");
+  }
+
+  /// Sets the orientation of the layout.
+  ///
+  /// GridLayout uses the orientation to decide where to place children added
+  /// with `Actor#add_child()`.
+  ///
+  /// _Since 1.12_
+  fn set_orientation(&mut self, orientation: Orientation) {
+    unsafe {
+      clutter_grid_layout_set_orientation(self.as_grid_layout(), orientation);
+    }
+  }
+
+  /// Retrieves the orientation of the layout.
+  ///
+  /// _Since 1.12_
+  fn get_orientation(&mut self) -> Orientation {
+    unsafe {
+      let foreign_result = clutter_grid_layout_get_orientation(self.as_grid_layout());
+      return foreign_result;
+    }
+  }
+
+  /// Sets the spacing between columns of the layout.
+  ///
+  /// _Since 1.12_
+  fn set_column_spacing(&mut self, spacing: u32) {
+    unsafe {
+      clutter_grid_layout_set_column_spacing(self.as_grid_layout(), spacing);
+    }
+  }
+
+  /// Retrieves the spacing set using `.set_column_spacing()`.
+  ///
+  /// _Since 1.12_
+  fn get_column_spacing(&mut self) -> u32 {
+    unsafe {
+      let foreign_result = clutter_grid_layout_get_column_spacing(self.as_grid_layout());
+      return foreign_result;
+    }
+  }
+
+  /// Sets the spacing between rows of the layout.
+  ///
+  /// _Since 1.12_
+  fn set_row_spacing(&mut self, spacing: u32) {
+    unsafe {
+      clutter_grid_layout_set_row_spacing(self.as_grid_layout(), spacing);
+    }
+  }
+
+  /// Retrieves the spacing set using `.set_row_spacing()`.
+  ///
+  /// _Since 1.12_
+  fn get_row_spacing(&mut self) -> u32 {
+    unsafe {
+      let foreign_result = clutter_grid_layout_get_row_spacing(self.as_grid_layout());
+      return foreign_result;
+    }
+  }
+
+  /// Sets whether all columns of the layout will have the same width.
+  ///
+  /// _Since 1.12_
+  fn set_column_homogeneous(&mut self, homogeneous: bool) {
+    unsafe {
+      clutter_grid_layout_set_column_homogeneous(self.as_grid_layout(), (homogeneous as i32));
+    }
+  }
+
+  /// Returns whether all columns of the layout have the same width.
+  ///
+  /// _Since 1.12_
+  fn get_column_homogeneous(&mut self) -> bool {
+    unsafe {
+      let foreign_result = clutter_grid_layout_get_column_homogeneous(self.as_grid_layout());
+      return foreign_result != 0;
+    }
+  }
+
+  /// Sets whether all rows of the layout will have the same height.
+  ///
+  /// _Since 1.12_
+  fn set_row_homogeneous(&mut self, homogeneous: bool) {
+    unsafe {
+      clutter_grid_layout_set_row_homogeneous(self.as_grid_layout(), (homogeneous as i32));
+    }
+  }
+
+  /// Returns whether all rows of the layout have the same height.
+  ///
+  /// _Since 1.12_
+  fn get_row_homogeneous(&mut self) -> bool {
+    unsafe {
+      let foreign_result = clutter_grid_layout_get_row_homogeneous(self.as_grid_layout());
+      return foreign_result != 0;
+    }
+  }
+}
+
+impl GridLayout for GridLayoutRef {
+  fn as_grid_layout(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+impl LayoutManager for GridLayoutRef {
+  fn as_layout_manager(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+/// Removes the row or column at `position`, given the names of the child
+/// properties holding the attach point and the span along that axis, and
+/// returns the children removed from `container`.
+fn remove_line<T: Actor>(layout: *mut libc::c_void, container: &mut T, position: i32, attach_property: &str, span_property: &str) -> Vec<ActorRef> {
+  let mut removed = Vec::new();
+  let mut manager = LayoutManagerRef { opaque: layout };
+
+  // Collect the children first, as some of them may be removed.
+  let mut children = Vec::new();
+  let mut child = container.get_first_child();
+  while !child.as_actor().is_null() {
+    let next = child.get_next_sibling();
+    children.push(child);
+    child = next;
+  }
+  unsafe { std::mem::forget(child); }
+
+  for child in children.iter_mut() {
+    let mut attach = Value::new(TYPE_INT);
+    let mut span = Value::new(TYPE_INT);
+    manager.child_get_property(container, child, attach_property, &mut attach);
+    manager.child_get_property(container, child, span_property, &mut span);
+
+    let start = attach.get_int();
+    let length = span.get_int();
+    if start <= position && position < start + length {
+      if length > 1 {
+        span.set_int(length - 1);
+        manager.child_set_property(container, child, span_property, &span);
+      } else {
+        // Keep the child alive, floating as a new actor is.
+        unsafe {
+          g_object_ref(child.as_actor());
+          container.remove_child(child);
+          g_object_force_floating(child.as_actor());
+        }
+        removed.push(unsafe { std::mem::transmute::<*mut libc::c_void, ActorRef>(child.as_actor()) });
+      }
+    } else if start > position {
+      attach.set_int(start - 1);
+      manager.child_set_property(container, child, attach_property, &attach);
+    }
+  }
+
+  // The children are owned by the scene graph, not by us.
+  for child in children.into_iter() {
+    unsafe { std::mem::forget(child); }
+  }
+
+  return removed;
+}
+
+extern {
+  fn clutter_grid_layout_new() -> GridLayoutRef;
+  fn g_object_ref(object: *mut libc::c_void) -> *mut libc::c_void;
+  fn g_object_force_floating(object: *mut libc::c_void);
+  fn clutter_grid_layout_attach(self_value: *mut libc::c_void, child: *mut libc::c_void, left: i32, top: i32, width: i32, height: i32);
+  fn clutter_grid_layout_attach_next_to(self_value: *mut libc::c_void, child: *mut libc::c_void, sibling: *mut libc::c_void, side: Position, width: i32, height: i32);
+  fn clutter_grid_layout_get_child_at(self_value: *mut libc::c_void, left: i32, top: i32) -> *mut libc::c_void;
+  fn clutter_grid_layout_insert_row(self_value: *mut libc::c_void, position: i32);
+  fn clutter_grid_layout_insert_column(self_value: *mut libc::c_void, position: i32);
+  fn clutter_grid_layout_insert_next_to(self_value: *mut libc::c_void, sibling: *mut libc::c_void, side: Position);
+  fn clutter_grid_layout_set_orientation(self_value: *mut libc::c_void, orientation: Orientation);
+  fn clutter_grid_layout_get_orientation(self_value: *mut libc::c_void) -> Orientation;
+  fn clutter_grid_layout_set_column_spacing(self_value: *mut libc::c_void, spacing: u32);
+  fn clutter_grid_layout_get_column_spacing(self_value: *mut libc::c_void) -> u32;
+  fn clutter_grid_layout_set_row_spacing(self_value: *mut libc::c_void, spacing: u32);
+  fn clutter_grid_layout_get_row_spacing(self_value: *mut libc::c_void) -> u32;
+  fn clutter_grid_layout_set_column_homogeneous(self_value: *mut libc::c_void, homogeneous: i32);
+  fn clutter_grid_layout_get_column_homogeneous(self_value: *mut libc::c_void) -> i32;
+  fn clutter_grid_layout_set_row_homogeneous(self_value: *mut libc::c_void, homogeneous: i32);
+  fn clutter_grid_layout_get_row_homogeneous(self_value: *mut libc::c_void) -> i32;
+}
@@ -0,0 +1,213 @@
+#![stable]
+
+use libc;
+use std;
+use super::actor::Actor;
+use super::value::Value;
+
+pub mod bin;
+pub mod box_layout;
+pub mod custom;
+pub mod fixed;
+pub mod flow;
+pub mod grid;
+
+/// Represents the orientation of actors or layout managers.
+///
+/// _Since 1.12_
+#[repr(i32)]
+pub enum Orientation {
+  /// An horizontal orientation
+  Horizontal = 0,
+
+  /// A vertical orientation
+  Vertical = 1
+}
+
+/// Opaque struct which holds a reference to the underlying Clutter object.
+#[repr(C)]
+pub struct LayoutManagerRef {
+  opaque: *mut libc::c_void
+}
+
+/// Layout managers base trait.
+///
+/// Actors can defer their layout to a LayoutManager instance, set with
+/// `Actor#set_layout_manager()`; the layout manager is then responsible for
+/// the preferred size of the actor and for the allocation of its children.
+/// This is what the `DelegateLayout` allocation flag refers to.
+///
+/// Clutter provides some simple LayoutManager implementations, like
+/// BoxLayout.
+///
+/// _Since 1.2_
+pub trait LayoutManager {
+  /// Returns a pointer to the the underlying C object.
+  ///
+  /// Generally only used internally.
+  fn as_layout_manager(&self) -> *mut libc::c_void;
+
+  /// Computes the minimum and natural widths of the `container` according to
+  /// the layout manager.
+  ///
+  /// _Since 1.2_
+  fn get_preferred_width<T: Actor>(&mut self, container: &mut T, for_height: f32) -> (f32, f32) {
+    unsafe {
+      let mut min_width_p:f32 = std::intrinsics::init();
+      let mut nat_width_p:f32 = std::intrinsics::init();
+      clutter_layout_manager_get_preferred_width(self.as_layout_manager(), container.as_actor(), for_height, &mut min_width_p, &mut nat_width_p);
+      return (min_width_p, nat_width_p);
+    }
+  }
+
+  /// Computes the minimum and natural heights of the `container` according
+  /// to the layout manager.
+  ///
+  /// _Since 1.2_
+  fn get_preferred_height<T: Actor>(&mut self, container: &mut T, for_width: f32) -> (f32, f32) {
+    unsafe {
+      let mut min_height_p:f32 = std::intrinsics::init();
+      let mut nat_height_p:f32 = std::intrinsics::init();
+      clutter_layout_manager_get_preferred_height(self.as_layout_manager(), container.as_actor(), for_width, &mut min_height_p, &mut nat_height_p);
+      return (min_height_p, nat_height_p);
+    }
+  }
+
+  /// Allocates the children of `container` given an area.
+  ///
+  /// _Since 1.2_
+  fn allocate<T: Actor>(&mut self, container: &mut T, allocation: &super::actor::Box, flags: super::actor::allocation::Flags) {
+    unsafe {
+      clutter_layout_manager_allocate(self.as_layout_manager(), container.as_actor(), allocation, flags);
+    }
+  }
+
+  /// Retrieves the LayoutMeta that the layout manager associated to the
+  /// `actor` child of `container`, eventually by creating one if the layout
+  /// manager supports layout properties.
+  ///
+  /// _Since 1.0_
+  fn get_child_meta<T: Actor, U: Actor>(&mut self, container: &mut T, actor: &mut U) -> LayoutMetaRef {
+    unsafe {
+      let foreign_result = clutter_layout_manager_get_child_meta(self.as_layout_manager(), container.as_actor(), actor.as_actor());
+      return foreign_result;
+    }
+  }
+
+  /// Gets a property on the LayoutMeta created by the layout manager for
+  /// `child`, a child of `container`, and stores it inside `value`.
+  ///
+  /// `value` must be initialised to the type of the property.
+  ///
+  /// _Since 1.2_
+  fn child_get_property<T: Actor, U: Actor>(&mut self, container: &mut T, child: &mut U, property_name: &str, value: &mut Value) {
+    unsafe {
+      use std::ffi::CString;
+      clutter_layout_manager_child_get_property(self.as_layout_manager(), container.as_actor(), child.as_actor(), property_name.to_c_str().unwrap() as *mut i8, value);
+    }
+  }
+
+  /// Sets a property on the LayoutMeta created by the layout manager for
+  /// `child`, a child of `container`.
+  ///
+  /// _Since 1.2_
+  fn child_set_property<T: Actor, U: Actor>(&mut self, container: &mut T, child: &mut U, property_name: &str, value: &Value) {
+    unsafe {
+      use std::ffi::CString;
+      clutter_layout_manager_child_set_property(self.as_layout_manager(), container.as_actor(), child.as_actor(), property_name.to_c_str().unwrap() as *mut i8, value);
+    }
+  }
+
+  /// Emits the `layout-changed` signal on the manager.
+  ///
+  /// This function should only be called by implementations of the
+  /// LayoutManager trait.
+  ///
+  /// _Since 1.2_
+  fn layout_changed(&mut self) {
+    unsafe {
+      clutter_layout_manager_layout_changed(self.as_layout_manager());
+    }
+  }
+}
+
+impl LayoutManager for LayoutManagerRef {
+  fn as_layout_manager(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+/// Opaque struct which holds a reference to the underlying Clutter object.
+#[repr(C)]
+pub struct LayoutMetaRef {
+  opaque: *mut libc::c_void
+}
+
+/// Wrapper for actors inside a layout manager.
+///
+/// LayoutMeta is a wrapper object created by LayoutManager implementations
+/// in order to store child-specific data and properties.
+///
+/// A LayoutMeta wraps an Actor inside a container using the LayoutManager
+/// implementation.
+///
+/// _Since 1.2_
+pub trait LayoutMeta {
+  /// Returns a pointer to the the underlying C object.
+  ///
+  /// Generally only used internally.
+  fn as_layout_meta(&self) -> *mut libc::c_void;
+
+  /// Retrieves the actor wrapped by the meta.
+  ///
+  /// The actor is owned by its container.
+  ///
+  /// _Since 0.8_
+  fn get_actor(&mut self) -> super::actor::ActorHandle {
+    unsafe {
+      let foreign_result = clutter_child_meta_get_actor(self.as_layout_meta());
+      return std::mem::transmute(foreign_result);
+    }
+  }
+
+  /// Retrieves the container using the meta.
+  ///
+  /// The container is owned by the scene graph.
+  ///
+  /// _Since 0.8_
+  fn get_container(&mut self) -> super::actor::ActorHandle {
+    unsafe {
+      let foreign_result = clutter_child_meta_get_container(self.as_layout_meta());
+      return std::mem::transmute(foreign_result);
+    }
+  }
+
+  /// Retrieves the layout manager which created the meta.
+  ///
+  /// _Since 1.2_
+  fn get_manager(&mut self) -> LayoutManagerRef {
+    unsafe {
+      let foreign_result = clutter_layout_meta_get_manager(self.as_layout_meta());
+      return foreign_result;
+    }
+  }
+}
+
+impl LayoutMeta for LayoutMetaRef {
+  fn as_layout_meta(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+extern {
+  fn clutter_layout_manager_get_preferred_width(self_value: *mut libc::c_void, container: *mut libc::c_void, for_height: f32, min_width_p: *mut f32, nat_width_p: *mut f32);
+  fn clutter_layout_manager_get_preferred_height(self_value: *mut libc::c_void, container: *mut libc::c_void, for_width: f32, min_height_p: *mut f32, nat_height_p: *mut f32);
+  fn clutter_layout_manager_allocate(self_value: *mut libc::c_void, container: *mut libc::c_void, allocation: *const super::actor::Box, flags: super::actor::allocation::Flags);
+  fn clutter_layout_manager_child_get_property(self_value: *mut libc::c_void, container: *mut libc::c_void, actor: *mut libc::c_void, property_name: *mut libc::c_char, value: *mut Value);
+  fn clutter_layout_manager_child_set_property(self_value: *mut libc::c_void, container: *mut libc::c_void, actor: *mut libc::c_void, property_name: *mut libc::c_char, value: *const Value);
+  fn clutter_layout_manager_layout_changed(self_value: *mut libc::c_void);
+  fn clutter_layout_manager_get_child_meta(self_value: *mut libc::c_void, container: *mut libc::c_void, actor: *mut libc::c_void) -> LayoutMetaRef;
+  fn clutter_child_meta_get_actor(self_value: *mut libc::c_void) -> *mut libc::c_void;
+  fn clutter_child_meta_get_container(self_value: *mut libc::c_void) -> *mut libc::c_void;
+  fn clutter_layout_meta_get_manager(self_value: *mut libc::c_void) -> LayoutManagerRef;
+}
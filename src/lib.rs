@@ -36,15 +36,32 @@ extern crate libc;
 
 #[link(name = "gobject-2.0")] extern {}
 #[link(name = "clutter-1.0")] extern {}
+#[link(name = "cogl")] extern {}
 
+pub mod action;
 pub mod actor;
+pub mod animatable;
+pub mod animation;
+pub mod builder;
 pub mod canvas;
+pub mod clock;
+pub mod clone;
 pub mod color;
 pub mod constraint;
 pub mod content;
+mod ffi;
+pub mod geometry;
+pub mod interval;
+pub mod layout;
+pub mod matrix;
+pub mod path;
 pub mod scaling;
+pub mod scroll;
+pub mod script;
+pub mod signal;
 pub mod stage;
 pub mod text;
+pub mod value;
 
 /// Initialises everything needed to operate with Clutter.
 ///
@@ -0,0 +1,188 @@
+#![stable]
+
+use libc;
+use std;
+
+/// A 4x4 transformation matrix.
+///
+/// Matrix is the same structure as CoglMatrix: the 16 public values are
+/// stored in column-major order, so that `xw`, `yw` and `zw` hold the
+/// translation component.
+///
+/// Matrices are usually built by starting from `Matrix::identity()` and
+/// applying transformations in sequence; each transformation is multiplied
+/// on the right of the current matrix, so it is applied to points before the
+/// previous ones.
+///
+/// _Since 1.12_
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Matrix {
+  pub xx: f32,
+  pub yx: f32,
+  pub zx: f32,
+  pub wx: f32,
+
+  pub xy: f32,
+  pub yy: f32,
+  pub zy: f32,
+  pub wy: f32,
+
+  pub xz: f32,
+  pub yz: f32,
+  pub zz: f32,
+  pub wz: f32,
+
+  pub xw: f32,
+  pub yw: f32,
+  pub zw: f32,
+  pub ww: f32,
+
+  inv: [f32; 16],
+  matrix_type: libc::c_ulong,
+  flags: libc::c_ulong,
+  padding: libc::c_ulong
+}
+
+impl Matrix {
+  /// Creates a new identity matrix.
+  ///
+  /// _Since 1.12_
+  pub fn identity() -> Matrix {
+    unsafe {
+      let mut matrix: Matrix = std::intrinsics::init();
+      cogl_matrix_init_identity(&mut matrix);
+      return matrix;
+    }
+  }
+
+  /// Creates a new matrix from 16 values, in column-major order.
+  ///
+  /// _Since 1.12_
+  pub fn from_array(array: &[f32; 16]) -> Matrix {
+    unsafe {
+      let mut matrix: Matrix = std::intrinsics::init();
+      cogl_matrix_init_from_array(&mut matrix, array.as_ptr());
+      return matrix;
+    }
+  }
+
+  /// Returns the 16 values of the matrix, in column-major order.
+  ///
+  /// _Since 1.12_
+  pub fn to_array(&self) -> [f32; 16] {
+    return [self.xx, self.yx, self.zx, self.wx,
+            self.xy, self.yy, self.zy, self.wy,
+            self.xz, self.yz, self.zz, self.wz,
+            self.xw, self.yw, self.zw, self.ww];
+  }
+
+  /// Checks whether the matrix is an identity matrix.
+  ///
+  /// _Since 1.12_
+  pub fn is_identity(&self) -> bool {
+    unsafe {
+      let foreign_result = cogl_matrix_is_identity(self);
+      return foreign_result != 0;
+    }
+  }
+
+  /// Multiplies the matrix with a transform matrix that translates along the
+  /// X, Y and Z axis.
+  ///
+  /// _Since 1.12_
+  pub fn translate(&mut self, x: f32, y: f32, z: f32) {
+    unsafe {
+      cogl_matrix_translate(self, x, y, z);
+    }
+  }
+
+  /// Multiplies the matrix with a transform matrix that scales along the X,
+  /// Y and Z axis.
+  ///
+  /// _Since 1.12_
+  pub fn scale(&mut self, sx: f32, sy: f32, sz: f32) {
+    unsafe {
+      cogl_matrix_scale(self, sx, sy, sz);
+    }
+  }
+
+  /// Multiplies the matrix with a rotation matrix that applies a rotation of
+  /// `angle` degrees around the specified 3D vector.
+  ///
+  /// _Since 1.12_
+  pub fn rotate(&mut self, angle: f32, x: f32, y: f32, z: f32) {
+    unsafe {
+      cogl_matrix_rotate(self, angle, x, y, z);
+    }
+  }
+
+  /// Multiplies this matrix by `other` and returns the result.
+  ///
+  /// The resulting matrix applies `other` to points first, then this matrix.
+  ///
+  /// _Since 1.12_
+  pub fn multiply(&self, other: &Matrix) -> Matrix {
+    unsafe {
+      let mut result: Matrix = std::intrinsics::init();
+      cogl_matrix_multiply(&mut result, self, other);
+      return result;
+    }
+  }
+
+  /// Gets the inverse transform of the matrix.
+  ///
+  /// Returns __None__ if the matrix is not invertible, for instance if it
+  /// scales by zero on one of the axes.
+  ///
+  /// _Since 1.12_
+  pub fn invert(&self) -> Option<Matrix> {
+    unsafe {
+      let mut inverse: Matrix = std::intrinsics::init();
+      let foreign_result = cogl_matrix_get_inverse(self, &mut inverse);
+      if foreign_result == 0 {
+        return None;
+      }
+
+      return Some(inverse);
+    }
+  }
+
+  /// Transforms a point whose position is given and returned as four float
+  /// components, `(x, y, z, w)`.
+  ///
+  /// _Since 1.12_
+  pub fn transform_point(&self, x: f32, y: f32, z: f32, w: f32) -> (f32, f32, f32, f32) {
+    unsafe {
+      let mut x_out = x;
+      let mut y_out = y;
+      let mut z_out = z;
+      let mut w_out = w;
+      cogl_matrix_transform_point(self, &mut x_out, &mut y_out, &mut z_out, &mut w_out);
+      return (x_out, y_out, z_out, w_out);
+    }
+  }
+}
+
+impl std::cmp::PartialEq for Matrix {
+  /// Compares two matrices to see if they represent the same transformation.
+  fn eq(&self, other: &Matrix) -> bool {
+    unsafe {
+      let foreign_result = cogl_matrix_equal(self, other);
+      return foreign_result != 0;
+    }
+  }
+}
+
+extern {
+  fn cogl_matrix_init_identity(matrix: *mut Matrix);
+  fn cogl_matrix_init_from_array(matrix: *mut Matrix, array: *const f32);
+  fn cogl_matrix_is_identity(matrix: *const Matrix) -> i32;
+  fn cogl_matrix_translate(matrix: *mut Matrix, x: f32, y: f32, z: f32);
+  fn cogl_matrix_scale(matrix: *mut Matrix, sx: f32, sy: f32, sz: f32);
+  fn cogl_matrix_rotate(matrix: *mut Matrix, angle: f32, x: f32, y: f32, z: f32);
+  fn cogl_matrix_multiply(result: *mut Matrix, a: *const Matrix, b: *const Matrix);
+  fn cogl_matrix_get_inverse(matrix: *const Matrix, inverse: *mut Matrix) -> i32;
+  fn cogl_matrix_transform_point(matrix: *const Matrix, x: *mut f32, y: *mut f32, z: *mut f32, w: *mut f32);
+  fn cogl_matrix_equal(v1: *const Matrix, v2: *const Matrix) -> i32;
+}
@@ -0,0 +1,282 @@
+#![stable]
+
+use libc;
+use std;
+
+/// Point in a path.
+///
+/// _Since 0.2_
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Knot {
+  /// X coordinate of the knot
+  pub x: i32,
+
+  /// Y coordinate of the knot
+  pub y: i32
+}
+
+/// Opaque struct which holds a reference to the underlying Clutter object.
+#[repr(C)]
+pub struct PathRef {
+  opaque: *mut libc::c_void
+}
+
+impl PathRef {
+  /// Creates a new, empty Path.
+  ///
+  /// _Since 1.0_
+  pub fn new() -> PathRef {
+    unsafe {
+      let foreign_result = clutter_path_new();
+      return foreign_result;
+    }
+  }
+
+  /// Creates a new Path instance with the nodes described in `desc`.
+  ///
+  /// See `.add_string()` for details of the format of the string.
+  ///
+  /// _Since 1.0_
+  pub fn new_with_description(desc: &str) -> PathRef {
+    unsafe {
+      use std::ffi::CString;
+      let foreign_result = clutter_path_new_with_description(desc.to_c_str().unwrap() as *mut i8);
+      return foreign_result;
+    }
+  }
+}
+
+/// An object describing a path with straight lines and bezier curves.
+///
+/// A Path contains a description of a path consisting of straight lines and
+/// bezier curves. This can be used in a PathConstraint to make actors follow
+/// the path.
+///
+/// The path consists of a series of nodes. Each node is one of the following
+/// four types:
+///
+/// - move-to: changes the position of the path to the given pair of
+///   coordinates, without drawing a line
+/// - line-to: draws a straight line from the current position to the given
+///   pair of coordinates
+/// - curve-to: draws a bezier curve from the current position to the last of
+///   three pairs of coordinates, using the first two as control points
+/// - close: draws a straight line back to the position of the last move-to
+///
+/// Each node also has a relative variant, whose coordinates are added to the
+/// current position of the path.
+///
+/// The path can also be described with an SVG-style string, as described in
+/// `.add_string()`.
+///
+/// _Since 1.0_
+pub trait Path {
+  /// Returns a pointer to the the underlying C object.
+  ///
+  /// Generally only used internally.
+  fn as_path(&self) -> *mut libc::c_void;
+
+  /// Adds a move-to node to the path.
+  ///
+  /// This causes the actor to jump to the given coordinates.
+  ///
+  /// _Since 1.0_
+  fn add_move_to(&mut self, x: i32, y: i32) {
+    unsafe {
+      clutter_path_add_move_to(self.as_path(), x, y);
+    }
+  }
+
+  /// Same as `.add_move_to()` except the coordinates are relative to the
+  /// previous node.
+  ///
+  /// _Since 1.0_
+  fn add_rel_move_to(&mut self, x: i32, y: i32) {
+    unsafe {
+      clutter_path_add_rel_move_to(self.as_path(), x, y);
+    }
+  }
+
+  /// Adds a line-to node to the path.
+  ///
+  /// This causes the actor to move to the new coordinates in a straight line.
+  ///
+  /// _Since 1.0_
+  fn add_line_to(&mut self, x: i32, y: i32) {
+    unsafe {
+      clutter_path_add_line_to(self.as_path(), x, y);
+    }
+  }
+
+  /// Same as `.add_line_to()` except the coordinates are relative to the
+  /// previous node.
+  ///
+  /// _Since 1.0_
+  fn add_rel_line_to(&mut self, x: i32, y: i32) {
+    unsafe {
+      clutter_path_add_rel_line_to(self.as_path(), x, y);
+    }
+  }
+
+  /// Adds a curve-to node to the path.
+  ///
+  /// This causes the actor to follow a bezier from the last node to
+  /// (`x_3`, `y_3`) using (`x_1`, `y_1`) and (`x_2`, `y_2`) as control points.
+  ///
+  /// _Since 1.0_
+  fn add_curve_to(&mut self, x_1: i32, y_1: i32, x_2: i32, y_2: i32, x_3: i32, y_3: i32) {
+    unsafe {
+      clutter_path_add_curve_to(self.as_path(), x_1, y_1, x_2, y_2, x_3, y_3);
+    }
+  }
+
+  /// Same as `.add_curve_to()` except the coordinates are relative to the
+  /// previous node.
+  ///
+  /// _Since 1.0_
+  fn add_rel_curve_to(&mut self, x_1: i32, y_1: i32, x_2: i32, y_2: i32, x_3: i32, y_3: i32) {
+    unsafe {
+      clutter_path_add_rel_curve_to(self.as_path(), x_1, y_1, x_2, y_2, x_3, y_3);
+    }
+  }
+
+  /// Adds a close node to the path.
+  ///
+  /// This creates a straight line from the last node to the last move-to
+  /// node.
+  ///
+  /// _Since 1.0_
+  fn add_close(&mut self) {
+    unsafe {
+      clutter_path_add_close(self.as_path());
+    }
+  }
+
+  /// Adds new nodes to the end of the path as described in `string`.
+  ///
+  /// The format is a subset of the SVG path format. Each node is represented
+  /// by a letter and is followed by zero, one or three pairs of coordinates.
+  /// The coordinates can be separated by spaces or a comma. The types are:
+  ///
+  /// - `M`: Adds a move-to node. Takes one pair of coordinates.
+  /// - `L`: Adds a line-to node. Takes one pair of coordinates.
+  /// - `C`: Adds a curve-to node. Takes three pairs of coordinates.
+  /// - `z`: Adds a close node. No coordinates are needed.
+  ///
+  /// The M, L and C commands can also be specified in lower case which means
+  /// the coordinates are relative to the previous node.
+  ///
+  /// For example, to move an actor in a 100 by 100 pixel square centered on
+  /// the point 300,300 you could use the following path:
+  ///
+  /// ```text
+  /// M 250,350 l 0 -100 L 350,250 l 0 100 z
+  /// ```
+  ///
+  /// If the path description isn't valid __false__ will be returned and no
+  /// nodes will be added.
+  ///
+  /// _Since 1.0_
+  fn add_string(&mut self, string: &str) -> bool {
+    unsafe {
+      use std::ffi::CString;
+      let foreign_result = clutter_path_add_string(self.as_path(), string.to_c_str().unwrap() as *mut i8);
+      return foreign_result != 0;
+    }
+  }
+
+  /// Replaces all of the nodes in the path with nodes described by `string`.
+  ///
+  /// See `.add_string()` for details of the format.
+  ///
+  /// If the string is invalid then __false__ is returned and the path is
+  /// unaltered.
+  ///
+  /// _Since 1.0_
+  fn set_description(&mut self, string: &str) -> bool {
+    unsafe {
+      use std::ffi::CString;
+      let foreign_result = clutter_path_set_description(self.as_path(), string.to_c_str().unwrap() as *mut i8);
+      return foreign_result != 0;
+    }
+  }
+
+  /// Returns a newly allocated string describing the path in the same format
+  /// as used by `.add_string()`.
+  ///
+  /// _Since 1.0_
+  fn get_description(&mut self) -> std::ffi::CString {
+    unsafe {
+      let foreign_result = clutter_path_get_description(self.as_path());
+      return std::ffi::CString::new(foreign_result as *const i8, true);
+    }
+  }
+
+  /// Removes all nodes from the path.
+  ///
+  /// _Since 1.0_
+  fn clear(&mut self) {
+    unsafe {
+      clutter_path_clear(self.as_path());
+    }
+  }
+
+  /// Retrieves the number of nodes in the path.
+  ///
+  /// _Since 1.0_
+  fn get_n_nodes(&mut self) -> u32 {
+    unsafe {
+      let foreign_result = clutter_path_get_n_nodes(self.as_path());
+      return foreign_result;
+    }
+  }
+
+  /// Retrieves an approximation of the total length of the path.
+  ///
+  /// _Since 1.0_
+  fn get_length(&mut self) -> u32 {
+    unsafe {
+      let foreign_result = clutter_path_get_length(self.as_path());
+      return foreign_result;
+    }
+  }
+
+  /// The value in `progress` represents a position along the path where 0.0
+  /// is the beginning and 1.0 is the end of the path. An interpolated
+  /// position is then returned.
+  ///
+  /// _Since 1.0_
+  fn get_position(&mut self, progress: f64) -> Knot {
+    unsafe {
+      let mut position:Knot = std::intrinsics::init();
+      clutter_path_get_position(self.as_path(), progress, &mut position);
+      return position;
+    }
+  }
+}
+
+impl Path for PathRef {
+  fn as_path(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+extern {
+  fn clutter_path_new() -> PathRef;
+  fn clutter_path_new_with_description(desc: *mut libc::c_char) -> PathRef;
+  fn clutter_path_add_move_to(self_value: *mut libc::c_void, x: i32, y: i32);
+  fn clutter_path_add_rel_move_to(self_value: *mut libc::c_void, x: i32, y: i32);
+  fn clutter_path_add_line_to(self_value: *mut libc::c_void, x: i32, y: i32);
+  fn clutter_path_add_rel_line_to(self_value: *mut libc::c_void, x: i32, y: i32);
+  fn clutter_path_add_curve_to(self_value: *mut libc::c_void, x_1: i32, y_1: i32, x_2: i32, y_2: i32, x_3: i32, y_3: i32);
+  fn clutter_path_add_rel_curve_to(self_value: *mut libc::c_void, x_1: i32, y_1: i32, x_2: i32, y_2: i32, x_3: i32, y_3: i32);
+  fn clutter_path_add_close(self_value: *mut libc::c_void);
+  fn clutter_path_add_string(self_value: *mut libc::c_void, string: *mut libc::c_char) -> i32;
+  fn clutter_path_set_description(self_value: *mut libc::c_void, string: *mut libc::c_char) -> i32;
+  fn clutter_path_get_description(self_value: *mut libc::c_void) -> *mut i8;
+  fn clutter_path_clear(self_value: *mut libc::c_void);
+  fn clutter_path_get_n_nodes(self_value: *mut libc::c_void) -> u32;
+  fn clutter_path_get_length(self_value: *mut libc::c_void) -> u32;
+  fn clutter_path_get_position(self_value: *mut libc::c_void, progress: f64, position: *mut Knot) -> u32;
+}
@@ -0,0 +1,379 @@
+#![stable]
+
+use libc;
+use std;
+use std::collections::HashMap;
+use std::rc::Rc;
+use super::actor::ActorHandle;
+use super::clone::CloneRef;
+use super::constraint::ConstraintRef;
+use super::ffi::{c_str_to_string, take_list, type_name_of, List};
+use super::layout::LayoutManagerRef;
+use super::stage::StageRef;
+use super::text::TextRef;
+use super::signal::{self, Handler};
+use super::value::Type;
+
+/// The errors reported by a Script.
+#[derive(Clone, PartialEq, Debug)]
+pub enum ScriptError {
+  /// The UI definition could not be read or parsed; holds the GError code
+  /// and message
+  Load(i32, String),
+
+  /// No object with the given id exists in the script
+  UnknownObject(String),
+
+  /// The object with the given id is of the type named in the second field,
+  /// which is not compatible with the requested type
+  WrongType(String, String),
+
+  /// The script refers to signal handlers missing from the SignalHandlers
+  UnknownHandlers(Vec<String>)
+}
+
+/// A type that can be retrieved from a Script with `Script#get_object()`.
+pub trait ScriptObject {
+  /// Returns the type objects must be instances of.
+  fn object_type() -> Type;
+
+  /// Wraps a pointer to an object of type `object_type()`.
+  ///
+  /// The object is still owned by the script.
+  unsafe fn from_object(object: *mut libc::c_void) -> Self;
+}
+
+impl ScriptObject for ActorHandle {
+  fn object_type() -> Type {
+    unsafe {
+      return clutter_actor_get_type();
+    }
+  }
+
+  unsafe fn from_object(object: *mut libc::c_void) -> ActorHandle {
+    return std::mem::transmute(object);
+  }
+}
+
+impl ScriptObject for TextRef {
+  fn object_type() -> Type {
+    unsafe {
+      return clutter_text_get_type();
+    }
+  }
+
+  unsafe fn from_object(object: *mut libc::c_void) -> TextRef {
+    return std::mem::transmute(object);
+  }
+}
+
+impl ScriptObject for StageRef {
+  fn object_type() -> Type {
+    unsafe {
+      return clutter_stage_get_type();
+    }
+  }
+
+  unsafe fn from_object(object: *mut libc::c_void) -> StageRef {
+    return std::mem::transmute(object);
+  }
+}
+
+impl ScriptObject for CloneRef {
+  fn object_type() -> Type {
+    unsafe {
+      return clutter_clone_get_type();
+    }
+  }
+
+  unsafe fn from_object(object: *mut libc::c_void) -> CloneRef {
+    return std::mem::transmute(object);
+  }
+}
+
+impl ScriptObject for LayoutManagerRef {
+  fn object_type() -> Type {
+    unsafe {
+      return clutter_layout_manager_get_type();
+    }
+  }
+
+  unsafe fn from_object(object: *mut libc::c_void) -> LayoutManagerRef {
+    return std::mem::transmute(object);
+  }
+}
+
+impl ScriptObject for ConstraintRef {
+  fn object_type() -> Type {
+    unsafe {
+      return clutter_constraint_get_type();
+    }
+  }
+
+  unsafe fn from_object(object: *mut libc::c_void) -> ConstraintRef {
+    return std::mem::transmute(object);
+  }
+}
+
+/// A set of named signal handlers, used by `Script#connect_signals()`.
+pub struct SignalHandlers {
+  handlers: HashMap<String, Rc<Handler>>
+}
+
+impl SignalHandlers {
+  /// Creates an empty set of handlers.
+  pub fn new() -> SignalHandlers {
+    return SignalHandlers { handlers: HashMap::new() };
+  }
+
+  /// Registers `handler` under `name`, which is the value of the `handler`
+  /// member of a signal definition in the script.
+  pub fn add(&mut self, name: &str, handler: Handler) {
+    self.handlers.insert(name.to_string(), Rc::new(handler));
+  }
+}
+
+/// Opaque struct which holds a reference to the underlying Clutter object.
+#[repr(C)]
+pub struct ScriptRef {
+  opaque: *mut libc::c_void
+}
+
+impl ScriptRef {
+  /// Creates a new Script instance.
+  ///
+  /// Script can be used to load objects definitions for scenegraph elements,
+  /// like actors, or behavioural elements, like transitions. Once a Script
+  /// has loaded the definitions, it owns the objects it creates.
+  ///
+  /// _Since 0.6_
+  pub fn new() -> ScriptRef {
+    unsafe {
+      let foreign_result = clutter_script_new();
+      return foreign_result;
+    }
+  }
+}
+
+/// Loads objects from UI definition data.
+///
+/// Script parses JSON data describing objects, and builds them; each object
+/// with an `id` member can then be retrieved with `.get_object()`. For
+/// instance:
+///
+/// ```text
+/// {
+///   "id" : "red-button",
+///   "type" : "ClutterActor",
+///   "width" : 100,
+///   "height" : 100,
+///   "background-color" : "#ff0000ff",
+///   "signals" : [
+///     { "name" : "button-press-event", "handler" : "on_button_press" }
+///   ]
+/// }
+/// ```
+///
+/// Signal handlers named in the definitions are connected with
+/// `.connect_signals()`.
+///
+/// _Since 0.6_
+pub trait Script {
+  /// Returns a pointer to the the underlying C object.
+  ///
+  /// Generally only used internally.
+  fn as_script(&self) -> *mut libc::c_void;
+
+  /// Loads the definitions from `filename` and merges them with the objects
+  /// already loaded.
+  ///
+  /// Returns the merge id, which can be passed to `.unmerge_objects()`.
+  ///
+  /// _Since 0.6_
+  fn load_from_file(&mut self, filename: &str) -> Result<u32, ScriptError> {
+    unsafe {
+      let mut error: *mut GError = std::ptr::null_mut();
+      let foreign_result = clutter_script_load_from_file(self.as_script(), filename.to_c_str().unwrap() as *mut i8, &mut error);
+      return check_load(foreign_result, error);
+    }
+  }
+
+  /// Loads the definitions from `data` and merges them with the objects
+  /// already loaded.
+  ///
+  /// Returns the merge id, which can be passed to `.unmerge_objects()`.
+  ///
+  /// _Since 0.6_
+  fn load_from_data(&mut self, data: &str) -> Result<u32, ScriptError> {
+    unsafe {
+      let mut error: *mut GError = std::ptr::null_mut();
+      let foreign_result = clutter_script_load_from_data(self.as_script(), data.as_ptr() as *const i8, data.len() as libc::ssize_t, &mut error);
+      return check_load(foreign_result, error);
+    }
+  }
+
+  /// Retrieves the object bound to `name`, checking that it is an instance
+  /// of the type of `T`.
+  ///
+  /// The object is still owned by the script.
+  ///
+  /// _Since 0.6_
+  fn get_object<T: ScriptObject>(&mut self, name: &str) -> Result<T, ScriptError> {
+    unsafe {
+      let object = clutter_script_get_object(self.as_script(), name.to_c_str().unwrap() as *mut i8);
+      if object.is_null() {
+        return Err(ScriptError::UnknownObject(name.to_string()));
+      }
+
+      if g_type_check_instance_is_a(object, T::object_type()) == 0 {
+        return Err(ScriptError::WrongType(name.to_string(), type_name_of(object)));
+      }
+
+      return Ok(T::from_object(object));
+    }
+  }
+
+  /// Unmerges the objects identified by `merge_id`, as returned by
+  /// `.load_from_file()` or `.load_from_data()`.
+  ///
+  /// _Since 0.6_
+  fn unmerge_objects(&mut self, merge_id: u32) {
+    unsafe {
+      clutter_script_unmerge_objects(self.as_script(), merge_id);
+    }
+  }
+
+  /// Retrieves the ids of all the objects created by the script.
+  ///
+  /// Objects without an `id` member get an id generated by Clutter.
+  ///
+  /// _Since 0.8_
+  fn list_objects(&mut self) -> Vec<String> {
+    unsafe {
+      let mut ids = Vec::new();
+      for object in take_list(clutter_script_list_objects(self.as_script())).into_iter() {
+        let id = clutter_get_script_id(object);
+        if !id.is_null() {
+          ids.push(c_str_to_string(id));
+        }
+      }
+
+      return ids;
+    }
+  }
+
+  /// Connects all the signals defined in the script to the handlers
+  /// registered in `handlers` under the same name.
+  ///
+  /// Signals whose handler is not registered are not connected; their
+  /// handler names are returned in a `ScriptError::UnknownHandlers`.
+  ///
+  /// A signal definition naming an `object` is only connected while that
+  /// object is alive; if it is also `swapped`, the handler receives the
+  /// object in place of the emitter, and the emitter after the arguments
+  /// of the signal. Without an `object`, `swapped` has no effect, as the
+  /// handlers take no user data.
+  ///
+  /// Clutter discards the signal definitions as it connects them, so the
+  /// handler names cannot be checked beforehand: when an error is returned,
+  /// the signals whose handler is registered have still been connected, and
+  /// the others are not retried by a later call. Register every handler the
+  /// script names before calling this method.
+  ///
+  /// _Since 0.6_
+  fn connect_signals(&mut self, handlers: &SignalHandlers) -> Result<(), ScriptError> {
+    unsafe {
+      let mut state = ConnectState { handlers: handlers, missing: Vec::new() };
+      clutter_script_connect_signals_full(self.as_script(), connect_signal, std::mem::transmute(&mut state));
+      if state.missing.is_empty() {
+        return Ok(());
+      }
+
+      return Err(ScriptError::UnknownHandlers(state.missing));
+    }
+  }
+}
+
+impl Script for ScriptRef {
+  fn as_script(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+impl std::ops::Drop for ScriptRef {
+  /// Releases the script, and with it the objects it created that are not
+  /// referenced elsewhere.
+  fn drop(&mut self) {
+    unsafe {
+      g_object_unref(self.opaque);
+    }
+  }
+}
+
+#[repr(C)]
+struct GError {
+  domain: u32,
+  code: i32,
+  message: *const libc::c_char
+}
+
+struct ConnectState<'a> {
+  handlers: &'a SignalHandlers,
+  missing: Vec<String>
+}
+
+const CONNECT_AFTER: u32 = 1;
+const CONNECT_SWAPPED: u32 = 2;
+
+unsafe fn check_load(merge_id: u32, error: *mut GError) -> Result<u32, ScriptError> {
+  if error.is_null() {
+    return Ok(merge_id);
+  }
+
+  let result = ScriptError::Load((*error).code, c_str_to_string((*error).message));
+  g_error_free(error);
+  return Err(result);
+}
+
+extern "C" fn connect_signal(_script: *mut libc::c_void, object: *mut libc::c_void, signal_name: *const libc::c_char, handler_name: *const libc::c_char, connect_object: *mut libc::c_void, flags: u32, user_data: *mut libc::c_void) {
+  unsafe {
+    let state: &mut ConnectState = std::mem::transmute(user_data);
+    let name = c_str_to_string(handler_name);
+    let handler = match state.handlers.handlers.get(&name) {
+      Some(handler) => handler.clone(),
+      None => {
+        if !state.missing.contains(&name) {
+          state.missing.push(name);
+        }
+        return;
+      }
+    };
+
+    let after = (flags & CONNECT_AFTER) != 0;
+    if connect_object.is_null() {
+      signal::connect_raw(object, signal_name, handler, after);
+    } else {
+      signal::connect_object_raw(object, signal_name, handler, connect_object, (flags & CONNECT_SWAPPED) != 0, after);
+    }
+  }
+}
+
+extern {
+  fn clutter_script_new() -> ScriptRef;
+  fn clutter_script_load_from_file(self_value: *mut libc::c_void, filename: *mut libc::c_char, error: *mut *mut GError) -> u32;
+  fn clutter_script_load_from_data(self_value: *mut libc::c_void, data: *const libc::c_char, length: libc::ssize_t, error: *mut *mut GError) -> u32;
+  fn clutter_script_get_object(self_value: *mut libc::c_void, name: *mut libc::c_char) -> *mut libc::c_void;
+  fn clutter_script_unmerge_objects(self_value: *mut libc::c_void, merge_id: u32);
+  fn clutter_script_list_objects(self_value: *mut libc::c_void) -> *mut List;
+  fn clutter_script_connect_signals_full(self_value: *mut libc::c_void, func: extern "C" fn(*mut libc::c_void, *mut libc::c_void, *const libc::c_char, *const libc::c_char, *mut libc::c_void, u32, *mut libc::c_void), user_data: *mut libc::c_void);
+  fn clutter_get_script_id(gobject: *mut libc::c_void) -> *const libc::c_char;
+  fn clutter_actor_get_type() -> Type;
+  fn clutter_text_get_type() -> Type;
+  fn clutter_stage_get_type() -> Type;
+  fn clutter_clone_get_type() -> Type;
+  fn clutter_layout_manager_get_type() -> Type;
+  fn clutter_constraint_get_type() -> Type;
+  fn g_type_check_instance_is_a(instance: *mut libc::c_void, iface_type: Type) -> i32;
+  fn g_error_free(error: *mut GError);
+  fn g_object_unref(object: *mut libc::c_void);
+}
@@ -0,0 +1,671 @@
+#![stable]
+
+use libc;
+use std;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::rc::Rc;
+use super::Mode;
+use super::view::ScrollView;
+use super::super::actor::{Actor, ActorHandle, ActorRef, FromActor};
+use super::super::geometry::{Point, Rect, Size};
+
+/// A change in the items of a DataSource.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Change {
+  /// `count` items were inserted at `position`, as (position, count)
+  Inserted(usize, usize),
+
+  /// `count` items were removed at `position`, as (position, count)
+  Removed(usize, usize),
+
+  /// `count` items changed at `position`, as (position, count)
+  Updated(usize, usize),
+
+  /// Any number of items changed
+  Reset
+}
+
+/// Receives the changes of a DataSource.
+///
+/// A ListView gives an Observer to its source with
+/// `DataSource#set_observer()`; the source then reports each change to its
+/// items, after making it.
+#[derive(Clone)]
+pub struct Observer {
+  notify: Rc<std::boxed::Box<Fn(Change) + 'static>>
+}
+
+impl Observer {
+  /// Creates an Observer calling `notify` for each change.
+  pub fn new(notify: std::boxed::Box<Fn(Change) + 'static>) -> Observer {
+    return Observer { notify: Rc::new(notify) };
+  }
+
+  /// Reports `change`.
+  pub fn notify(&self, change: Change) {
+    (**self.notify)(change);
+  }
+
+  /// Reports that `count` items were inserted at `position`.
+  pub fn inserted(&self, position: usize, count: usize) {
+    self.notify(Change::Inserted(position, count));
+  }
+
+  /// Reports that `count` items were removed at `position`.
+  pub fn removed(&self, position: usize, count: usize) {
+    self.notify(Change::Removed(position, count));
+  }
+
+  /// Reports that `count` items changed at `position`.
+  pub fn updated(&self, position: usize, count: usize) {
+    self.notify(Change::Updated(position, count));
+  }
+
+  /// Reports that any number of items changed.
+  pub fn reset(&self) {
+    self.notify(Change::Reset);
+  }
+}
+
+/// The items displayed by a ListView.
+pub trait DataSource {
+  /// The type of the items, as passed to `Factory#bind()`.
+  type Item;
+
+  /// Returns the number of items.
+  fn count(&self) -> usize;
+
+  /// Returns the item at `index`, which is lower than `.count()`.
+  fn item_at(&self, index: usize) -> Self::Item;
+
+  /// Sets the observer to which changes must be reported, replacing the
+  /// previous one.
+  fn set_observer(&mut self, observer: Observer);
+}
+
+/// A DataSource holding its items in a Vec.
+pub struct VecSource<T> {
+  items: Vec<T>,
+  observer: Option<Observer>
+}
+
+impl<T: Clone> VecSource<T> {
+  /// Creates a source holding `items`.
+  pub fn new(items: Vec<T>) -> VecSource<T> {
+    return VecSource { items: items, observer: None };
+  }
+
+  /// Returns the items.
+  pub fn items(&self) -> &[T] {
+    return self.items.as_slice();
+  }
+
+  /// Appends `item`.
+  pub fn push(&mut self, item: T) {
+    let position = self.items.len();
+    self.items.push(item);
+    self.notify(Change::Inserted(position, 1));
+  }
+
+  /// Inserts `item` at `index`.
+  pub fn insert(&mut self, index: usize, item: T) {
+    self.items.insert(index, item);
+    self.notify(Change::Inserted(index, 1));
+  }
+
+  /// Removes and returns the item at `index`.
+  pub fn remove(&mut self, index: usize) -> T {
+    let item = self.items.remove(index);
+    self.notify(Change::Removed(index, 1));
+    return item;
+  }
+
+  /// Replaces the item at `index`.
+  pub fn set(&mut self, index: usize, item: T) {
+    self.items[index] = item;
+    self.notify(Change::Updated(index, 1));
+  }
+
+  /// Replaces all the items.
+  pub fn replace(&mut self, items: Vec<T>) {
+    self.items = items;
+    self.notify(Change::Reset);
+  }
+
+  fn notify(&self, change: Change) {
+    if let Some(ref observer) = self.observer {
+      observer.notify(change);
+    }
+  }
+}
+
+impl<T: Clone> DataSource for VecSource<T> {
+  type Item = T;
+
+  fn count(&self) -> usize {
+    return self.items.len();
+  }
+
+  fn item_at(&self, index: usize) -> T {
+    return self.items[index].clone();
+  }
+
+  fn set_observer(&mut self, observer: Observer) {
+    self.observer = Some(observer);
+  }
+}
+
+/// Creates the actors of a ListView and binds items to them.
+///
+/// Actors are recycled: once created, an actor is bound to an item each
+/// time it is scrolled into view, and unbound when it is scrolled out, so
+/// `.bind()` must set everything that depends on the item.
+pub trait Factory<T> {
+  /// The type of the actors; a ListView only keeps pointers to them, and
+  /// gets them back with `FromActor::from_actor()`.
+  type Actor: Actor + FromActor;
+
+  /// Creates an actor, not yet bound to any item.
+  fn create(&mut self) -> Self::Actor;
+
+  /// Binds `item`, at `index` in the source, to `actor`.
+  fn bind(&mut self, actor: &mut Self::Actor, item: T, index: usize);
+
+  /// Unbinds `actor` from its item, before it is hidden for later reuse.
+  fn unbind(&mut self, _actor: &mut Self::Actor) {
+  }
+}
+
+/// How a ListView lays out its items; all the items have the same size.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Layout {
+  /// One item per row, as wide as the view, with the given height
+  List(f32),
+
+  /// Items of the given size, in rows of as many items as fit in the width
+  /// of the view; items without width are laid out in a single column
+  Grid(Size)
+}
+
+/// A scrollable list or grid of items, which only creates the actors of the
+/// visible items.
+///
+/// The ListView gets its items from a DataSource, and their actors from a
+/// Factory. It keeps actors for the items in view, plus one row above and
+/// below; when it scrolls, the actors of the items leaving the view are
+/// unbound and reused for the items entering it, so the number of actors
+/// depends on the size of the view, not on the number of items.
+///
+/// The view scrolls vertically, with the kinetic scrolling of ScrollView.
+pub struct ListView<S: DataSource, F: Factory<S::Item>> {
+  view: ScrollView,
+  inner: Rc<Inner<S, F>>
+}
+
+struct Inner<S: DataSource, F: Factory<S::Item>> {
+  source: RefCell<S>,
+  state: RefCell<State<F>>,
+  pending: RefCell<Vec<Change>>,
+  busy: Cell<bool>,
+  resize_queued: Cell<bool>
+}
+
+struct State<F> {
+  factory: F,
+  layout: Layout,
+  spacing: f32,
+  offset: Point,
+  size: Size,
+  content: *mut libc::c_void,
+  active: HashMap<usize, *mut libc::c_void>,
+  pool: Vec<*mut libc::c_void>
+}
+
+impl<S: DataSource + 'static, F: Factory<S::Item> + 'static> ListView<S, F> {
+  /// Creates a view of the items of `source`, whose actors are created by
+  /// `factory` and laid out according to `layout`.
+  pub fn new(source: S, factory: F, layout: Layout) -> ListView<S, F> {
+    let view = ScrollView::new();
+    view.set_scroll_mode(Mode::Vertically);
+
+    let content = ActorRef::new().into_handle();
+    view.set_content(content);
+
+    let state = State {
+      factory: factory,
+      layout: layout,
+      spacing: 0.0,
+      offset: Point::new(0.0, 0.0),
+      size: Size::new(0.0, 0.0),
+      content: content.as_actor(),
+      active: HashMap::new(),
+      pool: Vec::new()
+    };
+
+    let inner = Rc::new(Inner {
+      source: RefCell::new(source),
+      state: RefCell::new(state),
+      pending: RefCell::new(Vec::new()),
+      busy: Cell::new(false),
+      resize_queued: Cell::new(false)
+    });
+
+    let weak = inner.downgrade();
+    inner.source.borrow_mut().set_observer(Observer::new(std::boxed::Box::new(move |change: Change| {
+      if let Some(inner) = weak.upgrade() {
+        inner.pending.borrow_mut().push(change);
+        if !inner.busy.get() {
+          inner.flush();
+        }
+      }
+    })));
+
+    // The listener keeps the state alive as long as the view
+    let listened = inner.clone();
+    view.on_scrolled(std::boxed::Box::new(move |offset: &Point, size: &Size| {
+      Inner::scrolled(&listened, offset, size);
+    }));
+
+    return ListView { view: view, inner: inner };
+  }
+
+  /// Calls `f` with the source, to modify it.
+  ///
+  /// Changes reported by the source while `f` runs are applied once it
+  /// returns, and the view is then scrolled back within the bounds of the
+  /// items if needed.
+  pub fn with_source<R, G: FnOnce(&mut S) -> R>(&self, f: G) -> R {
+    self.inner.busy.set(true);
+    let result = f(&mut *self.inner.source.borrow_mut());
+    self.inner.busy.set(false);
+    self.inner.flush();
+
+    let offset = self.view.get_offset();
+    self.view.scroll_to_point(&offset, false);
+    return result;
+  }
+
+  /// Calls `f` with the factory, for instance to change how it binds items;
+  /// call `.refresh()` afterwards to bind the visible items again.
+  pub fn with_factory<R, G: FnOnce(&mut F) -> R>(&self, f: G) -> R {
+    return f(&mut self.inner.state.borrow_mut().factory);
+  }
+
+  /// Sets how the items are laid out.
+  pub fn set_layout(&self, layout: Layout) {
+    self.inner.state.borrow_mut().layout = layout;
+    self.inner.relayout();
+  }
+
+  /// Retrieves how the items are laid out.
+  pub fn get_layout(&self) -> Layout {
+    return self.inner.state.borrow().layout;
+  }
+
+  /// Sets the space between rows, and between the items of a grid, in
+  /// pixels.
+  pub fn set_spacing(&self, spacing: f32) {
+    self.inner.state.borrow_mut().spacing = spacing.max(0.0);
+    self.inner.relayout();
+  }
+
+  /// Retrieves the space between items.
+  pub fn get_spacing(&self) -> f32 {
+    return self.inner.state.borrow().spacing;
+  }
+
+  /// Binds all the visible items again.
+  pub fn refresh(&self) {
+    self.inner.apply(Change::Reset);
+  }
+
+  /// Retrieves the actor bound to the item at `index`, if the item is in
+  /// view.
+  pub fn get_actor(&self, index: usize) -> Option<F::Actor> {
+    unsafe {
+      return self.inner.state.borrow().active.get(&index).map(|actor| F::Actor::from_actor(*actor));
+    }
+  }
+
+  /// Scrolls so that the item at `index` is visible.
+  pub fn scroll_to_item(&self, index: usize, animated: bool) {
+    let rect = self.inner.state.borrow().item_rect(index);
+    self.view.scroll_to_rect(&rect, animated);
+  }
+
+  /// Retrieves the ScrollView scrolling the items, to configure its
+  /// scrolling behaviour.
+  pub fn get_scroll_view(&self) -> ScrollView {
+    return self.view.clone();
+  }
+}
+
+impl<S: DataSource + 'static, F: Factory<S::Item> + 'static> Actor for ListView<S, F> {
+  fn as_actor(&self) -> *mut libc::c_void {
+    return self.view.as_actor();
+  }
+}
+
+impl<S: DataSource + 'static, F: Factory<S::Item> + 'static> Inner<S, F> {
+  /// Applies the pending changes, including those reported while applying
+  /// them.
+  fn flush(&self) {
+    self.busy.set(true);
+    loop {
+      let changes = std::mem::replace(&mut *self.pending.borrow_mut(), Vec::new());
+      if changes.is_empty() {
+        break;
+      }
+
+      for change in changes.into_iter() {
+        self.apply(change);
+      }
+    }
+    self.busy.set(false);
+  }
+
+  fn apply(&self, change: Change) {
+    {
+      let mut state = self.state.borrow_mut();
+      match change {
+        Change::Inserted(position, _) | Change::Removed(position, _) => state.recycle_from::<S::Item>(position),
+        Change::Updated(position, count) => state.recycle_range::<S::Item>(position, position + count),
+        Change::Reset => state.recycle_from::<S::Item>(0)
+      }
+    }
+
+    self.relayout();
+  }
+
+  fn relayout(&self) {
+    let source = self.source.borrow();
+    self.state.borrow_mut().update(&*source);
+  }
+
+  fn scrolled(this: &Rc<Inner<S, F>>, offset: &Point, size: &Size) {
+    let resized = {
+      let mut state = this.state.borrow_mut();
+      let resized = state.size != *size;
+      state.offset = *offset;
+      state.size = *size;
+      resized
+    };
+
+    if !resized {
+      this.relayout();
+      return;
+    }
+
+    // Resizes are reported during the allocation of the view, where adding
+    // and moving actors would queue another relayout; the items are laid
+    // out just after it instead
+    if !this.resize_queued.get() {
+      this.resize_queued.set(true);
+      let inner = this.clone();
+      schedule(std::boxed::Box::new(move || {
+        inner.resize_queued.set(false);
+        inner.relayout();
+      }));
+    }
+  }
+}
+
+impl<F> State<F> {
+  fn columns(&self) -> usize {
+    return match self.layout {
+      Layout::List(_) => 1,
+      Layout::Grid(item) => {
+        let pitch = item.width + self.spacing;
+        if pitch <= 0.0 {
+          return 1;
+        }
+        (((self.size.width + self.spacing) / pitch).floor().max(1.0)) as usize
+      }
+    };
+  }
+
+  fn item_size(&self) -> Size {
+    return match self.layout {
+      Layout::List(height) => Size::new(self.size.width, height),
+      Layout::Grid(item) => item
+    };
+  }
+
+  fn item_rect(&self, index: usize) -> Rect {
+    let columns = self.columns();
+    let item = self.item_size();
+    let x = (index % columns) as f32 * (item.width + self.spacing);
+    let y = (index / columns) as f32 * (item.height + self.spacing);
+    return Rect::new(x, y, item.width, item.height);
+  }
+
+  /// Unbinds the actors of the items from `first` on.
+  fn recycle_from<T>(&mut self, first: usize) where F: Factory<T> {
+    self.recycle_range::<T>(first, std::usize::MAX);
+  }
+
+  /// Unbinds the actors of the items from `first` to `last`, excluded.
+  fn recycle_range<T>(&mut self, first: usize, last: usize) where F: Factory<T> {
+    let stale: Vec<usize> = self.active.keys().filter(|index| **index >= first && **index < last).map(|index| *index).collect();
+    for index in stale.iter() {
+      let actor = self.active.remove(index).unwrap();
+      self.recycle::<T>(actor);
+    }
+  }
+
+  fn recycle<T>(&mut self, actor: *mut libc::c_void) where F: Factory<T> {
+    unsafe {
+      self.factory.unbind(&mut F::Actor::from_actor(actor));
+      handle(actor).hide();
+      self.pool.push(actor);
+    }
+  }
+
+  /// Takes an actor from the pool, or creates one.
+  fn obtain<T>(&mut self) -> *mut libc::c_void where F: Factory<T> {
+    if let Some(actor) = self.pool.pop() {
+      handle(actor).show();
+      return actor;
+    }
+
+    let mut actor = self.factory.create();
+    handle(self.content).add_child(&mut actor);
+    let raw = actor.as_actor();
+    std::mem::forget(actor);
+    return raw;
+  }
+
+  /// Sizes the content for the items of `source`, and binds the items in
+  /// view to actors.
+  fn update<S: DataSource>(&mut self, source: &S) where F: Factory<S::Item> {
+    let count = source.count();
+    let columns = self.columns();
+    let item = self.item_size();
+    let pitch = item.height + self.spacing;
+    let rows = (count + columns - 1) / columns;
+
+    let height = if rows == 0 { 0.0 } else { (rows as f32 * pitch - self.spacing).max(0.0) };
+    handle(self.content).set_size(self.size.width, height);
+
+    let (first, last) = visible_range(self.offset.y, self.size.height, pitch, columns, count);
+
+    let outside: Vec<usize> = self.active.keys().filter(|index| **index < first || **index >= last).map(|index| *index).collect();
+    for index in outside.iter() {
+      let actor = self.active.remove(index).unwrap();
+      self.recycle::<S::Item>(actor);
+    }
+
+    for index in first..last {
+      let actor = match self.active.get(&index) {
+        Some(actor) => *actor,
+        None => {
+          let actor = self.obtain::<S::Item>();
+          unsafe {
+            self.factory.bind(&mut F::Actor::from_actor(actor), source.item_at(index), index);
+          }
+          self.active.insert(index, actor);
+          actor
+        }
+      };
+
+      let rect = self.item_rect(index);
+      let mut actor = handle(actor);
+      actor.set_position(rect.origin.x, rect.origin.y);
+      actor.set_size(rect.size.width, rect.size.height);
+    }
+  }
+}
+
+/// Returns the indices of the items, from the first included to the last
+/// excluded, whose rows of `pitch` pixels are visible in a view of
+/// `visible` pixels scrolled to `offset`.
+///
+/// One more row is included on each side, so that small scrolls do not show
+/// gaps while actors are rebound. Rows without height are never in view.
+fn visible_range(offset: f32, visible: f32, pitch: f32, columns: usize, count: usize) -> (usize, usize) {
+  if pitch <= 0.0 {
+    return (0, 0);
+  }
+
+  let first_row = ((offset / pitch).floor() - 1.0).max(0.0) as usize;
+  let last_row = ((offset + visible) / pitch).ceil().max(0.0) as usize + 1;
+  return (std::cmp::min(first_row * columns, count), std::cmp::min(last_row * columns, count));
+}
+
+fn handle(actor: *mut libc::c_void) -> ActorHandle {
+  unsafe {
+    return std::mem::transmute(actor);
+  }
+}
+
+/// Runs `task` once, before the next frame is drawn.
+fn schedule(task: std::boxed::Box<Fn() + 'static>) {
+  unsafe {
+    let data: *mut libc::c_void = std::mem::transmute(std::boxed::Box::new(task));
+    clutter_threads_add_idle_full(PRIORITY_HIGH_IDLE, run_task, data, free_task);
+  }
+}
+
+extern "C" fn run_task(data: *mut libc::c_void) -> i32 {
+  unsafe {
+    let task: &std::boxed::Box<Fn()> = std::mem::transmute(data);
+    (*task)();
+    return 0;
+  }
+}
+
+extern "C" fn free_task(data: *mut libc::c_void) {
+  unsafe {
+    let task: std::boxed::Box<std::boxed::Box<Fn()>> = std::mem::transmute(data);
+    drop(task);
+  }
+}
+
+/// G_PRIORITY_HIGH_IDLE, which runs before Clutter redraws.
+const PRIORITY_HIGH_IDLE: i32 = 100;
+
+#[cfg(test)]
+mod tests {
+  use std;
+  use std::collections::HashMap;
+  use super::{Layout, State, visible_range};
+  use super::super::super::geometry::{Point, Rect, Size};
+
+  fn state(layout: Layout, spacing: f32, width: f32) -> State<()> {
+    return State {
+      factory: (),
+      layout: layout,
+      spacing: spacing,
+      offset: Point::new(0.0, 0.0),
+      size: Size::new(width, 300.0),
+      content: std::ptr::null_mut(),
+      active: HashMap::new(),
+      pool: Vec::new()
+    };
+  }
+
+  #[test]
+  fn list_columns() {
+    assert_eq!(state(Layout::List(40.0), 0.0, 200.0).columns(), 1);
+    assert_eq!(state(Layout::List(40.0), 10.0, 0.0).columns(), 1);
+  }
+
+  #[test]
+  fn grid_columns() {
+    assert_eq!(state(Layout::Grid(Size::new(50.0, 50.0)), 0.0, 200.0).columns(), 4);
+    assert_eq!(state(Layout::Grid(Size::new(50.0, 50.0)), 0.0, 199.0).columns(), 3);
+
+    // The spacing only goes between items
+    assert_eq!(state(Layout::Grid(Size::new(50.0, 50.0)), 10.0, 230.0).columns(), 4);
+    assert_eq!(state(Layout::Grid(Size::new(50.0, 50.0)), 10.0, 229.0).columns(), 3);
+
+    // There is always at least one column
+    assert_eq!(state(Layout::Grid(Size::new(50.0, 50.0)), 0.0, 20.0).columns(), 1);
+    assert_eq!(state(Layout::Grid(Size::new(50.0, 50.0)), 0.0, 0.0).columns(), 1);
+  }
+
+  #[test]
+  fn grid_columns_without_item_width() {
+    assert_eq!(state(Layout::Grid(Size::new(0.0, 50.0)), 0.0, 200.0).columns(), 1);
+    assert_eq!(state(Layout::Grid(Size::new(0.0, 0.0)), 0.0, 0.0).columns(), 1);
+    assert_eq!(state(Layout::Grid(Size::new(0.0, 50.0)), 10.0, 200.0).columns(), 21);
+  }
+
+  #[test]
+  fn list_item_rect() {
+    let list = state(Layout::List(40.0), 5.0, 200.0);
+    assert_eq!(list.item_rect(0), Rect::new(0.0, 0.0, 200.0, 40.0));
+    assert_eq!(list.item_rect(1), Rect::new(0.0, 45.0, 200.0, 40.0));
+    assert_eq!(list.item_rect(10), Rect::new(0.0, 450.0, 200.0, 40.0));
+  }
+
+  #[test]
+  fn grid_item_rect() {
+    let grid = state(Layout::Grid(Size::new(50.0, 30.0)), 10.0, 230.0);
+    assert_eq!(grid.item_rect(0), Rect::new(0.0, 0.0, 50.0, 30.0));
+    assert_eq!(grid.item_rect(3), Rect::new(180.0, 0.0, 50.0, 30.0));
+    assert_eq!(grid.item_rect(4), Rect::new(0.0, 40.0, 50.0, 30.0));
+    assert_eq!(grid.item_rect(9), Rect::new(60.0, 80.0, 50.0, 30.0));
+  }
+
+  #[test]
+  fn visible_range_at_the_top() {
+    // Rows 0 to 7 are visible, and one more row is bound below them
+    assert_eq!(visible_range(0.0, 300.0, 40.0, 1, 100), (0, 9));
+    assert_eq!(visible_range(0.0, 300.0, 40.0, 3, 100), (0, 27));
+  }
+
+  #[test]
+  fn visible_range_while_scrolled() {
+    // Rows 2 to 9 are visible
+    assert_eq!(visible_range(100.0, 300.0, 40.0, 1, 100), (1, 11));
+    assert_eq!(visible_range(100.0, 300.0, 40.0, 3, 100), (3, 33));
+
+    // Rows 2 to 9 exactly
+    assert_eq!(visible_range(80.0, 320.0, 40.0, 1, 100), (1, 11));
+  }
+
+  #[test]
+  fn visible_range_is_clamped_to_the_items() {
+    assert_eq!(visible_range(0.0, 300.0, 40.0, 1, 5), (0, 5));
+    assert_eq!(visible_range(0.0, 300.0, 40.0, 3, 0), (0, 0));
+    assert_eq!(visible_range(3900.0, 300.0, 40.0, 1, 100), (96, 100));
+    assert_eq!(visible_range(10000.0, 300.0, 40.0, 1, 100), (100, 100));
+  }
+
+  #[test]
+  fn visible_range_while_overshooting() {
+    assert_eq!(visible_range(-100.0, 300.0, 40.0, 1, 100), (0, 6));
+    assert_eq!(visible_range(-500.0, 300.0, 40.0, 1, 100), (0, 1));
+  }
+
+  #[test]
+  fn visible_range_without_row_height() {
+    assert_eq!(visible_range(0.0, 300.0, 0.0, 1, 100), (0, 0));
+  }
+}
+
+extern {
+  fn clutter_threads_add_idle_full(priority: i32, func: extern "C" fn(*mut libc::c_void) -> i32, data: *mut libc::c_void, notify: extern "C" fn(*mut libc::c_void)) -> u32;
+}
@@ -0,0 +1,134 @@
+#![stable]
+
+use libc;
+use super::actor::Actor;
+use super::geometry::{Point, Rect};
+
+pub mod list;
+pub mod view;
+
+/// The directions in which a ScrollActor can scroll.
+///
+/// _Since 1.12_
+#[repr(i32)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Mode {
+  /// Ignore scrolling
+  None = 0,
+
+  /// Scroll only horizontally
+  Horizontally = 1,
+
+  /// Scroll only vertically
+  Vertically = 2,
+
+  /// Scroll in both directions
+  Both = 3
+}
+
+impl Mode {
+  /// Whether the mode allows scrolling horizontally.
+  pub fn is_horizontal(&self) -> bool {
+    return (*self as i32 & Mode::Horizontally as i32) != 0;
+  }
+
+  /// Whether the mode allows scrolling vertically.
+  pub fn is_vertical(&self) -> bool {
+    return (*self as i32 & Mode::Vertically as i32) != 0;
+  }
+}
+
+/// Opaque struct which holds a reference to the underlying Clutter object.
+#[repr(C)]
+pub struct ScrollActorRef {
+  opaque: *mut libc::c_void
+}
+
+impl ScrollActorRef {
+  /// Creates a new ScrollActor.
+  ///
+  /// _Since 1.12_
+  pub fn new() -> ScrollActorRef {
+    unsafe {
+      let foreign_result = clutter_scroll_actor_new();
+      return foreign_result;
+    }
+  }
+}
+
+/// An actor for displaying a portion of its children.
+///
+/// ScrollActor is an actor that can be used to display a portion of the
+/// contents of its children: its allocation is the viewport, and scrolling
+/// translates the children so that the given point is at its origin. It
+/// does not handle events; see `view::ScrollView` for a container that
+/// scrolls with the pointer, touch and mouse wheel.
+///
+/// Scrolling is animated using the easing state of the actor, as set with
+/// `Actor#save_easing_state()`.
+///
+/// _Since 1.12_
+pub trait ScrollActor {
+  /// Returns a pointer to the the underlying C object.
+  ///
+  /// Generally only used internally.
+  fn as_scroll_actor(&self) -> *mut libc::c_void;
+
+  /// Sets the directions in which the actor can be scrolled.
+  ///
+  /// _Since 1.12_
+  fn set_scroll_mode(&mut self, mode: Mode) {
+    unsafe {
+      clutter_scroll_actor_set_scroll_mode(self.as_scroll_actor(), mode);
+    }
+  }
+
+  /// Retrieves the directions in which the actor can be scrolled.
+  ///
+  /// _Since 1.12_
+  fn get_scroll_mode(&mut self) -> Mode {
+    unsafe {
+      let foreign_result = clutter_scroll_actor_get_scroll_mode(self.as_scroll_actor());
+      return foreign_result;
+    }
+  }
+
+  /// Scrolls the contents of the actor so that `point` is the new origin of
+  /// the visible area.
+  ///
+  /// _Since 1.12_
+  fn scroll_to_point(&mut self, point: &Point) {
+    unsafe {
+      clutter_scroll_actor_scroll_to_point(self.as_scroll_actor(), point);
+    }
+  }
+
+  /// Scrolls the contents of the actor so that `rect` is visible.
+  ///
+  /// _Since 1.12_
+  fn scroll_to_rect(&mut self, rect: &Rect) {
+    unsafe {
+      clutter_scroll_actor_scroll_to_rect(self.as_scroll_actor(), rect);
+    }
+  }
+}
+
+impl ScrollActor for ScrollActorRef {
+  fn as_scroll_actor(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+impl Actor for ScrollActorRef {
+  fn as_actor(&self) -> *mut libc::c_void {
+    return self.opaque;
+  }
+}
+
+extern {
+  fn clutter_scroll_actor_new() -> ScrollActorRef;
+  fn clutter_scroll_actor_set_scroll_mode(self_value: *mut libc::c_void, mode: Mode);
+  fn clutter_scroll_actor_get_scroll_mode(self_value: *mut libc::c_void) -> Mode;
+  fn clutter_scroll_actor_scroll_to_point(self_value: *mut libc::c_void, point: *const Point);
+  fn clutter_scroll_actor_scroll_to_rect(self_value: *mut libc::c_void, rect: *const Rect);
+}
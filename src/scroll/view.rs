@@ -0,0 +1,654 @@
+#![stable]
+
+use libc;
+use std;
+use std::cell::RefCell;
+use std::rc::Rc;
+use super::{Mode, ScrollActor, ScrollActorRef};
+use super::super::action::pan::{self, PanAction, PanActionRef};
+use super::super::actor::{allocation, Actor, ActorHandle, ActorRef};
+use super::super::actor::Box as ActorBox;
+use super::super::animation::AnimationMode;
+use super::super::color::Color;
+use super::super::constraint::BindConstraintRef;
+use super::super::constraint::bind::Coordinate;
+use super::super::geometry::{Point, Rect, Size};
+use super::super::signal;
+use super::super::value::{Value, TYPE_BOOLEAN};
+
+/// The thickness of the scroll indicators, in pixels.
+const INDICATOR_THICKNESS: f32 = 4.0;
+
+/// The distance between the scroll indicators and the edges of the view.
+const INDICATOR_MARGIN: f32 = 2.0;
+
+/// The shortest length of a scroll indicator, in pixels.
+const INDICATOR_MIN_LENGTH: f32 = 16.0;
+
+/// How long the scroll indicators stay visible after scrolling stops, in
+/// milliseconds.
+const INDICATOR_LINGER: i32 = 600;
+
+/// The duration of the animation bringing the content back within its
+/// bounds after an overshoot, in milliseconds.
+const SNAP_BACK_DURATION: i32 = 250;
+
+/// The duration of the animation of a mouse wheel step, in milliseconds.
+const WHEEL_DURATION: i32 = 100;
+
+/// A scrollable viewport over a content actor.
+///
+/// ScrollView combines a ScrollActor, which displays the visible part of
+/// the content, with a PanAction for kinetic scrolling by dragging, and
+/// handles the mouse wheel. Dragging past the edges of the content
+/// overshoots, with the content following the pointer at half speed, and
+/// the content springs back into place when the gesture ends. Thin
+/// indicators show the position of the visible area while scrolling.
+///
+/// The view is an actor, to be added to a parent like any other. It is not
+/// destroyed when the ScrollView is dropped; as with a LinearSystem, clones
+/// of a ScrollView control the same view.
+#[derive(Clone)]
+pub struct ScrollView {
+  state: Rc<RefCell<State>>
+}
+
+struct State {
+  root: *mut libc::c_void,
+  viewport: *mut libc::c_void,
+  pan: *mut libc::c_void,
+  indicators: [*mut libc::c_void; 2],
+  mode: Mode,
+  offset: Point,
+  shown: Point,
+  from: Point,
+  animation: *mut libc::c_void,
+  overshoot: f32,
+  wheel_step: f32,
+  show_indicators: bool,
+  size: Size,
+  listeners: Vec<Rc<Listener>>
+}
+
+/// A handler called when the visible area moves or is resized, with the offset
+/// and the size of the visible area.
+pub type Listener = std::boxed::Box<Fn(&Point, &Size) + 'static>;
+
+impl ScrollView {
+  /// Creates an empty view, scrolling in both directions.
+  pub fn new() -> ScrollView {
+    let mut root = ActorRef::new();
+    root.set_reactive(true);
+    root.set_clip_to_allocation(true);
+
+    let mut viewport = ScrollActorRef::new();
+    viewport.set_scroll_mode(Mode::Both);
+    viewport.set_reactive(true);
+    viewport.add_constraint(&mut BindConstraintRef::new(&mut root, Coordinate::Size, 0.0));
+    root.add_child(&mut viewport);
+
+    let mut pan = PanActionRef::new();
+    pan.set_pan_axis(pan::Axis::None);
+    pan.set_interpolate(true);
+    viewport.add_action(&mut pan);
+
+    let mut indicators = [std::ptr::null_mut(); 2];
+    for slot in indicators.iter_mut() {
+      let mut indicator = ActorRef::new();
+      indicator.set_opacity(0);
+      root.add_child(&mut indicator);
+      *slot = indicator.as_actor();
+      std::mem::forget(indicator);
+    }
+
+    let animation = unsafe {
+      let animation = clutter_timeline_new(SNAP_BACK_DURATION as u32);
+      clutter_timeline_set_progress_mode(animation, AnimationMode::EaseOutCubic);
+      animation
+    };
+
+    let state = State {
+      root: root.as_actor(),
+      viewport: viewport.as_actor(),
+      pan: pan.as_pan_action(),
+      indicators: indicators,
+      mode: Mode::Both,
+      offset: Point::new(0.0, 0.0),
+      shown: Point::new(0.0, 0.0),
+      from: Point::new(0.0, 0.0),
+      animation: animation,
+      overshoot: 100.0,
+      wheel_step: 48.0,
+      show_indicators: true,
+      size: Size::new(0.0, 0.0),
+      listeners: Vec::new()
+    };
+    std::mem::forget(root);
+
+    let view = ScrollView { state: Rc::new(RefCell::new(state)) };
+    view.set_indicator_color(&Color::new(0, 0, 0, 160u8 as i8));
+    view.connect_handlers(&mut pan);
+    return view;
+  }
+
+  fn connect_handlers(&self, pan: &mut PanActionRef) {
+    let state = self.state.clone();
+    pan.on_pan(std::boxed::Box::new(move |_actor: &mut ActorHandle, is_interpolated: bool| {
+      return state.borrow_mut().pan(is_interpolated);
+    }));
+
+    let state = self.state.clone();
+    pan.on_pan_stopped(std::boxed::Box::new(move |_actor: &mut ActorHandle| {
+      state.borrow_mut().snap_back();
+    }));
+
+    let state = self.state.clone();
+    let root = self.state.borrow().root;
+    unsafe {
+      signal::connect(root, "scroll-event", std::boxed::Box::new(move |params: &[Value]| {
+        let mut result = Value::new(TYPE_BOOLEAN);
+        result.set_boolean(state.borrow_mut().wheel(params[1].get_boxed()));
+        return Some(result);
+      }), false);
+    }
+
+    // Animated scrolls move the content on each frame, so that listeners
+    // see every offset the content goes through
+    let state = self.state.clone();
+    let animation = self.state.borrow().animation;
+    unsafe {
+      signal::connect(animation, "new-frame", std::boxed::Box::new(move |_| {
+        let mut state = state.borrow_mut();
+        let progress = clutter_timeline_get_progress(state.animation) as f32;
+        let offset = state.from + (state.offset - state.from) * progress;
+        state.show(offset);
+        return None;
+      }), false);
+    }
+
+    // The timeline holds a reference to the state, which is released with
+    // the timeline when the view is destroyed
+    let state = self.state.clone();
+    unsafe {
+      signal::connect(root, "destroy", std::boxed::Box::new(move |_| {
+        let mut state = state.borrow_mut();
+        if !state.animation.is_null() {
+          clutter_timeline_stop(state.animation);
+          g_object_unref(state.animation);
+          state.animation = std::ptr::null_mut();
+        }
+        return None;
+      }), false);
+    }
+
+    let state = self.state.clone();
+    let mut root = handle(self.state.borrow().root);
+    root.on_allocation_changed(std::boxed::Box::new(move |_actor: &mut ActorRef, allocation: &ActorBox, _flags: allocation::Flags| {
+      let size = allocation.get_size();
+      let listeners = {
+        let mut state = state.borrow_mut();
+        if state.size == size {
+          return;
+        }
+        state.size = size;
+        state.listeners.clone()
+      };
+
+      let offset = state.borrow().shown;
+      for listener in listeners.iter() {
+        (**listener)(&offset, &size);
+      }
+    }));
+  }
+
+  /// Registers `listener`, called each time the visible area moves and each
+  /// time the allocation of the view changes size. During animated scrolls,
+  /// the listener is called on each frame, with the offset of that frame.
+  ///
+  /// The listener must not call the methods of the ScrollView.
+  pub fn on_scrolled(&self, listener: Listener) {
+    self.state.borrow_mut().listeners.push(Rc::new(listener));
+  }
+
+  /// Sets the actor to scroll, destroying the previous one.
+  ///
+  /// The view takes ownership of `content`, which is displayed at its
+  /// preferred size; the view scrolls back to the origin.
+  pub fn set_content<T: Actor>(&self, mut content: T) {
+    let mut state = self.state.borrow_mut();
+    let mut viewport = handle(state.viewport);
+    viewport.destroy_all_children();
+    viewport.add_child(&mut content);
+    std::mem::forget(content);
+    state.scroll(Point::new(0.0, 0.0), 0);
+  }
+
+  /// Retrieves the actor being scrolled, if any.
+  pub fn get_content(&self) -> Option<ActorHandle> {
+    return self.state.borrow().content();
+  }
+
+  /// Sets the directions in which the view can be scrolled.
+  pub fn set_scroll_mode(&self, mode: Mode) {
+    let mut state = self.state.borrow_mut();
+    state.mode = mode;
+    scroll_actor(state.viewport).set_scroll_mode(mode);
+    pan_action(state.pan).set_pan_axis(match mode {
+      Mode::Horizontally => pan::Axis::X,
+      Mode::Vertically => pan::Axis::Y,
+      _ => pan::Axis::None
+    });
+    let offset = state.offset;
+    state.scroll(offset, 0);
+  }
+
+  /// Retrieves the directions in which the view can be scrolled.
+  pub fn get_scroll_mode(&self) -> Mode {
+    return self.state.borrow().mode;
+  }
+
+  /// Sets whether scrolling continues with a decelerating motion after a
+  /// drag gesture ends.
+  pub fn set_kinetic(&self, kinetic: bool) {
+    pan_action(self.state.borrow().pan).set_interpolate(kinetic);
+  }
+
+  /// Retrieves whether kinetic scrolling is enabled.
+  pub fn get_kinetic(&self) -> bool {
+    return pan_action(self.state.borrow().pan).get_interpolate();
+  }
+
+  /// Sets the deceleration rate of kinetic scrolling, between 0 and 1;
+  /// higher values make the motion last longer.
+  pub fn set_deceleration(&self, rate: f64) {
+    pan_action(self.state.borrow().pan).set_deceleration(rate);
+  }
+
+  /// Sets how far, in pixels, the content can be dragged past its edges.
+  ///
+  /// An overshoot of zero stops the content at its edges.
+  pub fn set_overshoot(&self, overshoot: f32) {
+    self.state.borrow_mut().overshoot = overshoot.max(0.0);
+  }
+
+  /// Retrieves how far the content can be dragged past its edges.
+  pub fn get_overshoot(&self) -> f32 {
+    return self.state.borrow().overshoot;
+  }
+
+  /// Sets the distance scrolled by a step of the mouse wheel, in pixels.
+  pub fn set_wheel_step(&self, step: f32) {
+    self.state.borrow_mut().wheel_step = step;
+  }
+
+  /// Retrieves the distance scrolled by a step of the mouse wheel.
+  pub fn get_wheel_step(&self) -> f32 {
+    return self.state.borrow().wheel_step;
+  }
+
+  /// Sets whether the scroll indicators are shown while scrolling.
+  pub fn set_show_indicators(&self, show: bool) {
+    self.state.borrow_mut().show_indicators = show;
+  }
+
+  /// Retrieves whether the scroll indicators are shown while scrolling.
+  pub fn get_show_indicators(&self) -> bool {
+    return self.state.borrow().show_indicators;
+  }
+
+  /// Sets the color of the scroll indicators.
+  pub fn set_indicator_color(&self, color: &Color) {
+    for indicator in self.state.borrow().indicators.iter() {
+      handle(*indicator).set_background_color(color);
+    }
+  }
+
+  /// Retrieves the origin of the visible area, in the coordinates of the
+  /// content. During an animated scroll, this is the offset the view is
+  /// scrolling to.
+  pub fn get_offset(&self) -> Point {
+    return self.state.borrow().offset;
+  }
+
+  /// Scrolls so that `point` is the origin of the visible area, clamped to
+  /// the bounds of the content. If `animated` is true, the view scrolls
+  /// with the easing mode and duration used for snapping back.
+  pub fn scroll_to_point(&self, point: &Point, animated: bool) {
+    let mut state = self.state.borrow_mut();
+    let target = state.clamp(*point);
+    state.scroll(target, if animated { SNAP_BACK_DURATION } else { 0 });
+  }
+
+  /// Scrolls by the smallest distance which makes `rect`, in the
+  /// coordinates of the content, visible. If `rect` is larger than the view,
+  /// its origin is made visible.
+  pub fn scroll_to_rect(&self, rect: &Rect, animated: bool) {
+    let mut state = self.state.borrow_mut();
+    let visible = state.viewport_size();
+    let rect = rect.normalize();
+    let mut target = state.offset;
+    target.x = reveal(target.x, visible.width, rect.origin.x, rect.size.width);
+    target.y = reveal(target.y, visible.height, rect.origin.y, rect.size.height);
+    let target = state.clamp(target);
+    state.scroll(target, if animated { SNAP_BACK_DURATION } else { 0 });
+  }
+}
+
+impl Actor for ScrollView {
+  fn as_actor(&self) -> *mut libc::c_void {
+    return self.state.borrow().root;
+  }
+}
+
+impl State {
+  fn content(&self) -> Option<ActorHandle> {
+    return handle(self.viewport).children().next();
+  }
+
+  fn viewport_size(&self) -> Size {
+    return handle(self.root).get_size();
+  }
+
+  fn content_size(&self) -> Size {
+    return match self.content() {
+      Some(mut content) => content.get_size(),
+      None => Size::new(0.0, 0.0)
+    };
+  }
+
+  /// The largest offset on each axis, zero on the axes the mode does not
+  /// scroll.
+  fn max_offset(&self) -> Point {
+    let visible = self.viewport_size();
+    let content = self.content_size();
+    return Point::new(
+      if self.mode.is_horizontal() { (content.width - visible.width).max(0.0) } else { 0.0 },
+      if self.mode.is_vertical() { (content.height - visible.height).max(0.0) } else { 0.0 });
+  }
+
+  fn clamp(&self, point: Point) -> Point {
+    let max = self.max_offset();
+    return Point::new(point.x.max(0.0).min(max.x), point.y.max(0.0).min(max.y));
+  }
+
+  /// Scrolls to `offset`, animating the content for `duration` milliseconds
+  /// if it is not zero.
+  fn scroll(&mut self, offset: Point, duration: i32) {
+    self.offset = offset;
+    if self.animation.is_null() {
+      self.show(offset);
+      return;
+    }
+
+    unsafe {
+      clutter_timeline_stop(self.animation);
+      if duration > 0 && offset != self.shown {
+        self.from = self.shown;
+        clutter_timeline_set_duration(self.animation, duration as u32);
+        clutter_timeline_start(self.animation);
+      } else {
+        self.show(offset);
+      }
+    }
+  }
+
+  /// Moves the content so that `offset` is the origin of the visible area,
+  /// and notifies the listeners.
+  fn show(&mut self, offset: Point) {
+    self.shown = offset;
+    scroll_actor(self.viewport).scroll_to_point(&offset);
+    self.update_indicators();
+
+    let size = self.viewport_size();
+    for listener in self.listeners.iter() {
+      (**listener)(&offset, &size);
+    }
+  }
+
+  /// Handles a `pan` event, returning false to stop the gesture.
+  fn pan(&mut self, is_interpolated: bool) -> bool {
+    let mut action = pan_action(self.pan);
+    let delta = if is_interpolated { action.get_interpolated_delta() } else { action.get_motion_delta(0) };
+    let max = self.max_offset();
+    let next = Point::new(
+      if self.mode.is_horizontal() { overshoot_step(self.shown.x, delta.x, max.x, self.overshoot) } else { 0.0 },
+      if self.mode.is_vertical() { overshoot_step(self.shown.y, delta.y, max.y, self.overshoot) } else { 0.0 });
+    self.scroll(next, 0);
+
+    // The inertia stops once it has reached the limit of the overshoot
+    let limit = self.overshoot;
+    let at_limit = |value: f32, max: f32| value <= -limit || value >= max + limit;
+    return !(is_interpolated && ((max.x > 0.0 && at_limit(next.x, max.x)) || (max.y > 0.0 && at_limit(next.y, max.y))));
+  }
+
+  /// Brings the content back within its bounds after an overshoot.
+  fn snap_back(&mut self) {
+    let target = self.clamp(self.offset);
+    if target != self.offset {
+      self.scroll(target, SNAP_BACK_DURATION);
+    }
+  }
+
+  /// Handles a `scroll-event`, returning whether the event was used.
+  fn wheel(&mut self, event: *mut libc::c_void) -> bool {
+    unsafe {
+      let mut delta = match clutter_event_get_scroll_direction(event) {
+        SCROLL_UP => Point::new(0.0, -1.0),
+        SCROLL_DOWN => Point::new(0.0, 1.0),
+        SCROLL_LEFT => Point::new(-1.0, 0.0),
+        SCROLL_RIGHT => Point::new(1.0, 0.0),
+        _ => {
+          let mut d_x: f64 = 0.0;
+          let mut d_y: f64 = 0.0;
+          clutter_event_get_scroll_delta(event, &mut d_x, &mut d_y);
+          Point::new(d_x as f32, d_y as f32)
+        }
+      };
+
+      // Vertical wheels scroll horizontally with shift, or when the view
+      // only scrolls horizontally
+      if delta.x == 0.0 && (clutter_event_has_shift_modifier(event) != 0 || self.mode == Mode::Horizontally) {
+        delta = Point::new(delta.y, 0.0);
+      }
+
+      let target = self.clamp(self.offset + delta * self.wheel_step);
+      if target == self.offset {
+        return false;
+      }
+
+      self.scroll(target, WHEEL_DURATION);
+      return true;
+    }
+  }
+
+  /// Sizes and places the indicators for the offset shown, shows them,
+  /// and fades them out after a while.
+  fn update_indicators(&mut self) {
+    let visible = self.viewport_size();
+    let content = self.content_size();
+    let max = self.max_offset();
+
+    let mut horizontal = handle(self.indicators[0]);
+    let mut vertical = handle(self.indicators[1]);
+    let show_horizontal = self.show_indicators && max.x > 0.0;
+    let show_vertical = self.show_indicators && max.y > 0.0;
+
+    if show_horizontal {
+      let track = visible.width - 2.0 * INDICATOR_MARGIN - if show_vertical { INDICATOR_THICKNESS } else { 0.0 };
+      let (position, length) = indicator_span(self.shown.x, max.x, visible.width, content.width, track);
+      horizontal.set_position(INDICATOR_MARGIN + position, visible.height - INDICATOR_MARGIN - INDICATOR_THICKNESS);
+      horizontal.set_size(length, INDICATOR_THICKNESS);
+    }
+
+    if show_vertical {
+      let track = visible.height - 2.0 * INDICATOR_MARGIN - if show_horizontal { INDICATOR_THICKNESS } else { 0.0 };
+      let (position, length) = indicator_span(self.shown.y, max.y, visible.height, content.height, track);
+      vertical.set_position(visible.width - INDICATOR_MARGIN - INDICATOR_THICKNESS, INDICATOR_MARGIN + position);
+      vertical.set_size(INDICATOR_THICKNESS, length);
+    }
+
+    for (mut indicator, show) in vec![(horizontal, show_horizontal), (vertical, show_vertical)].into_iter() {
+      unsafe {
+        clutter_actor_remove_all_transitions(indicator.as_actor());
+      }
+      indicator.set_opacity(if show { 255u8 as i8 } else { 0 });
+      if show {
+        indicator.save_easing_state();
+        indicator.set_easing_delay(INDICATOR_LINGER);
+        indicator.set_easing_duration(SNAP_BACK_DURATION);
+        indicator.set_opacity(0);
+        indicator.restore_easing_state();
+      }
+    }
+  }
+}
+
+/// Moves `offset` by `-delta`, at half speed past the edges, without going
+/// further than `overshoot` past them.
+fn overshoot_step(offset: f32, delta: f32, max: f32, overshoot: f32) -> f32 {
+  let mut next = offset - delta;
+  if next < 0.0 || next > max {
+    next = offset - delta / 2.0;
+  }
+
+  return next.max(-overshoot).min(max + overshoot);
+}
+
+/// Returns the offset of the visible area needed to make the span at
+/// `start` of `length` visible, scrolling as little as possible.
+fn reveal(offset: f32, visible: f32, start: f32, length: f32) -> f32 {
+  if start < offset || length > visible {
+    return start;
+  }
+
+  if start + length > offset + visible {
+    return start + length - visible;
+  }
+
+  return offset;
+}
+
+/// Returns the position and length of an indicator along a track of
+/// `track` pixels; the indicator shrinks while overshooting.
+fn indicator_span(offset: f32, max: f32, visible: f32, content: f32, track: f32) -> (f32, f32) {
+  let mut length = (track * visible / content).max(INDICATOR_MIN_LENGTH).min(track);
+  let excess = if offset < 0.0 { -offset } else if offset > max { offset - max } else { 0.0 };
+  length = (length - excess).max(INDICATOR_THICKNESS);
+
+  let progress = if max > 0.0 { (offset / max).max(0.0).min(1.0) } else { 0.0 };
+  return ((track - length) * progress, length);
+}
+
+fn handle(actor: *mut libc::c_void) -> ActorHandle {
+  unsafe {
+    return std::mem::transmute(actor);
+  }
+}
+
+fn scroll_actor(viewport: *mut libc::c_void) -> ScrollActorRef {
+  unsafe {
+    return std::mem::transmute(viewport);
+  }
+}
+
+fn pan_action(action: *mut libc::c_void) -> PanActionRef {
+  unsafe {
+    return std::mem::transmute(action);
+  }
+}
+
+const SCROLL_UP: i32 = 0;
+const SCROLL_DOWN: i32 = 1;
+const SCROLL_LEFT: i32 = 2;
+const SCROLL_RIGHT: i32 = 3;
+
+#[cfg(test)]
+mod tests {
+  use super::{indicator_span, overshoot_step, reveal, INDICATOR_MIN_LENGTH, INDICATOR_THICKNESS};
+
+  #[test]
+  fn overshoot_step_within_bounds() {
+    assert_eq!(overshoot_step(50.0, 10.0, 200.0, 100.0), 40.0);
+    assert_eq!(overshoot_step(50.0, -10.0, 200.0, 100.0), 60.0);
+    assert_eq!(overshoot_step(10.0, 10.0, 200.0, 100.0), 0.0);
+    assert_eq!(overshoot_step(190.0, -10.0, 200.0, 100.0), 200.0);
+  }
+
+  #[test]
+  fn overshoot_step_past_edges() {
+    // Past the edges, the content moves at half speed
+    assert_eq!(overshoot_step(0.0, 10.0, 200.0, 100.0), -5.0);
+    assert_eq!(overshoot_step(200.0, -10.0, 200.0, 100.0), 205.0);
+    assert_eq!(overshoot_step(-50.0, -10.0, 200.0, 100.0), -45.0);
+
+    // and never further than the overshoot
+    assert_eq!(overshoot_step(-98.0, 10.0, 200.0, 100.0), -100.0);
+    assert_eq!(overshoot_step(299.0, -10.0, 200.0, 100.0), 300.0);
+    assert_eq!(overshoot_step(0.0, 10.0, 200.0, 0.0), 0.0);
+  }
+
+  #[test]
+  fn overshoot_step_with_small_content() {
+    assert_eq!(overshoot_step(0.0, 10.0, 0.0, 100.0), -5.0);
+    assert_eq!(overshoot_step(0.0, -10.0, 0.0, 100.0), 5.0);
+    assert_eq!(overshoot_step(0.0, 500.0, 0.0, 100.0), -100.0);
+  }
+
+  #[test]
+  fn reveal_scrolls_as_little_as_possible() {
+    assert_eq!(reveal(100.0, 200.0, 150.0, 50.0), 100.0);
+    assert_eq!(reveal(100.0, 200.0, 100.0, 200.0), 100.0);
+    assert_eq!(reveal(100.0, 200.0, 50.0, 20.0), 50.0);
+    assert_eq!(reveal(100.0, 200.0, 90.0, 20.0), 90.0);
+    assert_eq!(reveal(100.0, 200.0, 290.0, 30.0), 120.0);
+    assert_eq!(reveal(100.0, 200.0, 400.0, 10.0), 210.0);
+  }
+
+  #[test]
+  fn reveal_spans_longer_than_the_view() {
+    assert_eq!(reveal(100.0, 200.0, 150.0, 300.0), 150.0);
+    assert_eq!(reveal(100.0, 200.0, 20.0, 300.0), 20.0);
+  }
+
+  #[test]
+  fn indicator_span_follows_the_offset() {
+    assert_eq!(indicator_span(0.0, 200.0, 200.0, 400.0, 100.0), (0.0, 50.0));
+    assert_eq!(indicator_span(100.0, 200.0, 200.0, 400.0, 100.0), (25.0, 50.0));
+    assert_eq!(indicator_span(200.0, 200.0, 200.0, 400.0, 100.0), (50.0, 50.0));
+  }
+
+  #[test]
+  fn indicator_span_has_a_minimum_length() {
+    assert_eq!(indicator_span(0.0, 3800.0, 200.0, 4000.0, 100.0), (0.0, INDICATOR_MIN_LENGTH));
+    assert_eq!(indicator_span(3800.0, 3800.0, 200.0, 4000.0, 100.0), (100.0 - INDICATOR_MIN_LENGTH, INDICATOR_MIN_LENGTH));
+  }
+
+  #[test]
+  fn indicator_span_shrinks_while_overshooting() {
+    assert_eq!(indicator_span(-20.0, 200.0, 200.0, 400.0, 100.0), (0.0, 30.0));
+    assert_eq!(indicator_span(230.0, 200.0, 200.0, 400.0, 100.0), (80.0, 20.0));
+    assert_eq!(indicator_span(-100.0, 200.0, 200.0, 400.0, 100.0), (0.0, INDICATOR_THICKNESS));
+    assert_eq!(indicator_span(300.0, 200.0, 200.0, 400.0, 100.0), (100.0 - INDICATOR_THICKNESS, INDICATOR_THICKNESS));
+  }
+
+  #[test]
+  fn indicator_span_with_small_content() {
+    assert_eq!(indicator_span(0.0, 0.0, 200.0, 100.0, 100.0), (0.0, 100.0));
+    assert_eq!(indicator_span(0.0, 0.0, 200.0, 0.0, 100.0), (0.0, 100.0));
+    assert_eq!(indicator_span(-30.0, 0.0, 200.0, 100.0, 100.0), (0.0, 70.0));
+  }
+}
+
+extern {
+  fn clutter_actor_remove_all_transitions(self_value: *mut libc::c_void);
+  fn clutter_timeline_new(msecs: u32) -> *mut libc::c_void;
+  fn clutter_timeline_set_duration(timeline: *mut libc::c_void, msecs: u32);
+  fn clutter_timeline_set_progress_mode(timeline: *mut libc::c_void, mode: AnimationMode);
+  fn clutter_timeline_get_progress(timeline: *mut libc::c_void) -> f64;
+  fn clutter_timeline_start(timeline: *mut libc::c_void);
+  fn clutter_timeline_stop(timeline: *mut libc::c_void);
+  fn clutter_event_get_scroll_direction(event: *mut libc::c_void) -> i32;
+  fn clutter_event_get_scroll_delta(event: *mut libc::c_void, dx: *mut f64, dy: *mut f64);
+  fn clutter_event_has_shift_modifier(event: *mut libc::c_void) -> i32;
+  fn g_object_unref(object: *mut libc::c_void);
+}
@@ -0,0 +1,110 @@
+#![stable]
+
+use libc;
+use std;
+use std::rc::Rc;
+use super::ffi::type_of;
+use super::value::Value;
+
+/// A signal handler: it receives the instance emitting the signal followed
+/// by the arguments of the signal, and returns the return value of the
+/// signal, if it has one.
+pub type Handler = std::boxed::Box<Fn(&[Value]) -> Option<Value> + 'static>;
+
+/// Connects `handler` to the signal `detailed_signal` of `instance`.
+///
+/// Unlike the typed `on_*` methods of the bindings, this works with any
+/// signal, at the price of receiving the arguments as Values. The handler is
+/// dropped when the instance is finalized or the handler is disconnected.
+///
+/// Returns the id of the signal handler.
+///
+/// This is unsafe because `instance` must point to a live GObject instance
+/// with a signal named `detailed_signal`.
+pub unsafe fn connect(instance: *mut libc::c_void, detailed_signal: &str, handler: Handler, after: bool) -> u64 {
+  use std::ffi::CString;
+  return connect_raw(instance, detailed_signal.to_c_str().unwrap() as *const i8, Rc::new(handler), after);
+}
+
+/// Connects a shared `handler`, with the signal name given as a C string.
+///
+/// Generally only used internally.
+pub unsafe fn connect_raw(instance: *mut libc::c_void, detailed_signal: *const libc::c_char, handler: Rc<Handler>, after: bool) -> u64 {
+  return g_signal_connect_closure(instance, detailed_signal, new_closure(handler), (after as i32));
+}
+
+/// Connects a shared `handler` for as long as `object` is alive, like
+/// g_signal_connect_object().
+///
+/// If `swapped` is set, the handler receives `object` in place of the
+/// instance, and the instance after the arguments of the signal, as a C
+/// handler connected with G_CONNECT_SWAPPED would.
+///
+/// Generally only used internally.
+pub unsafe fn connect_object_raw(instance: *mut libc::c_void, detailed_signal: *const libc::c_char, handler: Rc<Handler>, object: *mut libc::c_void, swapped: bool, after: bool) -> u64 {
+  let handler = if swapped {
+    let inner = handler;
+    // The closure is invalidated when the object is finalized, so the
+    // pointer is valid whenever the handler runs.
+    Rc::new(std::boxed::Box::new(move |params: &[Value]| {
+      let mut first = Value::new(type_of(object));
+      first.set_object(object);
+      let mut swapped_params = vec![first];
+      swapped_params.extend(params[1..].iter().map(|param| param.clone()));
+      swapped_params.push(params[0].clone());
+      return (*inner)(swapped_params.as_slice());
+    }) as Handler)
+  } else {
+    handler
+  };
+
+  let closure = new_closure(handler);
+  g_object_watch_closure(object, closure);
+  return g_signal_connect_closure(instance, detailed_signal, closure, (after as i32));
+}
+
+/// Creates a closure calling `handler`, which is dropped together with the
+/// closure.
+unsafe fn new_closure(handler: Rc<Handler>) -> *mut Closure {
+  let data: *mut libc::c_void = std::mem::transmute(std::boxed::Box::new(handler));
+  let closure = g_closure_new_simple(std::mem::size_of::<Closure>() as u32, data);
+  g_closure_set_marshal(closure, marshal_handler);
+  g_closure_add_finalize_notifier(closure, data, free_handler);
+  return closure;
+}
+
+/// Mirror of GClosure.
+#[repr(C)]
+struct Closure {
+  flags: u32,
+  marshal: *mut libc::c_void,
+  data: *mut libc::c_void,
+  notifiers: *mut libc::c_void
+}
+
+extern "C" fn marshal_handler(closure: *mut Closure, return_value: *mut Value, n_param_values: u32, param_values: *const Value, _invocation_hint: *mut libc::c_void, _marshal_data: *mut libc::c_void) {
+  unsafe {
+    let handler: &Rc<Handler> = std::mem::transmute((*closure).data);
+    let params = std::slice::from_raw_parts(param_values, n_param_values as usize);
+    match (**handler)(params) {
+      Some(ref value) if !return_value.is_null() => g_value_copy(value, return_value),
+      _ => ()
+    }
+  }
+}
+
+extern "C" fn free_handler(data: *mut libc::c_void, _closure: *mut Closure) {
+  unsafe {
+    let handler: std::boxed::Box<Rc<Handler>> = std::mem::transmute(data);
+    drop(handler);
+  }
+}
+
+extern {
+  fn g_closure_new_simple(sizeof_closure: u32, data: *mut libc::c_void) -> *mut Closure;
+  fn g_closure_set_marshal(closure: *mut Closure, marshal: extern "C" fn(*mut Closure, *mut Value, u32, *const Value, *mut libc::c_void, *mut libc::c_void));
+  fn g_closure_add_finalize_notifier(closure: *mut Closure, notify_data: *mut libc::c_void, notify_func: extern "C" fn(*mut libc::c_void, *mut Closure));
+  fn g_object_watch_closure(object: *mut libc::c_void, closure: *mut Closure);
+  fn g_signal_connect_closure(instance: *mut libc::c_void, detailed_signal: *const libc::c_char, closure: *mut Closure, after: i32) -> u64;
+  fn g_value_copy(src_value: *const Value, dest_value: *mut Value);
+}
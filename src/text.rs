@@ -2,7 +2,7 @@
 
 use libc;
 use std;
-use super::actor::Actor;
+use super::actor::{Actor, FromActor};
 use super::content::Content;
 
 /// Opaque struct which holds a reference to the underlying Clutter object.
@@ -265,6 +265,12 @@ impl Actor for TextRef {
   }
 }
 
+impl FromActor for TextRef {
+  unsafe fn from_actor(actor: *mut libc::c_void) -> TextRef {
+    return TextRef { opaque: actor };
+  }
+}
+
 impl Content for TextRef {
   fn as_content(&self) -> *mut libc::c_void {
     return self.opaque;
@@ -0,0 +1,235 @@
+#![stable]
+
+use libc;
+use std;
+
+/// A numerical value which represents the unique identifier of a registered
+/// type.
+pub type Type = libc::size_t;
+
+/// The error returned when the type of an implementation written in Rust
+/// cannot be registered because its name is already taken by a type
+/// registered elsewhere; holds the name.
+#[derive(Clone, PartialEq, Debug)]
+pub struct TypeNameTaken(pub String);
+
+/// Fundamental type for boolean values.
+pub const TYPE_BOOLEAN: Type = 5 << 2;
+
+/// Fundamental type for signed integer values.
+pub const TYPE_INT: Type = 6 << 2;
+
+/// Fundamental type for unsigned integer values.
+pub const TYPE_UINT: Type = 7 << 2;
+
+/// Fundamental type for single precision floating point values.
+pub const TYPE_FLOAT: Type = 14 << 2;
+
+/// Fundamental type for double precision floating point values.
+pub const TYPE_DOUBLE: Type = 15 << 2;
+
+/// Fundamental type for nul-terminated strings.
+pub const TYPE_STRING: Type = 16 << 2;
+
+/// A generic container for values of any registered type.
+///
+/// This is the Rust counterpart of a GValue: it holds a value together with
+/// the type of that value. It is used where Clutter needs to pass values of
+/// arbitrary types around, for instance in an Interval or in the state
+/// methods of Animatable.
+#[repr(C)]
+pub struct Value {
+  g_type: Type,
+  data: [u64; 2]
+}
+
+impl Value {
+  /// Creates a new Value, initialised to the default value of `value_type`.
+  pub fn new(value_type: Type) -> Value {
+    unsafe {
+      let mut value = Value { g_type: 0, data: [0, 0] };
+      g_value_init(&mut value, value_type);
+      return value;
+    }
+  }
+
+  /// Returns the type of the value held.
+  pub fn get_type(&self) -> Type {
+    return self.g_type;
+  }
+
+  /// Sets the contents of a boolean Value.
+  pub fn set_boolean(&mut self, v_boolean: bool) {
+    unsafe {
+      g_value_set_boolean(self, (v_boolean as i32));
+    }
+  }
+
+  /// Gets the contents of a boolean Value.
+  pub fn get_boolean(&self) -> bool {
+    unsafe {
+      let foreign_result = g_value_get_boolean(self);
+      return foreign_result != 0;
+    }
+  }
+
+  /// Sets the contents of an integer Value.
+  pub fn set_int(&mut self, v_int: i32) {
+    unsafe {
+      g_value_set_int(self, v_int);
+    }
+  }
+
+  /// Gets the contents of an integer Value.
+  pub fn get_int(&self) -> i32 {
+    unsafe {
+      let foreign_result = g_value_get_int(self);
+      return foreign_result;
+    }
+  }
+
+  /// Sets the contents of an unsigned integer Value.
+  pub fn set_uint(&mut self, v_uint: u32) {
+    unsafe {
+      g_value_set_uint(self, v_uint);
+    }
+  }
+
+  /// Gets the contents of an unsigned integer Value.
+  pub fn get_uint(&self) -> u32 {
+    unsafe {
+      let foreign_result = g_value_get_uint(self);
+      return foreign_result;
+    }
+  }
+
+  /// Sets the contents of a float Value.
+  pub fn set_float(&mut self, v_float: f32) {
+    unsafe {
+      g_value_set_float(self, v_float);
+    }
+  }
+
+  /// Gets the contents of a float Value.
+  pub fn get_float(&self) -> f32 {
+    unsafe {
+      let foreign_result = g_value_get_float(self);
+      return foreign_result;
+    }
+  }
+
+  /// Sets the contents of a double Value.
+  pub fn set_double(&mut self, v_double: f64) {
+    unsafe {
+      g_value_set_double(self, v_double);
+    }
+  }
+
+  /// Gets the contents of a double Value.
+  pub fn get_double(&self) -> f64 {
+    unsafe {
+      let foreign_result = g_value_get_double(self);
+      return foreign_result;
+    }
+  }
+
+  /// Sets the contents of a string Value; the string is copied.
+  pub fn set_string(&mut self, v_string: &str) {
+    unsafe {
+      use std::ffi::CString;
+      g_value_set_string(self, v_string.to_c_str().unwrap() as *mut i8);
+    }
+  }
+
+  /// Gets the contents of a string Value.
+  pub fn get_string(&self) -> std::ffi::CString {
+    unsafe {
+      let foreign_result = g_value_get_string(self);
+      return std::ffi::CString::new(foreign_result as *const i8, false);
+    }
+  }
+
+  /// Sets the contents of a boxed Value; the boxed structure is copied.
+  ///
+  /// Generally only used internally.
+  pub fn set_boxed(&mut self, v_boxed: *const libc::c_void) {
+    unsafe {
+      g_value_set_boxed(self, v_boxed);
+    }
+  }
+
+  /// Gets the contents of a boxed Value. The returned pointer is owned by the
+  /// Value.
+  ///
+  /// Generally only used internally.
+  pub fn get_boxed(&self) -> *mut libc::c_void {
+    unsafe {
+      let foreign_result = g_value_get_boxed(self);
+      return foreign_result;
+    }
+  }
+
+  /// Sets the contents of an object Value; a reference is taken on the object.
+  ///
+  /// Generally only used internally.
+  pub fn set_object(&mut self, v_object: *mut libc::c_void) {
+    unsafe {
+      g_value_set_object(self, v_object);
+    }
+  }
+
+  /// Gets the contents of an object Value. No reference is added on the
+  /// returned object.
+  ///
+  /// Generally only used internally.
+  pub fn get_object(&self) -> *mut libc::c_void {
+    unsafe {
+      let foreign_result = g_value_get_object(self);
+      return foreign_result;
+    }
+  }
+}
+
+impl std::clone::Clone for Value {
+  /// Copies the contents of the value into a new Value of the same type.
+  fn clone(&self) -> Value {
+    unsafe {
+      let mut value = Value::new(self.g_type);
+      g_value_copy(self, &mut value);
+      return value;
+    }
+  }
+}
+
+impl std::ops::Drop for Value {
+  /// Clears the current value and unsets its type.
+  fn drop(&mut self) {
+    unsafe {
+      if self.g_type != 0 {
+        g_value_unset(self);
+      }
+    }
+  }
+}
+
+extern {
+  fn g_value_init(value: *mut Value, g_type: Type) -> *mut Value;
+  fn g_value_copy(src_value: *const Value, dest_value: *mut Value);
+  fn g_value_unset(value: *mut Value);
+  fn g_value_set_boolean(value: *mut Value, v_boolean: i32);
+  fn g_value_get_boolean(value: *const Value) -> i32;
+  fn g_value_set_int(value: *mut Value, v_int: i32);
+  fn g_value_get_int(value: *const Value) -> i32;
+  fn g_value_set_uint(value: *mut Value, v_uint: u32);
+  fn g_value_get_uint(value: *const Value) -> u32;
+  fn g_value_set_float(value: *mut Value, v_float: f32);
+  fn g_value_get_float(value: *const Value) -> f32;
+  fn g_value_set_double(value: *mut Value, v_double: f64);
+  fn g_value_get_double(value: *const Value) -> f64;
+  fn g_value_set_string(value: *mut Value, v_string: *mut libc::c_char);
+  fn g_value_get_string(value: *const Value) -> *mut i8;
+  fn g_value_set_boxed(value: *mut Value, v_boxed: *const libc::c_void);
+  fn g_value_get_boxed(value: *const Value) -> *mut libc::c_void;
+  fn g_value_set_object(value: *mut Value, v_object: *mut libc::c_void);
+  fn g_value_get_object(value: *const Value) -> *mut libc::c_void;
+}